@@ -3,36 +3,928 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * A C-compatible vector for `Vec<u64>`.
+ */
+typedef struct U64Vec {
+  uintptr_t capacity;
+  uintptr_t length;
+  uint64_t *vec;
+} U64Vec;
+
+/**
+ *
+ * An error struct for making error handling easy.
+ *
+ * ## Error Codes
+ * - \>0: Magic number of the unknown object (as `i64` (or `ìnt64_t))
+ * - -1: Binary is corrupted
+ * - -2: Unknown/Bad magic number
+ * - -3: Error at reading and interpreting bytes
+ * - -4: I/O Error at parsing the object
+ * - -5: Buffer is too short to hold
+ * - -6: Unknown error[^1]
+ * - -7: Unimplemented executable format
+ *
+ * [^1]: All errors thrown by goblin crate and my code are covered. Because of matching goblin's [`Error`](goblin::error::Error) is non-exhaustive, I included non-exhaustive path too.
+ *
+ */
+typedef struct ParsingError {
+  int64_t code;
+  char *explanation;
+} ParsingError;
+
+/**
+ * A C-compatible vector for `Vec<String>`.
+ */
+typedef struct CharVec {
+  uintptr_t capacity;
+  uintptr_t length;
+  char **vec;
+} CharVec;
+
+/**
+ *
+ * A struct contains the information decoded from the debug directory (`IMAGE_DIRECTORY_ENTRY_DEBUG`) of PE files.
+ *
+ * Fields which are not found in the debug directory will be null pointers, blank vectors or `0`.
+ *
+ */
+typedef struct PeDebugInfo {
+  /**
+   * Types of the entries in the debug directory (Like: CodeView, POGO, Repro, etc.).
+   */
+  struct CharVec entries;
+  /**
+   * The path of the PDB file which is written by the linker into the CodeView entry.
+   */
+  char *pdb_path;
+  /**
+   * The GUID of the PDB file (in registry format) for RSDS entries or the signature of it (in hex) for NB10 entries.
+   */
+  char *pdb_guid;
+  /**
+   * The age of the PDB file. It is incremented each time the PDB file is updated.
+   */
+  uint32_t pdb_age;
+  /**
+   * The names of the sections listed in the POGO (Profile Guided Optimization) entry.
+   */
+  struct CharVec pogo_sections;
+  /**
+   * The counts in the VC_FEATURE entry as "name: count" strings.
+   */
+  struct CharVec vc_features;
+  /**
+   * The hash (in hex) written into the REPRO entry by deterministic builds.
+   */
+  char *repro_hash;
+  /**
+   * The names of the flags set in the EX_DLLCHARACTERISTICS entry.
+   */
+  struct CharVec ex_dll_characteristics;
+} PeDebugInfo;
+
+/**
+ *
+ * A struct contains the metadata of .NET (CLR) assemblies.
+ *
+ * It is filled if the CLR runtime header data directory of the PE file is present.
+ *
+ */
+typedef struct DotNetInfo {
+  /**
+   * The field is true if the object is a .NET assembly.
+   */
+  bool is_dotnet;
+  /**
+   * The version of the runtime the assembly was built against (Like: v4.0.30319).
+   */
+  char *runtime_version;
+  /**
+   * The name of the assembly.
+   *
+   * It is a null pointer in modules (.netmodule) since they don't have an assembly manifest.
+   */
+  char *assembly_name;
+  /**
+   * The version of the assembly.
+   */
+  char *assembly_version;
+  /**
+   * The culture of the assembly. It is "neutral" if the assembly is not a satellite assembly.
+   */
+  char *culture;
+  /**
+   * The public key token (in hex) of the assembly. It is a null pointer if the assembly is not strong-named.
+   */
+  char *public_key_token;
+  /**
+   * The value of `TargetFrameworkAttribute` (Like: .NETCoreApp,Version=v8.0).
+   */
+  char *target_framework;
+  /**
+   * The field is true if the assembly contains only IL code.
+   */
+  bool is_il_only;
+  /**
+   * The field is true if the assembly can only run as a 32 bit process.
+   */
+  bool requires_32bit;
+  /**
+   * The field is true if the assembly contains precompiled (ReadyToRun) native code.
+   */
+  bool is_ready_to_run;
+  /**
+   * The assemblies referenced by the assembly as display names (Like: "System.Runtime, Version=8.0.0.0, Culture=neutral, PublicKeyToken=b03f5f7f11d50a3a").
+   */
+  struct CharVec assembly_references;
+} DotNetInfo;
+
+/**
+ *
+ * A struct contains the entry point, TLS callbacks and load configuration of PE files.
+ *
+ * Addresses are RVAs unless they are stated as VAs. Fields which are not found in the object will be null pointers, blank vectors or `0`.
+ *
+ */
+typedef struct PeLoadInfo {
+  /**
+   * The RVA of the entry point.
+   */
+  uint64_t entry_point;
+  /**
+   * The name of the section which contains the entry point.
+   *
+   * It is a null pointer if the object doesn't have an entry point (`entry_point` is `0`, like resource-only DLLs).
+   * Otherwise, it is a null pointer if the entry point is not inside of any section, which is a common sign of packed objects.
+   */
+  char *entry_point_section;
+  /**
+   * The RVAs of the TLS callbacks. They are run before the entry point.
+   */
+  struct U64Vec tls_callbacks;
+  /**
+   * The VA of the security cookie used by `/GS`.
+   */
+  uint64_t security_cookie;
+  /**
+   * The VA of the safe SEH handler table (32 bit objects only).
+   */
+  uint64_t se_handler_table;
+  /**
+   * The count of the handlers in the safe SEH handler table (32 bit objects only).
+   */
+  uint64_t se_handler_count;
+  /**
+   * The names of the Control Flow Guard flags set in the load configuration.
+   */
+  struct CharVec guard_flags;
+  /**
+   * The VA of the CHPE metadata. It is only set in hybrid (ARM64EC, ARM64X and CHPE) objects.
+   */
+  uint64_t chpe_metadata;
+  /**
+   * The kind of the hybrid object (Like: ARM64EC, ARM64X). It is a null pointer in non-hybrid objects.
+   */
+  char *hybrid_kind;
+  /**
+   * The size of the exception directory (`.pdata`) in bytes.
+   */
+  uint32_t exception_directory_size;
+} PeLoadInfo;
+
+/**
+ *
+ * A struct contains the results of the heuristics which are commonly used to triage PE files.
+ *
+ */
+typedef struct PeAnomalies {
+  /**
+   * The field is true if there is data after the raw data of the last section (and the certificate table).
+   */
+  bool has_overlay;
+  /**
+   * The file offset of the overlay. It is `0` if there is no overlay.
+   */
+  uint64_t overlay_offset;
+  /**
+   * The size of the overlay in bytes.
+   */
+  uint64_t overlay_size;
+  /**
+   * The `CheckSum` field of the optional header. Most linkers leave it `0` unless the object is a driver or a system DLL.
+   */
+  uint32_t stored_checksum;
+  /**
+   * The checksum calculated from the bytes of the object.
+   */
+  uint32_t computed_checksum;
+  /**
+   * The field is true if the stored checksum matches the calculated one.
+   */
+  bool is_checksum_valid;
+  /**
+   * Explanations of the suspicious sections (Like: writable and executable sections, sections beyond the end of the file, etc.).
+   */
+  struct CharVec section_anomalies;
+} PeAnomalies;
+
+/**
+ *
+ * A struct contains the build ID of ELF files and the links to their separate debug files.
+ *
+ * Fields which are not found in the object will be null pointers or `0`.
+ *
+ */
+typedef struct ElfDebugInfo {
+  /**
+   * The build ID (in hex) in the `NT_GNU_BUILD_ID` note.
+   */
+  char *build_id;
+  /**
+   * The name of the debug file in the `.gnu_debuglink` section.
+   */
+  char *debuglink;
+  /**
+   * The CRC32 of the debug file in the `.gnu_debuglink` section.
+   */
+  uint32_t debuglink_crc;
+  /**
+   * The name of the supplementary debug file (dwz) in the `.gnu_debugaltlink` section.
+   */
+  char *debugaltlink;
+  /**
+   * The build ID (in hex) of the supplementary debug file in the `.gnu_debugaltlink` section.
+   */
+  char *debugaltlink_build_id;
+  /**
+   * The path of the debug file which matches the object.
+   *
+   * It is only searched if [`ULDDOptions::find_debug_files`] is true. It is a null pointer if the search is disabled or no debug file is found.
+   */
+  char *debug_file;
+} ElfDebugInfo;
+
+/**
+ *
+ * A struct contains what is left in the object after stripping.
+ *
+ * The symbol table and debug information is looked up in:
+ * - ELF: `.symtab`, `.debug_*`, `.zdebug_*` and `.stab` sections, `.gnu_debuglink` and `.gnu_debugaltlink`
+ * - PE: The COFF symbol table, the debug directory and `.debug_*` sections
+ * - COFF: The symbol table, `.debug$*` and `.debug_*` sections
+ * - Mach-O: Local symbols, stabs (`N_OSO` and the others) and `__DWARF` sections
+ *
+ */
+typedef struct StrippingInfo {
+  /**
+   * One of "Fully stripped", "Symbols only" or "Debug info".
+   *
+   * Only the embedded debug information makes it "Debug info", so the objects which only point to split or referenced debug information are "Fully stripped" or "Symbols only".
+   */
+  char *status;
+  /**
+   * The field is true if the object has a symbol table (not the dynamic one) with local symbols.
+   */
+  bool has_symbols;
+  /**
+   * The formats and locations of the debug information as "format (location)" strings (Like: "DWARF (Embedded)", "PDB (Split)", "DWARF (Referenced)").
+   */
+  struct CharVec debug_info;
+} StrippingInfo;
+
+/**
+ *
+ * A struct contains the state of a thread at the time the core dump was written (`NT_PRSTATUS`).
+ *
+ */
+typedef struct CoreThread {
+  /**
+   * The ID of the thread.
+   */
+  int32_t tid;
+  /**
+   * The signal which was being delivered to the thread. It is `0` if there was none.
+   */
+  int32_t signal;
+  /**
+   * The general purpose registers in the order of `elf_gregset_t` of the architecture.
+   */
+  struct U64Vec registers;
+} CoreThread;
+
+/**
+ * A C-compatible vector for [`CoreThread`].
+ */
+typedef struct CoreThreadVec {
+  uintptr_t capacity;
+  uintptr_t length;
+  struct CoreThread *vec;
+} CoreThreadVec;
+
+/**
+ *
+ * A struct contains the information about the crashed process which is found in the notes of ELF core dumps.
+ *
+ * Fields which are not found in the core dump will be null pointers, blank vectors or `0`.
+ *
+ */
+typedef struct CoreDumpInfo {
+  /**
+   * The field is true if the object is a core dump.
+   */
+  bool is_core;
+  /**
+   * The name of the executable of the process (`pr_fname` in `NT_PRPSINFO`). It is truncated to 15 characters by the kernel.
+   */
+  char *process_name;
+  /**
+   * The command line of the process (`pr_psargs` in `NT_PRPSINFO`). It is truncated to 79 characters by the kernel.
+   */
+  char *process_arguments;
+  /**
+   * The ID of the process.
+   */
+  int32_t pid;
+  /**
+   * The signal which caused the core dump.
+   */
+  int32_t signal;
+  /**
+   * The threads of the process.
+   */
+  struct CoreThreadVec threads;
+  /**
+   * The auxiliary vector (`NT_AUXV`) as "name: value" strings (Like: "AT_ENTRY: 0x401000").
+   */
+  struct CharVec auxv;
+  /**
+   * The files which were mapped into the memory of the process (`NT_FILE`).
+   *
+   * The shared libraries among them are also listed in `libraries`.
+   */
+  struct CharVec mapped_files;
+} CoreDumpInfo;
+
+/**
+ *
+ * A struct contains the entries of the dynamic section of ELF objects.
+ *
+ * Fields which are not found in the object will be null pointers, zeros or blank vectors.
+ *
+ */
+typedef struct ElfDynamicInfo {
+  /**
+   * The name the dynamic linker matches with the needed libraries of other objects (`DT_SONAME`).
+   */
+  char *soname;
+  /**
+   * The search paths in `DT_RPATH`, which are searched before `LD_LIBRARY_PATH`.
+   */
+  struct CharVec rpath;
+  /**
+   * The search paths in `DT_RUNPATH`, which are searched after `LD_LIBRARY_PATH`.
+   */
+  struct CharVec runpath;
+  /**
+   * The names of the bits set in `DT_FLAGS` (Like: ORIGIN, BIND_NOW, STATIC_TLS). Unknown bits are written in hexadecimal.
+   */
+  struct CharVec flags;
+  /**
+   * The names of the bits set in `DT_FLAGS_1` (Like: NOW, NODELETE, NOOPEN, ORIGIN, PIE). Unknown bits are written in hexadecimal.
+   */
+  struct CharVec flags_1;
+  /**
+   * The auditing libraries in `DT_AUDIT` and `DT_DEPAUDIT`.
+   */
+  struct CharVec audit;
+  /**
+   * The libraries the object is a standard filter of (`DT_FILTER`).
+   */
+  struct CharVec filter;
+  /**
+   * The libraries the object is an auxiliary filter of (`DT_AUXILIARY`).
+   */
+  struct CharVec auxiliary;
+  /**
+   * The field is true if the object has relocations against read-only segments (`DT_TEXTREL` or `DF_TEXTREL`).
+   */
+  bool has_textrel;
+  /**
+   * The address of the initialization function (`DT_INIT`).
+   */
+  uint64_t init;
+  /**
+   * The address of the termination function (`DT_FINI`).
+   */
+  uint64_t fini;
+  /**
+   * The functions in `DT_PREINIT_ARRAY`.
+   */
+  struct U64Vec preinit_array;
+  /**
+   * The functions in `DT_INIT_ARRAY`.
+   */
+  struct U64Vec init_array;
+  /**
+   * The functions in `DT_FINI_ARRAY`.
+   */
+  struct U64Vec fini_array;
+} ElfDynamicInfo;
+
+/**
+ *
+ * A struct contains the layout of a section or a segment.
+ *
+ * Fields which the executable format doesn't have will be null pointers or `0`.
+ *
+ */
+typedef struct SectionInfo {
+  /**
+   * The name of the section or the segment. ELF, NE and LE/LX segments don't have names, so their types are used instead (Like: PT_LOAD, CODE).
+   */
+  char *name;
+  /**
+   * The name of the segment the section belongs to. Mach-O only.
+   */
+  char *segment_name;
+  /**
+   * The type of the ELF or XCOFF section (Like: SHT_PROGBITS, STYP_TEXT). ELF and XCOFF only.
+   */
+  char *section_type;
+  /**
+   * The address of the section in the memory. The image base is added to the addresses of PE sections.
+   */
+  uint64_t virtual_address;
+  /**
+   * The size of the section in the memory.
+   */
+  uint64_t virtual_size;
+  /**
+   * The offset of the section in the file.
+   */
+  uint64_t file_offset;
+  /**
+   * The size of the section in the file. It is `0` for sections which are not stored in the file (Like: .bss).
+   */
+  uint64_t file_size;
+  /**
+   * The memory permissions like `ls` (Like: r-x). Mach-O sections have the permissions of their segments.
+   */
+  char *permissions;
+  /**
+   * The raw flags (`sh_flags`, `p_flags`, `Characteristics` or `flags` of Mach-O).
+   */
+  uint64_t flags;
+  /**
+   * The alignment of the section in bytes.
+   */
+  uint64_t alignment;
+} SectionInfo;
+
+/**
+ * A C-compatible vector for [`SectionInfo`].
+ */
+typedef struct SectionInfoVec {
+  uintptr_t capacity;
+  uintptr_t length;
+  struct SectionInfo *vec;
+} SectionInfoVec;
+
+/**
+ *
+ * A struct contains the metadata of Linux kernel modules which is found in the `.modinfo` and `__versions` sections.
+ *
+ * Fields which are not found in the module will be null pointers or blank vectors.
+ *
+ */
+typedef struct KernelModuleInfo {
+  /**
+   * The field is true if the object is a kernel module.
+   */
+  bool is_kernel_module;
+  /**
+   * The name of the module.
+   */
+  char *name;
+  /**
+   * The license of the module (Like: GPL, Dual BSD/GPL, Proprietary, etc.).
+   */
+  char *license;
+  /**
+   * The version magic string which has to match the kernel the module is loaded into.
+   */
+  char *vermagic;
+  /**
+   * The checksum of the source files of the module.
+   */
+  char *srcversion;
+  /**
+   * The version of the module.
+   */
+  char *version;
+  /**
+   * The description of the module.
+   */
+  char *description;
+  /**
+   * The authors of the module.
+   */
+  struct CharVec authors;
+  /**
+   * The device aliases which make the module get loaded automatically (Like: pci:v00008086d*).
+   */
+  struct CharVec aliases;
+  /**
+   * The CRCs of the kernel symbols the module uses as "name: crc" strings. They are only present if the kernel was built with `CONFIG_MODVERSIONS`.
+   */
+  struct CharVec symbol_versions;
+} KernelModuleInfo;
+
+/**
+ *
+ * A struct contains the build information which the Go linker embeds into the binaries (the output of `go version -m`).
+ *
+ * Fields which are not found in the binary will be null pointers or blank vectors.
+ *
+ */
+typedef struct GoBuildInfo {
+  /**
+   * The field is true if the build information is found.
+   */
+  bool is_go;
+  /**
+   * The version of Go which built the binary (Like: go1.22.5).
+   */
+  char *go_version;
+  /**
+   * The package path of the main package (Like: github.com/foo/bar/cmd/bar).
+   */
+  char *path;
+  /**
+   * The main module as "path version checksum". It is a null pointer if the binary is not built in module mode.
+   */
+  char *main_module;
+  /**
+   * The dependency modules as "path version checksum" strings. Replaced modules are followed by " => path version checksum".
+   */
+  struct CharVec dependencies;
+  /**
+   * The build settings as "key=value" strings (Like: CGO_ENABLED=1, GOOS=linux, -ldflags=-s -w).
+   */
+  struct CharVec build_settings;
+} GoBuildInfo;
+
+/**
+ *
+ * A struct contains the information about objects built by rustc.
+ *
+ * Fields which are not found in the object will be null pointers or blank vectors.
+ *
+ */
+typedef struct RustInfo {
+  /**
+   * The field is true if any trace of rustc is found (the fields below or the panic messages of the standard library).
+   */
+  bool is_rust;
+  /**
+   * The version of rustc in `.comment` (Like: 1.80.1 (3f5fd8dd4 2024-08-06)). ELF only.
+   */
+  char *rustc_version;
+  /**
+   * The commit hash of rustc in the paths of the standard library which are kept for panic messages.
+   */
+  char *rustc_commit;
+  /**
+   * The symbol mangling scheme (Legacy, v0 or Legacy and v0). It is a null pointer if the symbols are stripped.
+   */
+  char *mangling;
+  /**
+   * The crate the object is built from as "name version". It is only present if the object is built with `cargo auditable`.
+   */
+  char *root_crate;
+  /**
+   * The crates in the dependency tree as "name version (source, kind)" strings. They are only present if the object is built with `cargo auditable`.
+   */
+  struct CharVec dependencies;
+} RustInfo;
+
+/**
+ *
+ * A struct contains the information about WebAssembly modules and components.
+ *
+ * Fields which are not found in the binary will be null pointers, blank vectors or `0`.
+ *
+ */
+typedef struct WasmInfo {
+  /**
+   * The version in the header of the binary. It is `1` for modules and the draft version of the binary format (Like: 13) for components.
+   */
+  uint32_t version;
+  /**
+   * The field is true if the binary is a component of the component model.
+   */
+  bool is_component;
+  /**
+   * The name of the module in the `name` custom section.
+   */
+  char *module_name;
+  /**
+   * The version of WASI the binary targets (Preview 1 or Preview 2). It is a null pointer if it doesn't import any WASI function or interface.
+   */
+  char *wasi;
+  /**
+   * The imports as "module.name (kind)" strings for modules and "interface (kind)" strings for components (Like: wasi_snapshot_preview1.fd_write (function)).
+   */
+  struct CharVec imports;
+  /**
+   * The exports as "name (kind)" strings (Like: _start (function)).
+   */
+  struct CharVec exports;
+  /**
+   * The limits of the memories in 64 KiB pages (Like: min 17 pages, max 256 pages, shared).
+   */
+  struct CharVec memories;
+  /**
+   * The element types and the limits of the tables (Like: funcref: min 1 elements, max 1 elements).
+   */
+  struct CharVec tables;
+  /**
+   * The names of the custom sections in the order they appear (Like: name, producers, target_features).
+   */
+  struct CharVec custom_sections;
+  /**
+   * The features in the `target_features` custom section with their prefixes (+: used, -: disallowed, =: required).
+   */
+  struct CharVec target_features;
+} WasmInfo;
+
 /**
  *
- * An error struct for making error handling easy.
+ * A struct contains the information in the auxiliary header and the loader section of XCOFF objects.
  *
- * ## Error Codes
- * - \>0: Magic number of the unknown object (as `i64` (or `ìnt64_t))
- * - -1: Binary is corrupted
- * - -2: Unknown/Bad magic number
- * - -3: Error at reading and interpreting bytes
- * - -4: I/O Error at parsing the object
- * - -5: Buffer is too short to hold
- * - -6: Unknown error[^1]
- * - -7: Unimplemented executable format
+ * Object files usually don't have the full auxiliary header, so the fields read from it will be `0` or null pointers.
  *
- * [^1]: All errors thrown by goblin crate and my code are covered. Because of matching goblin's [`Error`](goblin::error::Error) is non-exhaustive, I included non-exhaustive path too.
+ */
+typedef struct XcoffInfo {
+  /**
+   * The address of the function descriptor of the entry point (`o_entry`).
+   */
+  uint64_t entry_point;
+  /**
+   * The address of the TOC anchor (`o_toc`).
+   */
+  uint64_t toc;
+  /**
+   * The module type (1L: single use, RO: read-only, RE: reentrant).
+   */
+  char *module_type;
+  /**
+   * The flags of the file header (Like: F_EXEC, F_DYNLOAD, F_SHROBJ).
+   */
+  struct CharVec flags;
+  /**
+   * The default library search path in the first import file ID of the loader section.
+   */
+  char *library_path;
+  /**
+   * The size of the text in bytes.
+   */
+  uint64_t text_size;
+  /**
+   * The size of the initialized data in bytes.
+   */
+  uint64_t data_size;
+  /**
+   * The size of the uninitialized data in bytes.
+   */
+  uint64_t bss_size;
+  /**
+   * The maximum stack size. It is `0` if the system default is used.
+   */
+  uint64_t max_stack;
+  /**
+   * The maximum data size. It is `0` if the system default is used.
+   */
+  uint64_t max_data;
+} XcoffInfo;
+
+/**
+ *
+ * A struct contains the information in the headers of MS-DOS, NE, LE and LX executables.
+ *
+ * Fields which are not found in the headers will be null pointers, blank vectors or `0`.
  *
  */
-typedef struct ParsingError {
-  int64_t code;
-  char *explanation;
-} ParsingError;
+typedef struct MzInfo {
+  /**
+   * The name of the module (the first entry of the resident names table).
+   */
+  char *module_name;
+  /**
+   * The description of the module (the first entry of the non-resident names table).
+   */
+  char *description;
+  /**
+   * The initial CS:IP as "segment:offset" (Like: 0001:0010). LE and LX use the object number instead of the segment.
+   *
+   * The segments of MS-DOS executables are relative to the start of the image.
+   */
+  char *entry_point;
+  /**
+   * The initial SS:SP as "segment:offset". LE and LX use the object number instead of the segment.
+   */
+  char *stack_pointer;
+  /**
+   * The flags of the header (Like: Multiple data, Library module, Compatible with Presentation Manager).
+   */
+  struct CharVec flags;
+  /**
+   * The version of the linker. NE only.
+   */
+  char *linker_version;
+  /**
+   * The number of the relocations in the MZ header. MS-DOS only.
+   */
+  uint16_t relocations;
+  /**
+   * The memory the program needs in addition to its image in bytes (`e_minalloc`). MS-DOS only.
+   */
+  uint32_t minimum_memory;
+  /**
+   * The memory the program requests in addition to its image in bytes (`e_maxalloc`). MS-DOS only.
+   */
+  uint32_t maximum_memory;
+  /**
+   * The DOS extenders found in the stub (Like: DOS/4GW, PMODE/W, CWSDPMI). MS-DOS executables are searched from the beginning instead of the stub.
+   */
+  struct CharVec extenders;
+  /**
+   * The executable packers found in the stub (Like: LZEXE, PKLITE, UPX). MS-DOS executables are searched from the beginning instead of the stub.
+   */
+  struct CharVec packers;
+} MzInfo;
 
 /**
- * A C-compatible vector for `Vec<String>`.
+ *
+ * A struct contains the information in the header of a.out executables and objects.
+ *
+ * The fields which Plan 9 doesn't have will be `0`.
+ *
  */
-typedef struct CharVec {
-  uintptr_t capacity;
-  uintptr_t length;
-  char **vec;
-} CharVec;
+typedef struct AoutInfo {
+  /**
+   * The magic number (OMAGIC, NMAGIC, ZMAGIC or QMAGIC). Plan 9 executables have the hexadecimal magic number instead.
+   */
+  char *magic;
+  /**
+   * The size of the text in bytes.
+   */
+  uint64_t text_size;
+  /**
+   * The size of the initialized data in bytes.
+   */
+  uint64_t data_size;
+  /**
+   * The size of the uninitialized data in bytes.
+   */
+  uint64_t bss_size;
+  /**
+   * The address of the entry point.
+   */
+  uint64_t entry_point;
+  /**
+   * The size of the symbol table in bytes. It is `0` if the symbol table is stripped.
+   */
+  uint64_t symbols_size;
+  /**
+   * Whether the executable is dynamically linked (`EX_DYNAMIC` on BSD and the dynamic bit on SunOS).
+   */
+  bool is_dynamic;
+} AoutInfo;
+
+/**
+ *
+ * A struct contains the information in Java class files.
+ *
+ * The names of the classes are written with dots (Like: java.lang.Object).
+ *
+ */
+typedef struct JavaInfo {
+  /**
+   * The major version of the class file (Like: 61).
+   */
+  uint16_t major_version;
+  /**
+   * The minor version of the class file. It is 65535 if the class uses the preview features.
+   */
+  uint16_t minor_version;
+  /**
+   * The Java release which introduced the class file version (Like: Java SE 17).
+   */
+  char *java_release;
+  /**
+   * The field is true if the class depends on the preview features of its Java release.
+   */
+  bool uses_preview_features;
+  /**
+   * The name of the class.
+   */
+  char *class_name;
+  /**
+   * The name of the super class. It is a null pointer for java.lang.Object and modules.
+   */
+  char *super_class;
+  /**
+   * The interfaces the class implements.
+   */
+  struct CharVec interfaces;
+  /**
+   * The access flags of the class (Like: ACC_PUBLIC, ACC_FINAL, ACC_SUPER).
+   */
+  struct CharVec access_flags;
+  /**
+   * The name of the source file in the `SourceFile` attribute. Obfuscators usually remove it.
+   */
+  char *source_file;
+} JavaInfo;
+
+/**
+ *
+ * A struct contains the information in the files of Android Runtime (DEX, OAT and VDEX).
+ *
+ * The counts which the format doesn't have will be `0`.
+ *
+ */
+typedef struct DexInfo {
+  /**
+   * The version of the format (Like: 039 for DEX, 230 for OAT and 027 for VDEX).
+   */
+  char *version;
+  /**
+   * The number of the classes defined in the DEX file.
+   */
+  uint32_t class_count;
+  /**
+   * The number of the strings in the DEX file.
+   */
+  uint32_t string_count;
+  /**
+   * The number of the types the DEX file refers to.
+   */
+  uint32_t type_count;
+  /**
+   * The number of the methods the DEX file refers to.
+   */
+  uint32_t method_count;
+  /**
+   * The number of the fields the DEX file refers to.
+   */
+  uint32_t field_count;
+  /**
+   * The number of the DEX files which the OAT or VDEX file was made from.
+   */
+  uint32_t dex_file_count;
+  /**
+   * The instruction set the OAT file was compiled for (Like: arm64).
+   *
+   * VDEX files don't record it, so it is taken from the name of their directory (Like: oat/arm64/base.vdex) if it is an instruction set.
+   */
+  char *instruction_set;
+} DexInfo;
+
+/**
+ *
+ * A struct contains the metadata of Debian (`.deb`) and RPM (`.rpm`) packages.
+ *
+ * The dependencies are the ones the package declares, so they can be compared with the libraries its objects need.
+ *
+ */
+typedef struct PackageInfo {
+  /**
+   * The format of the package (deb or rpm).
+   */
+  char *format;
+  /**
+   * The name of the package (Like: coreutils).
+   */
+  char *name;
+  /**
+   * The version of the package with its epoch if it has one (Like: 1:2.10-3 for deb and 1:9.4-3.fc40 for rpm).
+   */
+  char *version;
+  /**
+   * The architecture of the package (Like: amd64 for deb and x86_64 for rpm).
+   */
+  char *architecture;
+  /**
+   * The packages and the capabilities the package depends on with their versions (Like: libc6 (>= 2.34) for deb and libc.so.6(GLIBC_2.34)(64bit) for rpm).
+   *
+   * The `Pre-Depends` and `Depends` fields are read from deb packages and the requirements except the ones on the features of rpm itself (rpmlib(...)) are read from rpm packages.
+   */
+  struct CharVec dependencies;
+} PackageInfo;
 
 /**
  *
@@ -83,7 +975,9 @@ typedef struct ULDDObj {
    */
   char *file_type;
   /**
-   * The field is true if the object was stripped from debug symbols otherwise it is not stripped or the object is an erroneous one .
+   * The field is true if the object has neither a symbol table nor debug information otherwise it is not stripped or the object is an erroneous one.
+   *
+   * See `stripping` for the details.
    */
   bool is_stripped;
   /**
@@ -93,7 +987,9 @@ typedef struct ULDDObj {
   /**
    * The specific CPU model the object compiled for.
    *
-   * macOS only field. It is null pointer in other executable formats.
+   * macOS and XCOFF only field. It is null pointer in other executable formats.
+   *
+   * It returns the CPU type in the auxiliary header of XCOFF objects (Like: POWER7).
    */
   char *cpu_subtype;
   /**
@@ -108,8 +1004,167 @@ typedef struct ULDDObj {
    * A vector of libraries linked against the object.
    *
    * It is blank in COFF files because they are mostly PE object files therefore they don't have linked libraries against them.
+   *
+   * The names of the assemblies which .NET assemblies reference (Like: System.Runtime) follow their native libraries. Their display names are in `dotnet`.
    */
   struct CharVec libraries;
+  /**
+   * The information decoded from the debug directory of the object.
+   *
+   * PE only field. It is blank in other executable formats.
+   */
+  struct PeDebugInfo pe_debug;
+  /**
+   * The metadata of .NET assemblies.
+   *
+   * PE only field. It is blank in other executable formats and native PE files.
+   */
+  struct DotNetInfo dotnet;
+  /**
+   * The information about how the object is loaded and started (entry point, TLS callbacks and load configuration).
+   *
+   * PE only field. It is blank in other executable formats.
+   */
+  struct PeLoadInfo pe_load;
+  /**
+   * The results of the overlay, checksum and section heuristics.
+   *
+   * PE only field. It is blank in other executable formats.
+   */
+  struct PeAnomalies pe_anomalies;
+  /**
+   * The MD5 hash of the imports of the object, which is used to cluster similar objects.
+   *
+   * - PE: imphash (compatible with pefile)
+   * - Mach-O: symhash (undefined external symbols)
+   * - ELF: Sorted names of the undefined dynamic symbols
+   *
+   * It is a null pointer if the object doesn't import anything or the executable format is not one of the above.
+   */
+  char *import_hash;
+  /**
+   * The minimum version of the OS the object requires, if the object records it.
+   *
+   * - ELF: The minimum kernel version in `NT_GNU_ABI_TAG`, the API level in the Android note or the OS version in the BSD ident notes
+   * - WebAssembly: The version of the WASI interfaces the component imports (Like: 0.2.0)
+   * - NE: The expected version of Windows (Like: 3.10)
+   *
+   * It is a null pointer if the object doesn't record it or the executable format is not one of the above.
+   */
+  char *os_version;
+  /**
+   * The build ID and the links to the separate debug files.
+   *
+   * ELF only field. It is blank in other executable formats.
+   */
+  struct ElfDebugInfo elf_debug;
+  /**
+   * The classification of the symbols and the debug information left in the object.
+   */
+  struct StrippingInfo stripping;
+  /**
+   * The information about the crashed process.
+   *
+   * ELF core dump only field. It is blank in other objects.
+   */
+  struct CoreDumpInfo core;
+  /**
+   * The metadata of the kernel module.
+   *
+   * Linux kernel module only field. It is blank in other objects. The modules it depends on are listed in `libraries`.
+   */
+  struct KernelModuleInfo kernel_module;
+  /**
+   * The compilers, linkers and other tools which built the object, with their versions and options when recorded.
+   *
+   * - ELF: `.comment`, `DW_AT_producer` of the compilation units, `.GCC.command.line`, `.note.gnu.property` and the Go/Rust markers (with the Go version in `go`)
+   * - WebAssembly: The `producers` custom section as "field: name version" strings (Like: processed-by: rustc 1.80.1)
+   *
+   * It is empty if the object doesn't record them or the executable format is not one of the above.
+   */
+  struct CharVec toolchain;
+  /**
+   * The build information embedded by the Go linker.
+   *
+   * ELF, PE and Mach-O only field. It is blank in other executable formats and binaries not built by Go.
+   */
+  struct GoBuildInfo go;
+  /**
+   * The traces rustc and cargo-auditable leave in the object.
+   *
+   * ELF, PE and Mach-O only field. It is blank in other executable formats and objects not built by rustc.
+   */
+  struct RustInfo rust;
+  /**
+   * The entries of the dynamic section other than the needed libraries (which are listed in `libraries`).
+   *
+   * ELF only field. It is blank in other executable formats and statically linked objects.
+   */
+  struct ElfDynamicInfo elf_dynamic;
+  /**
+   * The sections of the object.
+   *
+   * - ELF: Section headers
+   * - PE and COFF: Section table
+   * - Mach-O: Sections of all segments
+   *
+   * It is only filled if `list_sections` of [`ULDDOptions`] is true.
+   */
+  struct SectionInfoVec sections;
+  /**
+   * The segments of the object.
+   *
+   * - ELF: Program headers
+   * - Mach-O: Segments
+   * - NE: Segment table
+   * - LE and LX: Object table
+   *
+   * It is only filled if `list_sections` of [`ULDDOptions`] is true.
+   */
+  struct SectionInfoVec segments;
+  /**
+   * The imports, exports, limits and custom sections of the WebAssembly module or component.
+   *
+   * WebAssembly only field. It is blank in other executable formats. The imported modules (or the interfaces of components) are listed in `libraries`.
+   */
+  struct WasmInfo wasm;
+  /**
+   * The auxiliary header and the loader section of the XCOFF object.
+   *
+   * XCOFF only field. It is blank in other executable formats. The imported libraries are listed in `libraries`.
+   */
+  struct XcoffInfo xcoff;
+  /**
+   * The headers of MS-DOS executables and the executables which are behind the MZ stub of MS-DOS, except PE.
+   *
+   * MS-DOS, NE, LE and LX only field. It is blank in other executable formats. The imported modules are listed in `libraries` and the segments (or the objects) are listed in `segments`.
+   */
+  struct MzInfo mz;
+  /**
+   * The header of the a.out executable or object.
+   *
+   * a.out only field. It is blank in other executable formats. `os_type` is guessed from the byte order of the header, the magic number and the machine ID.
+   */
+  struct AoutInfo aout;
+  /**
+   * The version, the names and the access flags of the Java class file.
+   *
+   * Java class file only field. It is blank in other executable formats. JARs are not objects themselves, their native libraries are parsed as members instead.
+   */
+  struct JavaInfo java;
+  /**
+   * The version and the sizes of the DEX file, or the version and the instruction set of the OAT or VDEX file.
+   *
+   * DEX, VDEX and OAT only field. It is blank in other executable formats. OAT files are ELF shared objects which export `oatdata`, so their `os_type` is Android.
+   */
+  struct DexInfo dex;
+  /**
+   * The metadata of the package which the object was installed from.
+   *
+   * It is blank if the object is not in a `.deb` or `.rpm` package. The objects in packages are named with the paths they are installed to (Like: /usr/bin/ls). The packages are given as objects as well,
+   * which only have `file_name`, `member_name`, `file_type` (Debian package or RPM package) and this field, so their metadata is given even if they have no objects.
+   */
+  struct PackageInfo package;
 } ULDDObj;
 
 /**
@@ -129,6 +1184,30 @@ typedef struct ULDDObjResultVec {
   struct ULDDObjResult *vec;
 } ULDDObjResultVec;
 
+/**
+ *
+ * Options to change how the objects are parsed.
+ *
+ * [`read_obj`](crate::read_obj) uses the default options (all fields are `false`) except `debugging`.
+ *
+ */
+typedef struct ULDDOptions {
+  /**
+   * Prints the information about the parsing process.
+   */
+  bool debugging;
+  /**
+   * Searches the separate debug files of ELF objects under `/usr/lib/debug` and next to the file.
+   *
+   * It reads files from the disk, so `file_name` given to [`read_obj_with_options`](crate::read_obj_with_options) should be a path.
+   */
+  bool find_debug_files;
+  /**
+   * Lists the sections and the segments of the objects in `sections` and `segments` of [`ULDDObj`].
+   */
+  bool list_sections;
+} ULDDOptions;
+
 /**
  *
  * Parses the given buffer and returns a vector of parsed binaries.
@@ -145,6 +1224,20 @@ struct ULDDObjResultVec read_obj(const char *file_name,
                                  uintptr_t buffer_size,
                                  bool debugging);
 
+/**
+ *
+ * Parses the given buffer with the given [`ULDDOptions`] and returns a vector of parsed binaries.
+ *
+ * # Safety
+ *
+ * It has the same requirements as [`read_obj`].
+ *
+ */
+struct ULDDObjResultVec read_obj_with_options(const char *file_name,
+                                              const uint8_t *buffer,
+                                              uintptr_t buffer_size,
+                                              struct ULDDOptions options);
+
 /**
  *
  * # Safety
@@ -0,0 +1,299 @@
+use crate::{
+    debug::{classify_stripping, debug_objects, option_to_c_string},
+    impls::StringToCString,
+    structs::{AoutInfo, CharVec, ParsingError, ULDDObj, ULDDObjResult, ULDDOptions},
+    types::{AoutFileType, DebugInfoFormat, DebugInfoLocation, AOUT_MACHINE, AOUT_PLAN9_MAGIC},
+};
+use scroll::{Endian, Pread};
+use std::ptr::null_mut;
+
+/*
+Sources:
+    https://man.netbsd.org/a.out.5
+    https://github.com/NetBSD/src/blob/trunk/sys/sys/exec_aout.h (the machine IDs)
+    https://github.com/torvalds/linux/blob/v5.0/include/uapi/linux/a.out.h
+    https://9p.io/magic/man2html/6/a.out
+*/
+
+const OMAGIC: u16 = 0o407;
+const NMAGIC: u16 = 0o410;
+const ZMAGIC: u16 = 0o413;
+const QMAGIC: u16 = 0o314;
+const HEADER_SIZE: usize = 32;
+// Linux places the text of ZMAGIC executables at the second kilobyte
+const LINUX_ZMAGIC_TEXT_OFFSET: usize = 1024;
+const NLIST_SIZE: usize = 12;
+// The symbols which have any of these type bits are stabs
+const N_STAB: u8 = 0xe0;
+
+// The flags in the upper 6 bits of `a_midmag`
+const EX_PIC: u8 = 0x10;
+const EX_DYNAMIC: u8 = 0x20;
+const SUNOS_DYNAMIC: u8 = 0x80;
+
+// Plan 9 headers are followed by a 64-bit entry point if this bit is set
+const PLAN9_HDR_MAGIC: u32 = 0x8000;
+const PLAN9_HEADER_SIZE_64: usize = 40;
+
+const MID_PC386: u16 = 100;
+const MID_I386: u16 = 134;
+const MID_64_BIT: [u16; 7] = [141, 148, 155, 156, 157, 159, 160];
+
+#[derive(Clone, Copy, PartialEq)]
+enum AoutVariant {
+    // `a_info` in the host byte order (Linux and FreeBSD on little-endian machines)
+    HostOrder,
+    // `a_midmag` in the network byte order (NetBSD and OpenBSD)
+    NetworkOrder,
+    // The header of SunOS which has the dynamic bit and the tool version before the machine type
+    SunOS,
+    Plan9,
+}
+
+struct AoutHeader {
+    variant: AoutVariant,
+    magic: u16,
+    machine: u16,
+    flags: u8,
+    endian: Endian,
+}
+
+fn is_magic(magic: u16) -> bool {
+    [OMAGIC, NMAGIC, ZMAGIC, QMAGIC].contains(&magic)
+}
+
+fn magic_name(magic: u16) -> &'static str {
+    match magic {
+        OMAGIC => "OMAGIC",
+        NMAGIC => "NMAGIC",
+        ZMAGIC => "ZMAGIC",
+        _ => "QMAGIC",
+    }
+}
+
+// The sizes of the text, the data, the symbols and the relocations have to fit in the file
+fn fits(buffer: &[u8], endian: Endian) -> bool {
+    let total = [4, 8, 16, 24, 28]
+        .iter()
+        .map(|offset| {
+            buffer
+                .pread_with::<u32>(*offset, endian)
+                .map_or(u64::MAX, |size| size as u64)
+        })
+        .fold(0u64, |total, size| total.saturating_add(size));
+
+    total <= buffer.len() as u64
+}
+
+fn detect(buffer: &[u8]) -> Option<AoutHeader> {
+    let bytes = buffer.get(0..4)?;
+    if buffer.len() < HEADER_SIZE {
+        return None;
+    }
+
+    let plan9_magic = buffer.pread_with::<u32>(0, scroll::BE).ok()?;
+    if AOUT_PLAN9_MAGIC.contains_key(&plan9_magic) {
+        return Some(AoutHeader {
+            variant: AoutVariant::Plan9,
+            magic: 0,
+            machine: 0,
+            flags: 0,
+            endian: scroll::BE,
+        });
+    }
+
+    let host_magic = u16::from_le_bytes([bytes[0], bytes[1]]);
+    let host_machine = bytes[2] as u16;
+    if is_magic(host_magic)
+        && [0, MID_PC386, MID_I386, 151, 152].contains(&host_machine)
+        && fits(buffer, scroll::LE)
+    {
+        return Some(AoutHeader {
+            variant: AoutVariant::HostOrder,
+            magic: host_magic,
+            machine: host_machine,
+            flags: bytes[3] >> 2,
+            endian: scroll::LE,
+        });
+    }
+
+    let network_magic = u16::from_be_bytes([bytes[2], bytes[3]]);
+    if !is_magic(network_magic) {
+        return None;
+    }
+    // The rest of the header is in the byte order of the machine, which is guessed from the sizes
+    let endian = if fits(buffer, scroll::BE) {
+        scroll::BE
+    } else if fits(buffer, scroll::LE) {
+        scroll::LE
+    } else {
+        return None;
+    };
+    let machine = (((bytes[0] & 0x03) as u16) << 8) | bytes[1] as u16;
+    if machine >= MID_PC386 && AOUT_MACHINE.contains_key(&machine) {
+        return Some(AoutHeader {
+            variant: AoutVariant::NetworkOrder,
+            magic: network_magic,
+            machine,
+            flags: bytes[0] >> 2,
+            endian,
+        });
+    }
+    // 1: 68010, 2: 68020, 3: SPARC
+    (1..=3).contains(&bytes[1]).then_some(AoutHeader {
+        variant: AoutVariant::SunOS,
+        magic: network_magic,
+        machine: bytes[1] as u16,
+        flags: bytes[0],
+        endian: scroll::BE,
+    })
+}
+
+fn guess_os(header: &AoutHeader) -> Option<&'static str> {
+    match (header.variant, header.machine) {
+        (AoutVariant::Plan9, _) => Some("Plan 9"),
+        (AoutVariant::SunOS, _) => Some("SunOS"),
+        (AoutVariant::HostOrder, MID_I386) => Some("FreeBSD"),
+        // Only Linux uses QMAGIC with the little-endian header
+        (AoutVariant::HostOrder, MID_PC386) => Some("Linux"),
+        (AoutVariant::HostOrder, _) if header.magic == QMAGIC => Some("Linux"),
+        (AoutVariant::NetworkOrder, MID_PC386) => Some("386BSD"),
+        (AoutVariant::NetworkOrder, _) => Some("NetBSD"),
+        _ => None,
+    }
+}
+
+fn cpu_name(header: &AoutHeader, plan9_magic: u32) -> Option<&'static str> {
+    match header.variant {
+        AoutVariant::Plan9 => AOUT_PLAN9_MAGIC.get(&plan9_magic).copied(),
+        AoutVariant::SunOS => match header.machine {
+            1 => Some("Motorola 68010"),
+            2 => Some("Motorola 68020"),
+            _ => Some("SPARC"),
+        },
+        _ => AOUT_MACHINE.get(&header.machine).copied(),
+    }
+}
+
+// Looks for the stabs in the symbol table which follows the text, the data and the relocations
+fn has_stabs(buffer: &[u8], header: &AoutHeader, symbols_size: u64) -> bool {
+    let read = |offset: usize| {
+        buffer
+            .pread_with::<u32>(offset, header.endian)
+            .map_or(0, |value| value as usize)
+    };
+    let text_offset = match header.magic {
+        ZMAGIC if header.variant == AoutVariant::HostOrder && header.machine == MID_PC386 => {
+            LINUX_ZMAGIC_TEXT_OFFSET
+        }
+        ZMAGIC | QMAGIC => 0,
+        _ => HEADER_SIZE,
+    };
+    let symbols = text_offset + read(4) + read(8) + read(24) + read(28);
+
+    buffer
+        .get(symbols..symbols.saturating_add(symbols_size as usize))
+        .is_some_and(|table| {
+            table
+                .chunks_exact(NLIST_SIZE)
+                .any(|symbol| symbol[4] & N_STAB != 0)
+        })
+}
+
+///
+/// Parses the a.out executables and objects.
+///
+/// The magic numbers of a.out are too short to be told apart from other data, so it returns `None` if the sizes in the header don't fit in the buffer.
+///
+pub(crate) fn parse_aout(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+) -> Option<ULDDObjResult> {
+    let header = detect(buffer)?;
+    let read = |offset: usize| {
+        buffer
+            .pread_with::<u32>(offset, header.endian)
+            .map_or(0, |value| value as u64)
+    };
+    let plan9_magic = read(0) as u32;
+    let is_plan9_64 = header.variant == AoutVariant::Plan9 && plan9_magic & PLAN9_HDR_MAGIC != 0;
+    let (text_size, data_size, bss_size, symbols_size) = (read(4), read(8), read(12), read(16));
+    let relocations_size = match header.variant {
+        AoutVariant::Plan9 => 0,
+        _ => read(24) + read(28),
+    };
+    let entry_point = if is_plan9_64 {
+        buffer
+            .pread_with::<u64>(HEADER_SIZE, scroll::BE)
+            .ok()
+            .filter(|_| buffer.len() >= PLAN9_HEADER_SIZE_64)
+            .unwrap_or(0)
+    } else {
+        read(20)
+    };
+
+    let is_dynamic = match header.variant {
+        AoutVariant::SunOS => header.flags & SUNOS_DYNAMIC != 0,
+        AoutVariant::Plan9 => false,
+        _ => header.flags & EX_DYNAMIC != 0,
+    };
+    let is_pic = header.variant != AoutVariant::SunOS && header.flags & EX_PIC != 0;
+    let file_type = match header.magic {
+        _ if header.variant == AoutVariant::Plan9 => AoutFileType::Executable,
+        OMAGIC if relocations_size != 0 => AoutFileType::RelocatableObject,
+        _ if is_dynamic && is_pic => AoutFileType::SharedLibrary,
+        OMAGIC => AoutFileType::ImpureExecutable,
+        NMAGIC => AoutFileType::SharedTextExecutable,
+        _ => AoutFileType::DemandPagedExecutable,
+    };
+    let is_64 = is_plan9_64
+        || (header.variant == AoutVariant::NetworkOrder && MID_64_BIT.contains(&header.machine));
+    // Plan 9 symbol tables don't have stabs
+    let debug_info =
+        if header.variant != AoutVariant::Plan9 && has_stabs(buffer, &header, symbols_size) {
+            vec![(DebugInfoFormat::STABS, DebugInfoLocation::Embedded)]
+        } else {
+            vec![]
+        };
+    let (is_stripped, stripping) = classify_stripping(symbols_size != 0, &debug_info);
+    let magic = match header.variant {
+        AoutVariant::Plan9 => format!("{:#x}", plan9_magic),
+        _ => magic_name(header.magic).to_owned(),
+    };
+    debug_objects(
+        file_name,
+        member_names,
+        "an a.out binary",
+        options.debugging,
+    );
+
+    Some(ULDDObjResult {
+        error: ParsingError::default(),
+        obj: ULDDObj {
+            file_name: file_name.to_c_string(),
+            member_name: CharVec::from(member_names),
+            executable_format: "a.out".to_c_string(),
+            is_64,
+            os_type: option_to_c_string(guess_os(&header)),
+            file_type: file_type.to_c_string(),
+            is_stripped,
+            cpu_type: option_to_c_string(cpu_name(&header, plan9_magic)),
+            cpu_subtype: null_mut(),
+            interpreter: null_mut(),
+            libraries: CharVec::default(),
+            stripping,
+            aout: AoutInfo {
+                magic: magic.to_c_string(),
+                text_size,
+                data_size,
+                bss_size,
+                entry_point,
+                symbols_size,
+                is_dynamic,
+            },
+            ..Default::default()
+        },
+    })
+}
@@ -1,29 +1,43 @@
 use crate::{
     debug::{debug_objects, merge_members},
     parse_objects,
-    structs::{CharVec, Debugging, ParsingError, ULDDObj, ULDDObjResult},
+    structs::{CharVec, Debugging, ParsingError, ULDDObj, ULDDObjResult, ULDDOptions},
+    zip::Budget,
 };
-use goblin::archive::Archive;
+use goblin::{archive::Archive, error::Error};
+use std::collections::{BTreeMap, HashSet};
 use crate::impls::{ErrorToInt, StringToCString};
 
+/*
+Source of the big archive format:
+    https://www.ibm.com/docs/en/aix/7.3?topic=formats-ar-file-format-big
+*/
+
+pub(crate) const BIG_ARCHIVE_MAGIC: &[u8] = b"<bigaf>\n";
+const BIG_ARCHIVE_HEADER_SIZE: usize = 128;
+const BIG_MEMBER_HEADER_SIZE: usize = 112;
+
 pub(crate) fn parse_archive<'a>(
     file_name: &'a str,
     buffer: &'a [u8],
     archive: Archive<'a>,
     member_names: &mut Vec<&'a str>,
     objects: &mut Vec<ULDDObjResult>,
-    debugging: bool,
+    budget: &mut Budget,
+    options: &ULDDOptions,
 ) {
     for member in archive.members() {
-        member_names.push(file_name);
+        // The members which are skipped or are containers don't take the names, so the names are copied for each member
+        let mut names: Vec<&str> = member_names.to_vec();
+        names.push(file_name);
         let member_buffer = match archive.extract(member, buffer) {
             Ok(buf) => buf,
             Err(error) => {
                 Debugging::Error(format!("Error while extracting the bytes of the member named '{}' from buffer of the file named '{}'{}\nDetails:\n{}",
                     member,
                     file_name,
-                    merge_members(member_names),
-                    error)).print(debugging);
+                    merge_members(&mut names),
+                    error)).print(options.debugging);
                 return objects.push(ULDDObjResult {
                     error: ParsingError {
                         code: error.to_int(),
@@ -31,14 +45,168 @@ pub(crate) fn parse_archive<'a>(
                     },
                     obj: ULDDObj {
                         file_name: file_name.to_c_string(),
-                        member_name: CharVec::from(member_names),
+                        member_name: CharVec::from(&mut names),
                         file_type: "Archive".to_c_string(),
                         ..Default::default()
                     },
                 });
             }
         };
-        debug_objects(file_name, member_names, "an archive file", debugging);
-        parse_objects(member, member_buffer, member_names, objects, debugging);
+        debug_objects(file_name, &mut names, "an archive file", options.debugging);
+        parse_objects(member, member_buffer, &mut names, objects, budget, options);
+    }
+}
+
+// The numbers in the headers of big archives are decimal strings padded with spaces
+fn read_decimal(buffer: &[u8], offset: usize, length: usize) -> Result<u64, Error> {
+    let field = buffer.get(offset..offset + length).ok_or_else(|| {
+        Error::Malformed(format!(
+            "The header field at {:#x} is out of the archive",
+            offset
+        ))
+    })?;
+    let field = String::from_utf8_lossy(field);
+    let field = field.trim_matches([' ', '\0']);
+    if field.is_empty() {
+        return Ok(0);
+    }
+    field.parse::<u64>().map_err(|_| {
+        Error::Malformed(format!(
+            "The header field at {:#x} is not a number: {}",
+            offset, field
+        ))
+    })
+}
+
+// Returns the name, the bytes, the end of the member and the offset of the next member
+fn read_big_member(buffer: &[u8], offset: usize) -> Result<(&str, &[u8], usize, u64), Error> {
+    let out_of_archive = |part: &str| {
+        Error::Malformed(format!(
+            "The {} of the member at {:#x} is out of the archive",
+            part, offset
+        ))
+    };
+    // The fields are inside the header, so their offsets don't overflow if the end of the header doesn't
+    let name_start = offset
+        .checked_add(BIG_MEMBER_HEADER_SIZE)
+        .ok_or_else(|| out_of_archive("header"))?;
+    let size = read_decimal(buffer, offset, 20)? as usize;
+    let next = read_decimal(buffer, offset + 20, 20)?;
+    let name_length = read_decimal(buffer, offset + 108, 4)? as usize;
+    let name_end = name_start
+        .checked_add(name_length)
+        .ok_or_else(|| out_of_archive("name"))?;
+    let name = buffer
+        .get(name_start..name_end)
+        .ok_or_else(|| out_of_archive("name"))?;
+    // The name is padded to an even length and followed by "`\n"
+    let data_start = name_end + name_length % 2 + 2;
+    let data_end = data_start
+        .checked_add(size)
+        .ok_or_else(|| out_of_archive("data"))?;
+    let data = buffer
+        .get(data_start..data_end)
+        .ok_or_else(|| out_of_archive("data"))?;
+
+    Ok((
+        std::str::from_utf8(name).unwrap_or_default(),
+        data,
+        data_end,
+        next,
+    ))
+}
+
+///
+/// Parses the members of AIX big archives (`<bigaf>`), which goblin doesn't support.
+///
+/// The members are a doubly linked list which starts at the first member offset of the fixed-length header.
+///
+pub(crate) fn parse_big_archive<'a>(
+    file_name: &'a str,
+    buffer: &'a [u8],
+    member_names: &mut Vec<&'a str>,
+    objects: &mut Vec<ULDDObjResult>,
+    budget: &mut Budget,
+    options: &ULDDOptions,
+) {
+    let mut push_error = |error: Error, member_names: &mut Vec<&'a str>| {
+        Debugging::Error(format!(
+            "Error while reading the big archive named '{}'{}\nDetails:\n{}",
+            file_name,
+            merge_members(member_names),
+            error
+        ))
+        .print(options.debugging);
+        objects.push(ULDDObjResult {
+            error: ParsingError {
+                code: error.to_int(),
+                explanation: error.to_c_string(),
+            },
+            obj: ULDDObj {
+                file_name: file_name.to_c_string(),
+                member_name: CharVec::from(member_names),
+                file_type: "Archive".to_c_string(),
+                ..Default::default()
+            },
+        });
+    };
+    if buffer.len() < BIG_ARCHIVE_HEADER_SIZE {
+        return push_error(
+            Error::BufferTooShort(BIG_ARCHIVE_HEADER_SIZE, "bytes"),
+            member_names,
+        );
+    }
+    let (mut offset, last) = match (read_decimal(buffer, 68, 20), read_decimal(buffer, 88, 20)) {
+        (Ok(first), Ok(last)) => (first, last),
+        (Err(error), _) | (_, Err(error)) => return push_error(error, member_names),
+    };
+
+    // The offsets are remembered to not loop forever on corrupted archives and
+    // the spans of the members (by their starts) to reject the members which share their bytes
+    let mut visited = HashSet::new();
+    let mut spans: BTreeMap<usize, usize> = BTreeMap::new();
+    let mut members = vec![];
+    while offset != 0 && visited.insert(offset) {
+        match read_big_member(buffer, offset as usize) {
+            Ok((name, data, end, next)) => {
+                let start = offset as usize;
+                let overlaps = spans
+                    .range(..end)
+                    .next_back()
+                    .is_some_and(|(_, e)| *e > start);
+                if overlaps {
+                    push_error(
+                        Error::Malformed(format!(
+                            "The member at {:#x} overlaps another member",
+                            offset
+                        )),
+                        member_names,
+                    );
+                    break;
+                }
+                spans.insert(start, end);
+                members.push((name, data));
+                if offset == last {
+                    break;
+                }
+                offset = next;
+            }
+            Err(error) => {
+                push_error(error, member_names);
+                break;
+            }
+        }
+    }
+
+    for (name, data) in members {
+        let mut names: Vec<&str> = member_names.to_vec();
+        names.push(file_name);
+        debug_objects(
+            file_name,
+            &mut names,
+            "a big archive file",
+            options.debugging,
+        );
+        parse_objects(name, data, &mut names, objects, budget, options);
     }
 }
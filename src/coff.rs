@@ -1,28 +1,99 @@
 use crate::{
-    debug::debug_objects,
-    structs::{CharVec, ParsingError, ULDDObj, ULDDObjResult},
-    types::PE_ARCH,
+    debug::{classify_stripping, debug_objects, permissions_to_c_string},
+    structs::{
+        CharVec, ParsingError, SectionInfo, SectionInfoVec, ULDDObj, ULDDObjResult, ULDDOptions,
+    },
+    types::{DebugInfoFormat, DebugInfoLocation, PE_ARCH},
 };
 use goblin::pe::{
-    characteristic::{IMAGE_FILE_32BIT_MACHINE, IMAGE_FILE_DEBUG_STRIPPED},
+    characteristic::IMAGE_FILE_32BIT_MACHINE,
+    section_table::{
+        SectionTable, IMAGE_SCN_ALIGN_MASK, IMAGE_SCN_MEM_EXECUTE, IMAGE_SCN_MEM_READ,
+        IMAGE_SCN_MEM_WRITE,
+    },
     Coff,
 };
 use std::ptr::null_mut;
 use crate::debug::option_to_c_string;
 use crate::impls::StringToCString;
 
+///
+/// Lists the section table of PE and COFF objects.
+///
+/// Images align all sections by `SectionAlignment` of the optional header while object files store the alignment in the characteristics.
+///
+pub(crate) fn list_sections_coff(
+    sections: &[SectionTable],
+    image_base: u64,
+    section_alignment: Option<u32>,
+) -> SectionInfoVec {
+    let sections: Vec<SectionInfo> = sections
+        .iter()
+        .map(|s| {
+            let alignment = section_alignment.map(|a| a as u64).unwrap_or_else(|| {
+                match (s.characteristics & IMAGE_SCN_ALIGN_MASK) >> 20 {
+                    0 => 0,
+                    n => 1 << (n - 1),
+                }
+            });
+            let name = s
+                .real_name
+                .clone()
+                .or_else(|| s.name().ok().map(|name| name.to_owned()));
+            SectionInfo {
+                name: option_to_c_string(name),
+                segment_name: null_mut(),
+                section_type: null_mut(),
+                // The image base comes from the file and the address wraps around like the RVAs of the loader do
+                virtual_address: image_base.wrapping_add(s.virtual_address as u64),
+                virtual_size: s.virtual_size as u64,
+                file_offset: s.pointer_to_raw_data as u64,
+                file_size: s.size_of_raw_data as u64,
+                permissions: permissions_to_c_string(
+                    s.characteristics & IMAGE_SCN_MEM_READ != 0,
+                    s.characteristics & IMAGE_SCN_MEM_WRITE != 0,
+                    s.characteristics & IMAGE_SCN_MEM_EXECUTE != 0,
+                ),
+                flags: s.characteristics as u64,
+                alignment,
+            }
+        })
+        .collect();
+
+    SectionInfoVec::from(sections)
+}
+
 pub(crate) fn parse_coff(
     file_name: &str,
     coff: Coff,
     member_names: &mut Vec<&str>,
-    debugging: bool,
+    options: &ULDDOptions,
 ) -> ULDDObjResult {
     // Thanks to developers of goblin for making me to find out that I can "bitwise and" characteristics and wanted characteristics to find out if the COFF file has the one we want
     let is_64 = coff.header.characteristics & IMAGE_FILE_32BIT_MACHINE != IMAGE_FILE_32BIT_MACHINE;
-    let is_stripped =
-        coff.header.characteristics & IMAGE_FILE_DEBUG_STRIPPED == IMAGE_FILE_DEBUG_STRIPPED;
+    // MSVC puts CodeView records into .debug$S, .debug$T, etc. sections of the object files
+    let debug_info: Vec<(DebugInfoFormat, DebugInfoLocation)> = coff
+        .sections
+        .iter()
+        .filter_map(|s| match s.name() {
+            Ok(name) if name.starts_with(".debug$") => {
+                Some((DebugInfoFormat::CodeView, DebugInfoLocation::Embedded))
+            }
+            Ok(name) if name.starts_with(".debug_") => {
+                Some((DebugInfoFormat::DWARF, DebugInfoLocation::Embedded))
+            }
+            _ => None,
+        })
+        .collect();
+    let has_symbols = coff.symbols.as_ref().is_some_and(|s| s.iter().next().is_some());
+    let (is_stripped, stripping) = classify_stripping(has_symbols, &debug_info);
     let cpu_type = option_to_c_string(PE_ARCH.get(&coff.header.machine));
-    debug_objects(file_name, member_names, "a COFF binary", debugging);
+    let sections = if options.list_sections {
+        list_sections_coff(&coff.sections, 0, None)
+    } else {
+        Default::default()
+    };
+    debug_objects(file_name, member_names, "a COFF binary", options.debugging);
     ULDDObjResult {
         error: ParsingError::default(),
         obj: ULDDObj {
@@ -37,6 +108,9 @@ pub(crate) fn parse_coff(
             cpu_subtype: null_mut(),
             interpreter: null_mut(),
             libraries: CharVec::default(),
+            stripping,
+            sections,
+            ..Default::default()
         },
     }
 }
@@ -0,0 +1,349 @@
+use crate::{
+    debug::{debug_objects, merge_members},
+    impls::{ErrorToInt, StringToCString},
+    is_object, parse_objects,
+    structs::{CharVec, Debugging, ParsingError, ULDDObj, ULDDObjResult, ULDDOptions},
+    zip::{Budget, MAX_NESTING_DEPTH},
+    PEEK_SIZE,
+};
+use flate2::read::MultiGzDecoder;
+use goblin::error::Error;
+use ruzstd::decoding::{
+    errors::{FrameDecoderError, ReadFrameHeaderError},
+    StreamingDecoder,
+};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{self, Write};
+
+/*
+Sources:
+    https://www.rfc-editor.org/rfc/rfc1952 (gzip)
+    https://tukaani.org/xz/xz-file-format.txt
+    https://www.rfc-editor.org/rfc/rfc8878 (Zstandard)
+*/
+
+pub(crate) const GZIP_MAGIC: &[u8] = b"\x1f\x8b\x08";
+pub(crate) const XZ_MAGIC: &[u8] = b"\xfd7zXZ\0";
+pub(crate) const ZSTD_MAGIC: &[u8] = b"\x28\xb5\x2f\xfd";
+// The magic numbers of the skippable frames are 0x184D2A50 to 0x184D2A5F and a stream may start with them
+const ZSTD_SKIPPABLE_MAGIC: &[u8] = b"\x2a\x4d\x18";
+/// The maximum size of a compressed stream after decompression.
+pub(crate) const MAX_DECOMPRESSED_SIZE: usize = 1024 * 1024 * 1024;
+
+// The suffixes are removed from the names of the streams to name their contents
+const SUFFIXES: [(&str, &str); 7] = [
+    (".tgz", ".tar"),
+    (".txz", ".tar"),
+    (".tzst", ".tar"),
+    (".gz", ""),
+    (".xz", ""),
+    (".zst", ""),
+    (".zstd", ""),
+];
+
+#[derive(Clone, Copy)]
+enum Compression {
+    Gzip,
+    Xz,
+    Zstd,
+}
+
+impl Compression {
+    fn detect(buffer: &[u8]) -> Option<Compression> {
+        [
+            (GZIP_MAGIC, Compression::Gzip),
+            (XZ_MAGIC, Compression::Xz),
+            (ZSTD_MAGIC, Compression::Zstd),
+        ]
+        .into_iter()
+        .find(|(magic, _)| buffer.starts_with(magic))
+        .map(|(_, compression)| compression)
+        .or_else(|| {
+            (buffer.first().is_some_and(|byte| byte & 0xf0 == 0x50)
+                && buffer[1..].starts_with(ZSTD_SKIPPABLE_MAGIC))
+            .then_some(Compression::Zstd)
+        })
+    }
+}
+
+impl Display for Compression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Compression::Gzip => write!(f, "gzip"),
+            Compression::Xz => write!(f, "xz"),
+            Compression::Zstd => write!(f, "Zstandard"),
+        }
+    }
+}
+
+// Refuses the bytes after the limit, so the decompression stops as soon as the stream turns out to be too big
+struct LimitedWriter {
+    output: Vec<u8>,
+    limit: usize,
+}
+
+impl Write for LimitedWriter {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        let room = self.limit - self.output.len();
+        if room == 0 && !bytes.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::WriteZero,
+                "The limit of the decompressed size is reached",
+            ));
+        }
+        let length = bytes.len().min(room);
+        self.output.extend_from_slice(&bytes[..length]);
+
+        Ok(length)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub(crate) fn is_compressed(buffer: &[u8]) -> bool {
+    Compression::detect(buffer).is_some()
+}
+
+// Zstandard streams may have more than one frame and the skippable frames only have metadata
+fn decompress_zstd(mut data: &[u8], writer: &mut LimitedWriter) -> Result<(), String> {
+    while !data.is_empty() {
+        match StreamingDecoder::new(&mut data) {
+            Ok(mut decoder) => {
+                io::copy(&mut decoder, writer).map_err(|error| error.to_string())?;
+            }
+            Err(FrameDecoderError::ReadFrameHeaderError(ReadFrameHeaderError::SkipFrame {
+                length,
+                ..
+            })) => {
+                data = data
+                    .get(length as usize..)
+                    .ok_or("The skippable frame is out of the stream")?;
+            }
+            Err(error) => return Err(error.to_string()),
+        }
+    }
+
+    Ok(())
+}
+
+///
+/// Decompresses the stream until the end or the limit.
+///
+/// The bytes decompressed before an error are returned with the error, so the beginning of a stream can be read with a small limit.
+///
+fn decompress(
+    compression: Compression,
+    data: &[u8],
+    limit: usize,
+) -> (Vec<u8>, Result<(), String>) {
+    let mut writer = LimitedWriter {
+        output: vec![],
+        limit,
+    };
+    let result = match compression {
+        // gzip files may have more than one member (Like: the files of pigz and bgzip)
+        Compression::Gzip => io::copy(&mut MultiGzDecoder::new(data), &mut writer)
+            .map(|_| ())
+            .map_err(|error| error.to_string()),
+        Compression::Xz => {
+            lzma_rs::xz_decompress(&mut &data[..], &mut writer).map_err(|error| error.to_string())
+        }
+        Compression::Zstd => decompress_zstd(data, &mut writer),
+    };
+
+    (writer.output, result)
+}
+
+///
+/// Decompresses the whole gzip, xz or Zstandard stream if it isn't bigger than [`MAX_DECOMPRESSED_SIZE`] or
+/// the rest of the budget after decompression. The size of the stream is spent from the budget.
+///
+pub(crate) fn decompress_stream(buffer: &[u8], budget: &mut Budget) -> Result<Vec<u8>, Error> {
+    let compression = Compression::detect(buffer).ok_or_else(|| {
+        Error::Malformed("The stream is not compressed with gzip, xz or Zstandard".to_owned())
+    })?;
+    let limit = budget.size_limit(MAX_DECOMPRESSED_SIZE as u64) as usize;
+    // One byte more than the limit tells the streams which reach the limit from the ones which exceed it
+    let (data, result) = decompress(compression, buffer, limit + 1);
+    budget.spend_size(data.len() as u64);
+    match result {
+        _ if data.len() > limit => Err(Error::Malformed(format!(
+            "The {} stream is bigger than the limit (> {} bytes) after decompression",
+            compression, limit
+        ))),
+        Err(error) => Err(Error::Malformed(format!(
+            "The {} stream couldn't be decompressed: {}",
+            compression, error
+        ))),
+        Ok(()) => Ok(data),
+    }
+}
+
+// Names the contents after the stream without its suffix (Like: app.tar for app.tar.gz and app.tgz)
+fn contents_name(file_name: &str) -> String {
+    SUFFIXES
+        .iter()
+        .find_map(|(suffix, replacement)| {
+            file_name
+                .strip_suffix(suffix)
+                .map(|stem| format!("{}{}", stem, replacement))
+        })
+        .unwrap_or_else(|| file_name.to_owned())
+}
+
+fn to_error(
+    file_name: &str,
+    error: Error,
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+) -> ULDDObjResult {
+    Debugging::Error(format!(
+        "Error while decompressing the file named '{}'{}\nDetails:\n{}",
+        file_name,
+        merge_members(member_names),
+        error
+    ))
+    .print(options.debugging);
+
+    ULDDObjResult {
+        error: ParsingError {
+            code: error.to_int(),
+            explanation: error.to_c_string(),
+        },
+        obj: ULDDObj {
+            file_name: file_name.to_c_string(),
+            member_name: CharVec::from(member_names),
+            file_type: "Compressed file".to_c_string(),
+            ..Default::default()
+        },
+    }
+}
+
+///
+/// Parses the contents of gzip, xz and Zstandard streams (Like: `.tar.gz` release artifacts and OCI image layers) as members.
+///
+/// The contents are named after the stream without its suffix. The streams in containers are peeked first and only the ones
+/// which have an object or a container in them are decompressed. The limits below protect against decompression bombs:
+/// - A stream can't be bigger than [`MAX_DECOMPRESSED_SIZE`] after decompression
+/// - The streams decompressed from all the containers in the file can't be bigger than [`MAX_TOTAL_SIZE`](crate::zip::MAX_TOTAL_SIZE) in total (see [`Budget`])
+/// - The streams count as members against [`MAX_NESTING_DEPTH`], so the streams which decompress to themselves stop
+///
+pub(crate) fn parse_compressed(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    objects: &mut Vec<ULDDObjResult>,
+    budget: &mut Budget,
+    options: &ULDDOptions,
+) {
+    let Some(compression) = Compression::detect(buffer) else {
+        return;
+    };
+    if member_names.len() >= MAX_NESTING_DEPTH {
+        let error = Error::Malformed(format!(
+            "The compressed file is nested deeper than {} members",
+            MAX_NESTING_DEPTH
+        ));
+        return objects.push(to_error(file_name, error, member_names, options));
+    }
+    if !member_names.is_empty() {
+        let (head, _) = decompress(compression, buffer, PEEK_SIZE);
+        if !is_object(&head) {
+            return;
+        }
+    }
+
+    let data = match decompress_stream(buffer, budget) {
+        Ok(data) => data,
+        Err(error) => return objects.push(to_error(file_name, error, member_names, options)),
+    };
+
+    // The decompressed contents don't live as long as the stream, so the names are copied
+    let mut names: Vec<&str> = member_names.to_vec();
+    names.push(file_name);
+    debug_objects(
+        file_name,
+        &mut names,
+        &format!("a {} stream", compression),
+        options.debugging,
+    );
+    parse_objects(
+        &contents_name(file_name),
+        &data,
+        &mut names,
+        objects,
+        budget,
+        options,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{elf, gzip},
+        zip::MAX_TOTAL_SIZE,
+    };
+
+    // A frame of a single raw block, which has its size in a byte
+    fn zstd_frame(data: &[u8]) -> Vec<u8> {
+        let block_header = 1 | (data.len() as u32) << 3;
+        [
+            ZSTD_MAGIC,
+            &[0x20, data.len() as u8],
+            &block_header.to_le_bytes()[..3],
+            data,
+        ]
+        .concat()
+    }
+
+    fn zstd_skippable_frame(data: &[u8]) -> Vec<u8> {
+        [
+            b"\x5a\x2a\x4d\x18",
+            &(data.len() as u32).to_le_bytes()[..],
+            data,
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn gzip_members_are_concatenated() {
+        let stream = [gzip(&elf()), gzip(b"the second member")].concat();
+
+        let data = decompress_stream(&stream, &mut Budget::new()).unwrap();
+        assert_eq!(data, [elf(), b"the second member".to_vec()].concat());
+    }
+
+    #[test]
+    fn zstd_skippable_frames_are_skipped() {
+        let stream = [
+            zstd_skippable_frame(b"metadata"),
+            zstd_frame(&elf()),
+            zstd_skippable_frame(b""),
+            zstd_frame(b"the second frame"),
+        ]
+        .concat();
+        assert!(is_compressed(&stream));
+
+        let data = decompress_stream(&stream, &mut Budget::new()).unwrap();
+        assert_eq!(data, [elf(), b"the second frame".to_vec()].concat());
+    }
+
+    #[test]
+    fn streams_stop_at_the_rest_of_the_budget() {
+        let stream = gzip(&[0; 1000]);
+        let mut budget = Budget::new();
+        budget.spend_size(MAX_TOTAL_SIZE - 1000);
+        assert_eq!(decompress_stream(&stream, &mut budget).unwrap().len(), 1000);
+
+        let mut budget = Budget::new();
+        budget.spend_size(MAX_TOTAL_SIZE - 999);
+        assert_eq!(
+            decompress_stream(&stream, &mut budget).err().unwrap().to_string(),
+            "Malformed entity: The gzip stream is bigger than the limit (> 999 bytes) after decompression"
+        );
+        assert_eq!(budget.size_limit(u64::MAX), 0);
+    }
+}
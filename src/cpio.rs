@@ -0,0 +1,210 @@
+use crate::{
+    debug::merge_members,
+    impls::{ErrorToInt, StringToCString},
+    is_object,
+    package::installed_path,
+    parse_objects,
+    structs::{CharVec, Debugging, ParsingError, ULDDObj, ULDDObjResult, ULDDOptions},
+    zip::{Budget, MAX_NESTING_DEPTH},
+};
+use goblin::error::Error;
+use std::collections::HashMap;
+
+/*
+Source:
+    https://man7.org/linux/man-pages/man5/cpio.5.html (New ASCII Format)
+*/
+
+// The archives with checksums ("070702") have the same header
+const NEWC_MAGIC: &[u8] = b"070701";
+const CRC_MAGIC: &[u8] = b"070702";
+const HEADER_SIZE: usize = 110;
+const TRAILER: &str = "TRAILER!!!";
+
+const S_IFMT: u32 = 0o170000;
+const S_IFREG: u32 = 0o100000;
+const S_IFLNK: u32 = 0o120000;
+
+/// An entry of a cpio archive. The hard links of a file share its device and inode numbers.
+struct CpioEntry<'a> {
+    path: String,
+    mode: u32,
+    inode: (u32, u32, u32),
+    link_count: u32,
+    data: &'a [u8],
+}
+
+pub(crate) fn is_cpio(buffer: &[u8]) -> bool {
+    buffer.starts_with(NEWC_MAGIC) || buffer.starts_with(CRC_MAGIC)
+}
+
+// The numbers are 8 hexadecimal digits
+fn read_hex(header: &[u8], offset: usize) -> Result<u32, Error> {
+    let field = String::from_utf8_lossy(&header[offset..offset + 8]);
+    u32::from_str_radix(&field, 16).map_err(|_| {
+        Error::Malformed(format!(
+            "The number {:?} in the cpio header is malformed",
+            field
+        ))
+    })
+}
+
+fn read_entries(buffer: &[u8]) -> Result<Vec<CpioEntry<'_>>, Error> {
+    let mut entries = vec![];
+    let mut offset = 0;
+    loop {
+        let header = buffer
+            .get(offset..offset + HEADER_SIZE)
+            .ok_or(Error::BufferTooShort(
+                offset + HEADER_SIZE,
+                "bytes of the cpio archive",
+            ))?;
+        if !is_cpio(header) {
+            return Err(Error::Malformed(format!(
+                "The header at {:#x} doesn't start with a magic number of the new cpio format",
+                offset
+            )));
+        }
+        let (inode, mode, link_count) = (
+            read_hex(header, 6)?,
+            read_hex(header, 14)?,
+            read_hex(header, 38)?,
+        );
+        let (size, device_major, device_minor, name_size) = (
+            read_hex(header, 54)? as usize,
+            read_hex(header, 62)?,
+            read_hex(header, 70)?,
+            read_hex(header, 94)? as usize,
+        );
+
+        // The name and the data are padded to multiples of 4 bytes
+        let name_start = offset + HEADER_SIZE;
+        let name = buffer
+            .get(name_start..name_start + name_size)
+            .ok_or_else(|| {
+                Error::Malformed(format!(
+                    "The name of the entry at {:#x} is out of the cpio archive",
+                    offset
+                ))
+            })?;
+        let end = name.iter().position(|b| *b == 0).unwrap_or(name.len());
+        let path = String::from_utf8_lossy(&name[..end]).into_owned();
+        if path == TRAILER {
+            break;
+        }
+        let data_start = (name_start + name_size).next_multiple_of(4);
+        let data = data_start
+            .checked_add(size)
+            .and_then(|data_end| buffer.get(data_start..data_end))
+            .ok_or_else(|| {
+                Error::Malformed(format!(
+                    "The data of the entry at {:#x} is out of the cpio archive",
+                    offset
+                ))
+            })?;
+        offset = (data_start + size).next_multiple_of(4);
+
+        entries.push(CpioEntry {
+            path,
+            mode,
+            inode: (device_major, device_minor, inode),
+            link_count,
+            data,
+        });
+    }
+
+    Ok(entries)
+}
+
+fn to_error(
+    file_name: &str,
+    error: Error,
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+) -> ULDDObjResult {
+    Debugging::Error(format!(
+        "Error while reading the cpio archive of the file named '{}'{}\nDetails:\n{}",
+        file_name,
+        merge_members(member_names),
+        error
+    ))
+    .print(options.debugging);
+
+    ULDDObjResult {
+        error: ParsingError {
+            code: error.to_int(),
+            explanation: error.to_c_string(),
+        },
+        obj: ULDDObj {
+            file_name: file_name.to_c_string(),
+            member_name: CharVec::from(member_names),
+            file_type: "Archive".to_c_string(),
+            ..Default::default()
+        },
+    }
+}
+
+///
+/// Parses the objects in the cpio archives (new ASCII format) which RPM packages carry their files in.
+///
+/// The archive is not named, so the objects are members of the file which has the archive and they are named with the paths they are installed to.
+/// Only the data of the last hard link of a file is stored, so the other links are parsed with it. Symbolic links are not followed.
+///
+pub(crate) fn parse_cpio(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    objects: &mut Vec<ULDDObjResult>,
+    budget: &mut Budget,
+    options: &ULDDOptions,
+) {
+    if member_names.len() >= MAX_NESTING_DEPTH {
+        let error = Error::Malformed(format!(
+            "The cpio archive is nested deeper than {} members",
+            MAX_NESTING_DEPTH
+        ));
+        return objects.push(to_error(file_name, error, member_names, options));
+    }
+    let entries = match read_entries(buffer) {
+        Ok(entries) => entries,
+        Err(error) => return objects.push(to_error(file_name, error, member_names, options)),
+    };
+
+    let files: HashMap<(u32, u32, u32), &[u8]> = entries
+        .iter()
+        .filter(|entry| entry.mode & S_IFMT == S_IFREG && !entry.data.is_empty())
+        .map(|entry| (entry.inode, entry.data))
+        .collect();
+    for entry in &entries {
+        let data = match entry.mode & S_IFMT {
+            S_IFREG if entry.data.is_empty() && entry.link_count > 1 => {
+                match files.get(&entry.inode) {
+                    Some(data) => *data,
+                    None => continue,
+                }
+            }
+            S_IFREG => entry.data,
+            S_IFLNK => {
+                Debugging::Info(format!(
+                    "The symbolic link named '{}' of the file named '{}' is not followed (-> '{}')",
+                    entry.path,
+                    file_name,
+                    String::from_utf8_lossy(entry.data)
+                ))
+                .print(options.debugging);
+                continue;
+            }
+            // Directories, devices and FIFOs
+            _ => continue,
+        };
+        if !is_object(data) {
+            continue;
+        }
+
+        // The names of the entries don't live as long as the archive, so the names are copied for each entry
+        let entry_name = installed_path(&entry.path);
+        let mut names: Vec<&str> = member_names.to_vec();
+        names.push(file_name);
+        parse_objects(&entry_name, data, &mut names, objects, budget, options);
+    }
+}
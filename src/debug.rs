@@ -2,12 +2,96 @@ use std::ffi::c_char;
 use std::fmt::Display;
 use std::ptr::null_mut;
 use crate::impls::StringToCString;
-use crate::structs::Debugging;
+use crate::structs::{CharVec, Debugging, StrippingInfo};
+use crate::types::{DebugInfoFormat, DebugInfoLocation, StripStatus};
 
 pub(crate) fn option_to_c_string<T>(option: Option<T>) -> *mut c_char where T: Display  {
     option.map(|v| v.to_c_string()).unwrap_or(null_mut())
 }
 
+/// Formats the memory permissions like `ls` (Like: r-x).
+pub(crate) fn permissions_to_c_string(read: bool, write: bool, execute: bool) -> *mut c_char {
+    [(read, 'r'), (write, 'w'), (execute, 'x')]
+        .iter()
+        .map(|(set, letter)| if *set { *letter } else { '-' })
+        .collect::<String>()
+        .to_c_string()
+}
+
+/// Names the set bits of `value` with `names`. Unknown bits are written in hexadecimal.
+pub(crate) fn flag_name_list(value: u64, names: &phf::Map<u64, &'static str>) -> Vec<String> {
+    (0..u64::BITS)
+        .map(|bit| 1u64 << bit)
+        .filter(|flag| value & flag != 0)
+        .map(|flag| match names.get(&flag) {
+            Some(name) => name.to_string(),
+            None => format!("{:#x}", flag),
+        })
+        .collect()
+}
+
+/// Same as [`flag_name_list`] but returns a [`CharVec`].
+pub(crate) fn flag_names(value: u64, names: &phf::Map<u64, &'static str>) -> CharVec {
+    CharVec::from(flag_name_list(value, names))
+}
+
+/// Classifies the object with the symbols and the debug information found by the parser of its format.
+///
+/// The returned bool is the value of `is_stripped`.
+/// Only the embedded debug information counts, the split and referenced ones are listed in `debug_info` (Like: `.gnu_debuglink` of stripped ELF files).
+pub(crate) fn classify_stripping(
+    has_symbols: bool,
+    debug_info: &[(DebugInfoFormat, DebugInfoLocation)],
+) -> (bool, StrippingInfo) {
+    let has_embedded = debug_info
+        .iter()
+        .any(|(_, location)| *location == DebugInfoLocation::Embedded);
+    let status = match (has_symbols, has_embedded) {
+        (_, true) => StripStatus::DebugInfo,
+        (true, false) => StripStatus::SymbolsOnly,
+        (false, false) => StripStatus::FullyStripped,
+    };
+    let mut entries: Vec<String> = vec![];
+    for (format, location) in debug_info {
+        let entry = format!("{} ({})", format, location);
+        if !entries.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+
+    (
+        status == StripStatus::FullyStripped,
+        StrippingInfo {
+            status: status.to_c_string(),
+            has_symbols,
+            debug_info: CharVec::from(entries),
+        },
+    )
+}
+
+/// Reads the string until the first NUL byte, or until the end if it doesn't have one.
+pub(crate) fn read_c_str(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Hashes the comma separated list of the given names with MD5 like imphash and its relatives do.
+pub(crate) fn hash_names<T>(names: &[T]) -> String
+where
+    T: AsRef<str>,
+{
+    let joined = names
+        .iter()
+        .map(|n| n.as_ref())
+        .collect::<Vec<&str>>()
+        .join(",");
+    format!("{:x}", md5::compute(joined))
+}
+
 pub(crate) fn merge_members(member_names: &mut [&str]) -> String {
     if !member_names.is_empty() {
         format!(" (Member of: {})", member_names.join(" -> "))
@@ -0,0 +1,251 @@
+use crate::{
+    debug::{classify_stripping, debug_objects, merge_members, option_to_c_string},
+    impls::{ErrorToInt, StringToCString},
+    structs::{CharVec, Debugging, DexInfo, ParsingError, ULDDObj, ULDDObjResult, ULDDOptions},
+    types::{
+        DebugInfoFormat, DebugInfoLocation, DexFileType, ElfOS, ART_INSTRUCTION_SET,
+        ART_INSTRUCTION_SET_LEGACY,
+    },
+};
+use goblin::error::Error;
+use scroll::{Endian, Pread, LE};
+use std::path::Path;
+use std::ptr::null_mut;
+
+/*
+Sources:
+    https://source.android.com/docs/core/runtime/dex-format
+    https://android.googlesource.com/platform/art/+/refs/heads/main/libdexfile/dex/compact_dex_file.h
+    https://android.googlesource.com/platform/art/+/refs/heads/main/runtime/vdex_file.h
+*/
+
+pub(crate) const DEX_MAGIC: &[u8] = b"dex\n";
+pub(crate) const COMPACT_DEX_MAGIC: &[u8] = b"cdex";
+pub(crate) const VDEX_MAGIC: &[u8] = b"vdex";
+
+const ENDIAN_CONSTANT: u32 = 0x1234_5678;
+const REVERSE_ENDIAN_CONSTANT: u32 = 0x7856_3412;
+const NO_INDEX: u32 = 0xffff_ffff;
+const CLASS_DEF_SIZE: usize = 32;
+const MAP_ITEM_SIZE: usize = 12;
+const TYPE_DEBUG_INFO_ITEM: u16 = 0x2003;
+
+// VDEX files are split into sections since Android 12
+const VDEX_SECTIONS_VERSION: u32 = 27;
+const VDEX_CHECKSUM_SECTION: u32 = 0;
+const VDEX_SECTION_HEADER_SIZE: usize = 12;
+
+struct DexFile {
+    file_type: DexFileType,
+    info: DexInfo,
+    has_source_files: bool,
+    has_debug_info: bool,
+}
+
+fn is_version(bytes: &[u8]) -> bool {
+    matches!(bytes, [a, b, c, 0] if [a, b, c].iter().all(|digit| digit.is_ascii_digit()))
+}
+
+///
+/// Tells the files of Android Runtime by their magic numbers and the versions after them (Like: "dex\n039\0").
+///
+pub(crate) fn is_dex(buffer: &[u8]) -> bool {
+    [DEX_MAGIC, COMPACT_DEX_MAGIC, VDEX_MAGIC]
+        .iter()
+        .any(|magic| buffer.starts_with(magic))
+        && buffer.get(4..8).is_some_and(is_version)
+}
+
+///
+/// Returns the version which follows the magic number of DEX, OAT and VDEX files (Like: 039).
+///
+pub(crate) fn read_version(header: &[u8]) -> Option<String> {
+    header
+        .get(4..8)
+        .filter(|bytes| is_version(bytes))
+        .map(|bytes| String::from_utf8_lossy(&bytes[..3]).into_owned())
+}
+
+fn read_dex(buffer: &[u8], file_type: DexFileType) -> Result<DexFile, Error> {
+    let endian = match buffer.pread_with::<u32>(40, LE)? {
+        ENDIAN_CONSTANT => Endian::Little,
+        REVERSE_ENDIAN_CONSTANT => Endian::Big,
+        tag => {
+            return Err(Error::Malformed(format!(
+                "The endian tag of the DEX file is unknown ({:#x})",
+                tag
+            )))
+        }
+    };
+    let read = |offset: usize| buffer.pread_with::<u32>(offset, endian);
+    let file_size = read(32)?;
+    if file_size as usize > buffer.len() {
+        return Err(Error::BufferTooShort(
+            file_size as usize,
+            "bytes of the DEX file",
+        ));
+    }
+    let (string_count, type_count, field_count, method_count) =
+        (read(56)?, read(64)?, read(80)?, read(88)?);
+    let (map_offset, class_count, class_defs_offset, data_offset) =
+        (read(52)?, read(96)?, read(100)?, read(108)?);
+
+    // The names of the methods and the fields can't be stripped, but R8 and ProGuard remove the names of the source files
+    let has_source_files = (0..class_count as usize)
+        .map_while(|index| read(class_defs_offset as usize + index * CLASS_DEF_SIZE + 16).ok())
+        .any(|source_file| source_file != NO_INDEX);
+    // The offsets in the data of compact DEX files are relative to the data section, which may be shared in VDEX files
+    let map_offset = match file_type {
+        DexFileType::CompactDex => data_offset as usize + map_offset as usize,
+        _ => map_offset as usize,
+    };
+    let map_size = read(map_offset)? as usize;
+    let has_debug_info = (0..map_size)
+        .map_while(|index| {
+            let item = map_offset + 4 + index * MAP_ITEM_SIZE;
+            Some((
+                buffer.pread_with::<u16>(item, endian).ok()?,
+                read(item + 4).ok()?,
+            ))
+        })
+        .any(|(item_type, size)| item_type == TYPE_DEBUG_INFO_ITEM && size != 0);
+
+    Ok(DexFile {
+        file_type,
+        info: DexInfo {
+            version: option_to_c_string(read_version(buffer)),
+            class_count,
+            string_count,
+            type_count,
+            method_count,
+            field_count,
+            ..Default::default()
+        },
+        has_source_files,
+        has_debug_info,
+    })
+}
+
+fn read_vdex_dex_file_count(buffer: &[u8], version: u32) -> Result<u32, Error> {
+    // The checksum section has a checksum for each DEX file
+    if version >= VDEX_SECTIONS_VERSION {
+        let section_count = buffer.pread_with::<u32>(8, LE)?;
+        for index in 0..section_count as usize {
+            let header = 12 + index * VDEX_SECTION_HEADER_SIZE;
+            if buffer.pread_with::<u32>(header, LE)? == VDEX_CHECKSUM_SECTION {
+                return Ok(buffer.pread_with::<u32>(header + 8, LE)? / 4);
+            }
+        }
+
+        return Ok(0);
+    }
+    // Android 10 and 11 write the version of the DEX section after the version of the verifier dependencies
+    let offset = if buffer.get(8..12).is_some_and(is_version) {
+        12
+    } else {
+        8
+    };
+
+    Ok(buffer.pread_with::<u32>(offset, LE)?)
+}
+
+// VDEX files are not compiled for an instruction set, but they are placed in the directories of the instruction sets
+fn instruction_set_from_path(file_name: &str) -> Option<&'static str> {
+    let directory = Path::new(file_name).parent()?.file_name()?.to_str()?;
+
+    ART_INSTRUCTION_SET
+        .values()
+        .chain(ART_INSTRUCTION_SET_LEGACY.values())
+        .find(|instruction_set| **instruction_set == directory)
+        .copied()
+}
+
+fn read_vdex(file_name: &str, buffer: &[u8]) -> Result<DexFile, Error> {
+    let version = read_version(buffer).unwrap_or_default();
+    let dex_file_count = read_vdex_dex_file_count(buffer, version.parse().unwrap_or_default())?;
+
+    Ok(DexFile {
+        file_type: DexFileType::Vdex,
+        info: DexInfo {
+            version: version.to_c_string(),
+            dex_file_count,
+            instruction_set: option_to_c_string(instruction_set_from_path(file_name)),
+            ..Default::default()
+        },
+        has_source_files: false,
+        has_debug_info: false,
+    })
+}
+
+pub(crate) fn parse_dex(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+) -> ULDDObjResult {
+    let (executable_format, dex) = if buffer.starts_with(VDEX_MAGIC) {
+        ("VDEX", read_vdex(file_name, buffer))
+    } else if buffer.starts_with(COMPACT_DEX_MAGIC) {
+        ("DEX", read_dex(buffer, DexFileType::CompactDex))
+    } else {
+        ("DEX", read_dex(buffer, DexFileType::Dex))
+    };
+    let dex = match dex {
+        Ok(dex) => dex,
+        Err(error) => {
+            Debugging::Error(format!(
+                "Error while parsing the {} file named '{}'{}\nDetails:\n{}",
+                executable_format,
+                file_name,
+                merge_members(member_names),
+                error
+            ))
+            .print(options.debugging);
+
+            return ULDDObjResult {
+                error: ParsingError {
+                    code: error.to_int(),
+                    explanation: error.to_c_string(),
+                },
+                obj: ULDDObj {
+                    file_name: file_name.to_c_string(),
+                    member_name: CharVec::from(member_names),
+                    executable_format: executable_format.to_c_string(),
+                    ..Default::default()
+                },
+            };
+        }
+    };
+    debug_objects(
+        file_name,
+        member_names,
+        &format!("a {} file", executable_format),
+        options.debugging,
+    );
+
+    let debug_info = if dex.has_debug_info {
+        vec![(DebugInfoFormat::DexDebugInfo, DebugInfoLocation::Embedded)]
+    } else {
+        vec![]
+    };
+    let (is_stripped, stripping) = classify_stripping(dex.has_source_files, &debug_info);
+
+    ULDDObjResult {
+        error: ParsingError::default(),
+        obj: ULDDObj {
+            file_name: file_name.to_c_string(),
+            member_name: CharVec::from(member_names),
+            executable_format: executable_format.to_c_string(),
+            is_64: false,
+            os_type: ElfOS::Android.to_c_string(),
+            file_type: dex.file_type.to_c_string(),
+            is_stripped,
+            cpu_type: "Dalvik".to_c_string(),
+            cpu_subtype: null_mut(),
+            interpreter: null_mut(),
+            stripping,
+            dex: dex.info,
+            ..Default::default()
+        },
+    }
+}
@@ -2,14 +2,60 @@ use std::ffi::c_char;
 use std::ptr::null_mut;
 
 use crate::{
-    debug::debug_objects,
-    structs::{CharVec, ParsingError, ULDDObj, ULDDObjResult},
-    types::{ElfFileType, ElfOS, E_MACHINE, E_TYPE},
+    debug::{classify_stripping, debug_objects, permissions_to_c_string},
+    go::{parse_go_build_info, GoMemory},
+    rust::{parse_rust, AUDITABLE_SECTION},
+    structs::{
+        CharVec, Debugging, GoBuildInfo, ParsingError, RustInfo, SectionInfo, SectionInfoVec,
+        ULDDObj, ULDDObjResult, ULDDOptions,
+    },
+    types::{DebugInfoFormat, DebugInfoLocation, ElfFileType, ElfOS, E_MACHINE, E_TYPE},
 };
-use goblin::elf::Elf;
-use crate::debug::option_to_c_string;
+use core_dump::parse_core;
+use debug_link::{parse_debug_links, DebugLinks};
+use dynamic::parse_dynamic;
+use goblin::elf::{
+    header::ET_CORE,
+    program_header::{pt_to_str, PF_W, PT_LOAD},
+    section_header::{sht_to_str, SHF_ALLOC, SHF_EXECINSTR, SHF_WRITE, SHT_NOBITS, SHT_PROGBITS},
+    Elf,
+};
+use kernel_module::parse_kernel_module;
+use notes::{parse_notes, ElfNotes};
+use oat::parse_oat;
+use toolchain::find_toolchain;
+use crate::debug::{hash_names, option_to_c_string};
 use crate::impls::StringToCString;
 
+mod core_dump;
+mod debug_link;
+mod dynamic;
+mod kernel_module;
+mod notes;
+mod oat;
+mod toolchain;
+
+pub(crate) fn section_data<'a>(elf: &Elf<'_>, buffer: &'a [u8], name: &str) -> Option<&'a [u8]> {
+    let section = elf
+        .section_headers
+        .iter()
+        .find(|s| elf.shdr_strtab.get_at(s.sh_name) == Some(name))?;
+    if section.sh_type == SHT_NOBITS {
+        return None;
+    }
+    let start = section.sh_offset as usize;
+
+    buffer.get(start..start.saturating_add(section.sh_size as usize))
+}
+
+pub(crate) fn vaddr_to_offset(elf: &Elf<'_>, vaddr: u64) -> Option<usize> {
+    elf.program_headers
+        .iter()
+        .filter(|p| p.p_type == PT_LOAD)
+        .find(|p| vaddr >= p.p_vaddr && vaddr < p.p_vaddr.saturating_add(p.p_filesz))
+        .and_then(|p| usize::try_from((vaddr - p.p_vaddr).checked_add(p.p_offset)?).ok())
+}
+
 fn find_os_from_strtab_elf(elf: &Elf<'_>, pat: &[&str]) -> bool {
     [
         elf.strtab.to_vec().unwrap_or(vec![""]),
@@ -21,19 +67,60 @@ fn find_os_from_strtab_elf(elf: &Elf<'_>, pat: &[&str]) -> bool {
     .any(|s| pat.iter().any(|i| s.to_lowercase().contains(i)))
 }
 
-fn find_os_elf(elf: &Elf<'_>, os_abi: u8) -> (ElfOS, *mut c_char) {
+// Source: The default paths of the dynamic linkers
+fn find_os_from_interpreter_elf(elf: &Elf<'_>) -> Option<ElfOS> {
+    let interpreter = elf.interpreter?;
+    let os = match interpreter {
+        _ if interpreter.starts_with("/system/bin/linker") => ElfOS::Android,
+        _ if interpreter.starts_with("/libexec/ld-elf") => ElfOS::FreeBSD,
+        _ if interpreter == "/usr/libexec/ld.elf_so" => ElfOS::NetBSD,
+        _ if interpreter == "/usr/libexec/ld.so" => ElfOS::OpenBSD,
+        _ if interpreter == "/system/runtime_loader" => ElfOS::Haiku,
+        _ if interpreter.starts_with("/usr/lib/") && interpreter.ends_with("/ld.so.1") => {
+            if find_os_from_strtab_elf(elf, &["illumos"]) {
+                ElfOS::Illumos
+            } else {
+                ElfOS::Solaris
+            }
+        }
+        _ if interpreter.contains("/ld-linux")
+            || interpreter.contains("/ld-musl-")
+            || interpreter.starts_with("/lib/ld64.so")
+            || interpreter == "/lib/ld.so.1" =>
+        {
+            ElfOS::Linux
+        }
+        _ if interpreter.contains("Loader.so") => ElfOS::SerenityOS,
+        _ => return None,
+    };
+
+    Some(os)
+}
+
+fn find_os_elf(
+    elf: &Elf<'_>,
+    os_abi: u8,
+    notes: &ElfNotes,
+    is_oat: bool,
+) -> (ElfOS, *mut c_char) {
     let os = {
         match os_abi {
-            0x00 => match true {
-                _ if find_os_from_strtab_elf(elf, &["fbsd"]) => ElfOS::FreeBSD,
-                _ if find_os_from_strtab_elf(elf, &["openbsd"]) => ElfOS::OpenBSD,
-                _ if find_os_from_strtab_elf(elf, &["musl", "glibc", "linux"]) => ElfOS::Linux,
-                _ if find_os_from_strtab_elf(elf, &["android"]) => ElfOS::Android,
-                _ if find_os_from_strtab_elf(elf, &["netbsd"]) => ElfOS::NetBSD,
-                _ if find_os_from_strtab_elf(elf, &["solaris"]) => ElfOS::Solaris,
-                _ if find_os_from_strtab_elf(elf, &["illumos"]) => ElfOS::Illumos,
-                _ if elf.interpreter.is_some_and(|v| v.contains("Loader.so")) => ElfOS::SerenityOS,
-                _ => return (ElfOS::Undefined, null_mut()),
+            // dex2oat marks its output as Linux, but only Android Runtime loads OAT files
+            _ if is_oat => ElfOS::Android,
+            0x00 => match notes.os.or_else(|| find_os_from_interpreter_elf(elf)) {
+                Some(os) => os,
+                // The Go linker writes ident notes only for the BSDs and links statically on Linux
+                None if notes.is_go && elf.interpreter.is_none() => ElfOS::Linux,
+                None => match true {
+                    _ if find_os_from_strtab_elf(elf, &["fbsd"]) => ElfOS::FreeBSD,
+                    _ if find_os_from_strtab_elf(elf, &["openbsd"]) => ElfOS::OpenBSD,
+                    _ if find_os_from_strtab_elf(elf, &["musl", "glibc", "linux"]) => ElfOS::Linux,
+                    _ if find_os_from_strtab_elf(elf, &["android"]) => ElfOS::Android,
+                    _ if find_os_from_strtab_elf(elf, &["netbsd"]) => ElfOS::NetBSD,
+                    _ if find_os_from_strtab_elf(elf, &["solaris"]) => ElfOS::Solaris,
+                    _ if find_os_from_strtab_elf(elf, &["illumos"]) => ElfOS::Illumos,
+                    _ => return (ElfOS::Undefined, null_mut()),
+                },
             },
             0x01 => ElfOS::HPUX,
             0x02 => ElfOS::NetBSD,
@@ -88,35 +175,249 @@ fn find_linux_vdso(e_machine: u16, bit_type: bool) -> Option<&'static str> {
     }
 }
 
-fn convert_libraries_into_char_vec(elf: &mut Elf, os_abi: u8) -> CharVec {
+fn convert_libraries_into_char_vec(elf: &mut Elf, os: ElfOS) -> CharVec {
     let mut vector = std::mem::take(&mut elf.libraries);
-    if let (Some(vdso), ElfOS::Linux) = (
-        find_linux_vdso(elf.header.e_machine, elf.is_64),
-        find_os_elf(elf, os_abi).0,
-    ) {
+    if let (Some(vdso), ElfOS::Linux) = (find_linux_vdso(elf.header.e_machine, elf.is_64), os) {
         vector.push(vdso)
     }
 
     CharVec::from(vector)
 }
 
+fn find_debug_info_elf(
+    elf: &Elf<'_>,
+    debug_links: &DebugLinks,
+) -> Vec<(DebugInfoFormat, DebugInfoLocation)> {
+    let mut debug_info = vec![];
+    for section in &elf.section_headers {
+        // Separate debug files keep the section headers of the object but not the contents
+        if section.sh_type == SHT_NOBITS {
+            continue;
+        }
+        match elf.shdr_strtab.get_at(section.sh_name) {
+            Some(name) if name.starts_with(".debug_") || name.starts_with(".zdebug_") => {
+                debug_info.push((DebugInfoFormat::DWARF, DebugInfoLocation::Embedded))
+            }
+            Some(".stab") => debug_info.push((DebugInfoFormat::STABS, DebugInfoLocation::Embedded)),
+            _ => {}
+        }
+    }
+
+    if debug_links.debuglink.is_some() {
+        debug_info.push((DebugInfoFormat::DWARF, DebugInfoLocation::Split));
+    }
+    if debug_links.debugaltlink.is_some() {
+        debug_info.push((DebugInfoFormat::DWARF, DebugInfoLocation::Referenced));
+    }
+
+    debug_info
+}
+
+fn find_go_build_info_elf(elf: &Elf<'_>, buffer: &[u8]) -> Option<(String, GoBuildInfo)> {
+    let segments: Vec<_> = elf
+        .program_headers
+        .iter()
+        .filter(|p| p.p_type == PT_LOAD)
+        .filter_map(|p| {
+            let start = p.p_offset as usize;
+            let data = buffer.get(start..start.checked_add(p.p_filesz as usize)?)?;
+            Some((p.p_flags, p.p_vaddr, data))
+        })
+        .collect();
+    // The writable segments are only searched if the section headers are stripped
+    let candidates: Vec<&[u8]> = match section_data(elf, buffer, ".go.buildinfo") {
+        Some(data) => vec![data],
+        None if elf.section_headers.is_empty() => segments
+            .iter()
+            .filter(|(flags, _, _)| flags & PF_W != 0)
+            .map(|(_, _, data)| *data)
+            .collect(),
+        None => return None,
+    };
+    let memory = GoMemory {
+        regions: segments
+            .iter()
+            .map(|(_, address, data)| (*address, *data))
+            .collect(),
+    };
+
+    parse_go_build_info(&candidates, &memory)
+}
+
+fn find_rust_elf(elf: &Elf<'_>, buffer: &[u8]) -> RustInfo {
+    let symbols = elf
+        .syms
+        .iter()
+        .filter_map(|sym| elf.strtab.get_at(sym.st_name))
+        .chain(
+            elf.dynsyms
+                .iter()
+                .filter_map(|sym| elf.dynstrtab.get_at(sym.st_name)),
+        );
+    // The strings are in the read-only data sections (Like: `.rodata`)
+    // Core dumps are not searched since they are the memory of any process
+    let data: Vec<&[u8]> = if elf.header.e_type == ET_CORE {
+        vec![]
+    } else {
+        elf.section_headers
+            .iter()
+            .filter(|s| {
+                s.sh_type == SHT_PROGBITS
+                    && s.sh_flags & SHF_ALLOC as u64 != 0
+                    && s.sh_flags & (SHF_WRITE | SHF_EXECINSTR) as u64 == 0
+            })
+            .filter_map(|s| {
+                let start = s.sh_offset as usize;
+                buffer.get(start..start.checked_add(s.sh_size as usize)?)
+            })
+            .collect()
+    };
+
+    parse_rust(
+        section_data(elf, buffer, ".comment"),
+        elf.section_headers
+            .iter()
+            .any(|s| elf.shdr_strtab.get_at(s.sh_name) == Some(".rustc")),
+        symbols,
+        section_data(elf, buffer, AUDITABLE_SECTION),
+        &data,
+    )
+}
+
+fn list_sections_elf(elf: &Elf<'_>) -> (SectionInfoVec, SectionInfoVec) {
+    let sections: Vec<SectionInfo> = elf
+        .section_headers
+        .iter()
+        .map(|s| SectionInfo {
+            name: option_to_c_string(elf.shdr_strtab.get_at(s.sh_name)),
+            segment_name: null_mut(),
+            section_type: sht_to_str(s.sh_type).to_c_string(),
+            virtual_address: s.sh_addr,
+            virtual_size: s.sh_size,
+            file_offset: s.sh_offset,
+            file_size: if s.sh_type == SHT_NOBITS { 0 } else { s.sh_size },
+            // Sections which are not loaded (SHF_ALLOC) are not in the memory at all
+            permissions: permissions_to_c_string(
+                s.sh_flags & SHF_ALLOC as u64 != 0,
+                s.sh_flags & SHF_WRITE as u64 != 0,
+                s.sh_flags & SHF_EXECINSTR as u64 != 0,
+            ),
+            flags: s.sh_flags,
+            alignment: s.sh_addralign,
+        })
+        .collect();
+    let segments: Vec<SectionInfo> = elf
+        .program_headers
+        .iter()
+        .map(|p| SectionInfo {
+            name: pt_to_str(p.p_type).to_c_string(),
+            segment_name: null_mut(),
+            section_type: null_mut(),
+            virtual_address: p.p_vaddr,
+            virtual_size: p.p_memsz,
+            file_offset: p.p_offset,
+            file_size: p.p_filesz,
+            permissions: permissions_to_c_string(p.is_read(), p.is_write(), p.is_executable()),
+            flags: p.p_flags as u64,
+            alignment: p.p_align,
+        })
+        .collect();
+
+    (SectionInfoVec::from(sections), SectionInfoVec::from(segments))
+}
+
+fn import_hash_elf(elf: &Elf<'_>) -> Option<String> {
+    let mut imports: Vec<&str> = elf
+        .dynsyms
+        .iter()
+        .filter(|sym| sym.is_import() && sym.st_shndx == 0)
+        .filter_map(|sym| elf.dynstrtab.get_at(sym.st_name))
+        .filter(|name| !name.is_empty())
+        .collect();
+    imports.sort_unstable();
+    imports.dedup();
+
+    if imports.is_empty() {
+        None
+    } else {
+        Some(hash_names(&imports))
+    }
+}
+
 pub(crate) fn parse_elf(
     file_name: &str,
+    buffer: &[u8],
     elf: Elf,
     os_abi: u8,
     member_names: &mut Vec<&str>,
-    debugging: bool,
+    options: &ULDDOptions,
 ) -> ULDDObjResult {
     let mut elf = elf;
     let cpu_type = option_to_c_string(E_MACHINE.get(&elf.header.e_machine));
+    let kernel_module = parse_kernel_module(&elf, buffer);
+    let oat = parse_oat(&elf, buffer);
     let file_type = match E_TYPE.get(&elf.header.e_type) {
+        _ if kernel_module.is_some() => ElfFileType::KernelModule.to_c_string(),
+        _ if oat.is_some() => ElfFileType::OatFile.to_c_string(),
         _ if elf.header.e_type == 0x03 && elf.interpreter.is_some() => {
             ElfFileType::Executable.to_c_string()
         }
         rest => option_to_c_string(rest),
     };
     let interpreter = option_to_c_string(elf.interpreter);
-    debug_objects(file_name, member_names, "an ELF binary", debugging);
+    let import_hash = option_to_c_string(import_hash_elf(&elf));
+    let mut notes = parse_notes(&elf, buffer);
+    // Kernel modules don't have ABI notes but `.modinfo` is specific to Linux
+    if kernel_module.is_some() && notes.os.is_none() {
+        notes.os = Some(ElfOS::Linux);
+    }
+    let (os, os_type) = find_os_elf(&elf, os_abi, &notes, oat.is_some());
+    debug_objects(file_name, member_names, "an ELF binary", options.debugging);
+
+    let (go_version, go) = find_go_build_info_elf(&elf, buffer).unzip();
+    let rust = find_rust_elf(&elf, buffer);
+    let toolchain = find_toolchain(&elf, buffer, &notes, go_version.as_deref(), rust.is_rust);
+    let debug_links = parse_debug_links(&elf, buffer, notes.build_id);
+    let (is_stripped, stripping) = classify_stripping(
+        !elf.syms.is_empty(),
+        &find_debug_info_elf(&elf, &debug_links),
+    );
+    let debug_file = if options.find_debug_files {
+        debug_links.find_debug_file(file_name, !member_names.is_empty())
+    } else {
+        None
+    };
+    if let Some(path) = &debug_file {
+        Debugging::Affirmative(format!(
+            "Debug info of the binary named '{}' is available at '{}'",
+            file_name,
+            path.display()
+        ))
+        .print(options.debugging);
+    }
+    // The libraries of core dumps are the ones which were loaded into the crashed process and
+    // the libraries of kernel modules are the modules they depend on
+    let elf_dynamic = parse_dynamic(&elf, buffer);
+    let (sections, segments) = if options.list_sections {
+        list_sections_elf(&elf)
+    } else {
+        Default::default()
+    };
+    let (core, kernel_module, libraries) = match (elf.header.e_type, kernel_module) {
+        (ET_CORE, _) => {
+            let (core, libraries) = parse_core(&elf, buffer);
+            (core, Default::default(), CharVec::from(libraries))
+        }
+        (_, Some((kernel_module, depends))) => {
+            (Default::default(), kernel_module, CharVec::from(depends))
+        }
+        (_, None) => (
+            Default::default(),
+            Default::default(),
+            convert_libraries_into_char_vec(&mut elf, os),
+        ),
+    };
+
     ULDDObjResult {
         error: ParsingError::default(),
         obj: ULDDObj {
@@ -124,13 +425,27 @@ pub(crate) fn parse_elf(
             member_name: CharVec::from(member_names),
             executable_format: "ELF".to_c_string(),
             is_64: elf.is_64,
-            os_type: find_os_elf(&elf, os_abi).1,
+            os_type,
             file_type,
-            is_stripped: elf.syms.is_empty(),
+            is_stripped,
             cpu_type,
             cpu_subtype: null_mut(),
             interpreter,
-            libraries: convert_libraries_into_char_vec(&mut elf, os_abi),
+            libraries,
+            import_hash,
+            os_version: option_to_c_string(notes.os_version),
+            elf_debug: debug_links.to_elf_debug_info(debug_file),
+            stripping,
+            core,
+            kernel_module,
+            toolchain: CharVec::from(toolchain),
+            go: go.unwrap_or_default(),
+            rust,
+            elf_dynamic,
+            sections,
+            segments,
+            dex: oat.unwrap_or_default(),
+            ..Default::default()
         },
     }
 }
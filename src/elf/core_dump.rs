@@ -0,0 +1,203 @@
+use crate::{
+    debug::read_c_str,
+    impls::StringToCString,
+    structs::{CharVec, CoreDumpInfo, CoreThread, CoreThreadVec, U64Vec},
+    types::ELF_AUXV_TYPE,
+};
+use goblin::elf::{
+    note::{Note, NT_FILE, NT_PRPSINFO, NT_PRSTATUS, NT_SIGINFO},
+    Elf,
+};
+use scroll::{Endian, Pread};
+
+/*
+Sources:
+    https://github.com/torvalds/linux/blob/master/include/linux/elfcore.h
+    https://github.com/torvalds/linux/blob/master/include/uapi/linux/elfcore.h
+    https://github.com/torvalds/linux/blob/master/fs/binfmt_elf.c
+*/
+
+const NT_AUXV: u32 = 6;
+
+// Offsets of the fields of elf_prstatus which don't depend on the architecture
+const PRSTATUS_CURSIG: usize = 12;
+const PRSTATUS_PID_32: usize = 24;
+const PRSTATUS_PID_64: usize = 32;
+const PRSTATUS_REGISTERS_32: usize = 72;
+const PRSTATUS_REGISTERS_64: usize = 112;
+
+// pr_fname[16] and pr_psargs[80] are the last fields of elf_prpsinfo, and pr_pid is 16 bytes before them
+const PRPSINFO_FNAME_SIZE: usize = 16;
+const PRPSINFO_PSARGS_SIZE: usize = 80;
+
+/// Reads the `unsigned long` sized values of the core dump.
+#[derive(Clone, Copy)]
+struct WordReader {
+    is_64: bool,
+    endian: Endian,
+}
+
+impl WordReader {
+    fn size(&self) -> usize {
+        if self.is_64 {
+            8
+        } else {
+            4
+        }
+    }
+
+    fn read(&self, data: &[u8], offset: usize) -> Option<u64> {
+        if self.is_64 {
+            data.pread_with::<u64>(offset, self.endian).ok()
+        } else {
+            data.pread_with::<u32>(offset, self.endian)
+                .ok()
+                .map(u64::from)
+        }
+    }
+}
+
+fn parse_prpsinfo(note: &Note<'_>, endian: Endian, info: &mut CoreDumpInfo) {
+    let Some(fname_offset) = note
+        .desc
+        .len()
+        .checked_sub(PRPSINFO_FNAME_SIZE + PRPSINFO_PSARGS_SIZE)
+    else {
+        return;
+    };
+    let psargs_offset = fname_offset + PRPSINFO_FNAME_SIZE;
+
+    info.process_name = read_c_str(&note.desc[fname_offset..psargs_offset])
+        .trim_end()
+        .to_c_string();
+    info.process_arguments = read_c_str(&note.desc[psargs_offset..])
+        .trim_end()
+        .to_c_string();
+    // pr_pid, pr_ppid, pr_pgrp and pr_sid come before pr_fname
+    if let Some(pid_offset) = fname_offset.checked_sub(16) {
+        info.pid = note.desc.pread_with::<i32>(pid_offset, endian).unwrap_or(0);
+    }
+}
+
+fn parse_prstatus(note: &Note<'_>, reader: WordReader) -> CoreThread {
+    let (pid_offset, registers_offset, trailer) = if reader.is_64 {
+        // pr_fpvalid is padded to 8 bytes
+        (PRSTATUS_PID_64, PRSTATUS_REGISTERS_64, 8)
+    } else {
+        (PRSTATUS_PID_32, PRSTATUS_REGISTERS_32, 4)
+    };
+    let register_count =
+        note.desc.len().saturating_sub(registers_offset + trailer) / reader.size();
+    let registers: Vec<u64> = (0..register_count)
+        .map_while(|index| reader.read(note.desc, registers_offset + index * reader.size()))
+        .collect();
+
+    CoreThread {
+        tid: note
+            .desc
+            .pread_with::<i32>(pid_offset, reader.endian)
+            .unwrap_or(0),
+        signal: note
+            .desc
+            .pread_with::<u16>(PRSTATUS_CURSIG, reader.endian)
+            .map(i32::from)
+            .unwrap_or(0),
+        registers: U64Vec::from(registers),
+    }
+}
+
+// The vector is made of (type, value) pairs and ends with AT_NULL
+fn parse_auxv(note: &Note<'_>, reader: WordReader) -> Vec<String> {
+    note.desc
+        .chunks_exact(reader.size() * 2)
+        .map_while(|pair| Some((reader.read(pair, 0)?, reader.read(pair, reader.size())?)))
+        .take_while(|(key, _)| *key != 0)
+        .map(|(key, value)| match ELF_AUXV_TYPE.get(&key) {
+            Some(name) => format!("{}: {:#x}", name, value),
+            None => format!("Unknown ({}): {:#x}", key, value),
+        })
+        .collect()
+}
+
+// The table starts with the count and the page size, then (start, end, offset) triplets and the names follow
+fn parse_file_mappings(note: &Note<'_>, reader: WordReader) -> Vec<String> {
+    let Some(count) = reader.read(note.desc, 0) else {
+        return vec![];
+    };
+    let Some(names) = (count as usize)
+        .checked_mul(3 * reader.size())
+        .and_then(|size| size.checked_add(2 * reader.size()))
+        .and_then(|offset| note.desc.get(offset..))
+    else {
+        return vec![];
+    };
+
+    let mut files: Vec<String> = vec![];
+    for name in names.split(|b| *b == 0).take(count as usize) {
+        let name = String::from_utf8_lossy(name).into_owned();
+        if !name.is_empty() && !files.contains(&name) {
+            files.push(name);
+        }
+    }
+
+    files
+}
+
+fn is_shared_object(path: &str) -> bool {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    file_name.ends_with(".so") || file_name.contains(".so.")
+}
+
+/// Decodes the notes of the core dump. The shared objects in the `NT_FILE` table are returned as the libraries.
+pub(crate) fn parse_core(elf: &Elf<'_>, buffer: &[u8]) -> (CoreDumpInfo, Vec<String>) {
+    let reader = WordReader {
+        is_64: elf.is_64,
+        endian: if elf.little_endian {
+            Endian::Little
+        } else {
+            Endian::Big
+        },
+    };
+    let mut info = CoreDumpInfo {
+        is_core: true,
+        ..Default::default()
+    };
+    let mut threads = vec![];
+    let mut siginfo_signal = None;
+    let mut mapped_files = vec![];
+    let mut auxv = vec![];
+
+    for note in elf.iter_note_headers(buffer).into_iter().flatten() {
+        let Ok(note) = note else {
+            break;
+        };
+        if note.name.trim_end_matches('\0') != "CORE" {
+            continue;
+        }
+        match note.n_type {
+            NT_PRSTATUS => threads.push(parse_prstatus(&note, reader)),
+            NT_PRPSINFO => parse_prpsinfo(&note, reader.endian, &mut info),
+            NT_AUXV => auxv = parse_auxv(&note, reader),
+            NT_FILE => mapped_files = parse_file_mappings(&note, reader),
+            NT_SIGINFO => {
+                siginfo_signal = note.desc.pread_with::<i32>(0, reader.endian).ok();
+            }
+            _ => {}
+        }
+    }
+
+    // The first NT_PRSTATUS belongs to the thread which caused the dump
+    info.signal = siginfo_signal
+        .or_else(|| threads.first().map(|thread| thread.signal))
+        .unwrap_or(0);
+    let libraries = mapped_files
+        .iter()
+        .filter(|path| is_shared_object(path))
+        .cloned()
+        .collect();
+    info.threads = CoreThreadVec::from(threads);
+    info.auxv = CharVec::from(auxv);
+    info.mapped_files = CharVec::from(mapped_files);
+
+    (info, libraries)
+}
@@ -0,0 +1,134 @@
+use crate::{
+    debug::{option_to_c_string, to_hex},
+    elf::section_data,
+    structs::ElfDebugInfo,
+};
+use goblin::elf::Elf;
+use scroll::{Endian, Pread};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// Source: https://sourceware.org/gdb/current/onlinedocs/gdb.html/Separate-Debug-Files.html
+
+const DEBUG_FILE_DIRECTORY: &str = "/usr/lib/debug";
+
+/// The build ID and the links to the separate debug files of an ELF object.
+pub(crate) struct DebugLinks {
+    pub(crate) build_id: Option<String>,
+    pub(crate) debuglink: Option<(String, u32)>,
+    pub(crate) debugaltlink: Option<(String, String)>,
+}
+
+impl DebugLinks {
+    /// Looks for the debug file like GDB does. The build ID is tried first and then the paths relative to the object.
+    ///
+    /// Debug files found by the name in `.gnu_debuglink` are only accepted if their CRC32 matches.
+    pub(crate) fn find_debug_file(&self, file_name: &str, is_member: bool) -> Option<PathBuf> {
+        if let Some(path) = self.build_id.as_deref().and_then(find_by_build_id) {
+            return Some(path);
+        }
+
+        // Members of archives don't have a directory
+        if is_member {
+            return None;
+        }
+        let (debuglink, crc) = self.debuglink.as_ref()?;
+        find_by_debuglink(file_name, debuglink, *crc)
+    }
+
+    pub(crate) fn to_elf_debug_info(&self, debug_file: Option<PathBuf>) -> ElfDebugInfo {
+        let (debuglink, debuglink_crc) = match &self.debuglink {
+            Some((name, crc)) => (Some(name), *crc),
+            None => (None, 0),
+        };
+        let (debugaltlink, debugaltlink_build_id) = match &self.debugaltlink {
+            Some((name, build_id)) => (Some(name), Some(build_id)),
+            None => (None, None),
+        };
+
+        ElfDebugInfo {
+            build_id: option_to_c_string(self.build_id.as_ref()),
+            debuglink: option_to_c_string(debuglink),
+            debuglink_crc,
+            debugaltlink: option_to_c_string(debugaltlink),
+            debugaltlink_build_id: option_to_c_string(debugaltlink_build_id),
+            debug_file: option_to_c_string(debug_file.as_ref().map(|p| p.display())),
+        }
+    }
+}
+
+// The contents of both sections start with a NUL-terminated file name
+fn split_file_name(data: &[u8]) -> Option<(String, usize)> {
+    let end = data.iter().position(|b| *b == 0)?;
+    let name = String::from_utf8_lossy(&data[..end]).into_owned();
+    if name.is_empty() {
+        None
+    } else {
+        Some((name, end + 1))
+    }
+}
+
+fn find_by_build_id(build_id: &str) -> Option<PathBuf> {
+    if build_id.len() < 3 {
+        return None;
+    }
+    let path = Path::new(DEBUG_FILE_DIRECTORY)
+        .join(".build-id")
+        .join(&build_id[..2])
+        .join(format!("{}.debug", &build_id[2..]));
+
+    path.is_file().then_some(path)
+}
+
+fn find_by_debuglink(file_name: &str, debuglink: &str, crc: u32) -> Option<PathBuf> {
+    // The name must not point out of the searched directories
+    if debuglink.contains('/') {
+        return None;
+    }
+    let object_path = fs::canonicalize(file_name).ok()?;
+    let directory = object_path.parent()?;
+    let candidates = [
+        directory.join(debuglink),
+        directory.join(".debug").join(debuglink),
+        Path::new(DEBUG_FILE_DIRECTORY)
+            .join(directory.strip_prefix("/").unwrap_or(directory))
+            .join(debuglink),
+    ];
+
+    candidates.into_iter().find(|path| {
+        *path != object_path && fs::read(path).is_ok_and(|bytes| crc32fast::hash(&bytes) == crc)
+    })
+}
+
+pub(crate) fn parse_debug_links(
+    elf: &Elf<'_>,
+    buffer: &[u8],
+    build_id: Option<String>,
+) -> DebugLinks {
+    let endian = if elf.little_endian {
+        Endian::Little
+    } else {
+        Endian::Big
+    };
+
+    // The CRC comes after the name which is padded to 4 bytes
+    let debuglink = section_data(elf, buffer, ".gnu_debuglink").and_then(|data| {
+        let (name, name_size) = split_file_name(data)?;
+        let crc = data.pread_with::<u32>((name_size + 3) & !3, endian).ok()?;
+        Some((name, crc))
+    });
+
+    // The build ID of the supplementary file directly follows the name
+    let debugaltlink = section_data(elf, buffer, ".gnu_debugaltlink").and_then(|data| {
+        let (name, name_size) = split_file_name(data)?;
+        Some((name, to_hex(&data[name_size..])))
+    });
+
+    DebugLinks {
+        build_id,
+        debuglink,
+        debugaltlink,
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    debug::{flag_names, option_to_c_string},
+    structs::{CharVec, ElfDynamicInfo, U64Vec},
+    types::{ELF_DYNAMIC_FLAGS, ELF_DYNAMIC_FLAGS_1},
+};
+use goblin::elf::{
+    dynamic::{
+        DF_TEXTREL, DT_AUDIT, DT_DEPAUDIT, DT_FINI_ARRAY, DT_FINI_ARRAYSZ, DT_FLAGS, DT_FLAGS_1,
+        DT_INIT_ARRAY, DT_INIT_ARRAYSZ, DT_PREINIT_ARRAY, DT_PREINIT_ARRAYSZ, DT_RPATH, DT_RUNPATH,
+        DT_TEXTREL,
+    },
+    program_header::PT_LOAD,
+    Elf,
+};
+use scroll::{Endian, Pread};
+use std::collections::HashMap;
+
+// Source: https://refspecs.linuxfoundation.org/elf/gabi4+/ch5.dynamic.html
+
+// goblin doesn't define the tags of the filters
+const DT_AUXILIARY: u64 = 0x7fff_fffd;
+const DT_FILTER: u64 = 0x7fff_ffff;
+
+///
+/// Reads the function pointers in an init/fini array.
+///
+/// The pointers of position independent objects which use RELA relocations are zero in the file,
+/// so the addends of the relative relocations (which have no symbol) on the slots are used instead.
+///
+/// The size is not trusted, the array is cut at the end of the bytes its segment maps from the file.
+///
+fn read_array(
+    elf: &Elf<'_>,
+    buffer: &[u8],
+    addends: &HashMap<u64, u64>,
+    address: u64,
+    size: u64,
+) -> U64Vec {
+    let endian = if elf.little_endian {
+        Endian::Little
+    } else {
+        Endian::Big
+    };
+    let pointer_size: u64 = if elf.is_64 { 8 } else { 4 };
+    let Some(data) = elf
+        .program_headers
+        .iter()
+        .filter(|p| p.p_type == PT_LOAD)
+        .find(|p| address >= p.p_vaddr && address < p.p_vaddr.saturating_add(p.p_filesz))
+        .and_then(|p| {
+            let start = (address - p.p_vaddr).checked_add(p.p_offset)? as usize;
+            let end = p.p_offset.checked_add(p.p_filesz)? as usize;
+            buffer.get(start..end.min(buffer.len()))
+        })
+    else {
+        return U64Vec::default();
+    };
+
+    let count = size.min(data.len() as u64) / pointer_size;
+    let pointers: Vec<u64> = (0..count)
+        .map(|index| {
+            let offset = (index * pointer_size) as usize;
+            let value = if elf.is_64 {
+                data.pread_with::<u64>(offset, endian).ok()
+            } else {
+                data.pread_with::<u32>(offset, endian)
+                    .ok()
+                    .map(|v| v as u64)
+            };
+            match value {
+                Some(0) | None => addends
+                    .get(&(address + index * pointer_size))
+                    .copied()
+                    .unwrap_or(0),
+                Some(value) => value,
+            }
+        })
+        .collect();
+
+    U64Vec::from(pointers)
+}
+
+pub(crate) fn parse_dynamic(elf: &Elf<'_>, buffer: &[u8]) -> ElfDynamicInfo {
+    let Some(dynamic) = &elf.dynamic else {
+        return ElfDynamicInfo::default();
+    };
+    let value = |tag: u64| {
+        dynamic
+            .dyns
+            .iter()
+            .find(|d| d.d_tag == tag)
+            .map(|d| d.d_val)
+    };
+    // Search paths and the lists of audit libraries are separated by colons
+    let strings = |tags: &[u64]| -> CharVec {
+        let strings: Vec<&str> = dynamic
+            .dyns
+            .iter()
+            .filter(|d| tags.contains(&d.d_tag))
+            .filter_map(|d| elf.dynstrtab.get_at(d.d_val as usize))
+            .flat_map(|string| string.split(':'))
+            .filter(|string| !string.is_empty())
+            .collect();
+        CharVec::from(strings)
+    };
+    // The addends of the relative relocations by the slots they are applied to
+    let addends: HashMap<u64, u64> = elf
+        .dynrelas
+        .iter()
+        .filter(|rela| rela.r_sym == 0)
+        .filter_map(|rela| Some((rela.r_offset, rela.r_addend? as u64)))
+        .collect();
+    let array = |address_tag: u64, size_tag: u64| -> U64Vec {
+        match (value(address_tag), value(size_tag)) {
+            (Some(address), Some(size)) => read_array(elf, buffer, &addends, address, size),
+            _ => Default::default(),
+        }
+    };
+    let flags = value(DT_FLAGS).unwrap_or(0);
+
+    ElfDynamicInfo {
+        soname: option_to_c_string(elf.soname),
+        rpath: strings(&[DT_RPATH]),
+        runpath: strings(&[DT_RUNPATH]),
+        flags: flag_names(flags, &ELF_DYNAMIC_FLAGS),
+        flags_1: flag_names(value(DT_FLAGS_1).unwrap_or(0), &ELF_DYNAMIC_FLAGS_1),
+        audit: strings(&[DT_AUDIT, DT_DEPAUDIT]),
+        filter: strings(&[DT_FILTER]),
+        auxiliary: strings(&[DT_AUXILIARY]),
+        has_textrel: value(DT_TEXTREL).is_some() || flags & DF_TEXTREL != 0,
+        init: dynamic.info.init,
+        fini: dynamic.info.fini,
+        preinit_array: array(DT_PREINIT_ARRAY, DT_PREINIT_ARRAYSZ),
+        init_array: array(DT_INIT_ARRAY, DT_INIT_ARRAYSZ),
+        fini_array: array(DT_FINI_ARRAY, DT_FINI_ARRAYSZ),
+    }
+}
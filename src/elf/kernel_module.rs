@@ -0,0 +1,92 @@
+use crate::{
+    debug::{option_to_c_string, read_c_str},
+    elf::section_data,
+    structs::{CharVec, KernelModuleInfo},
+};
+use goblin::elf::{header::ET_REL, Elf};
+use scroll::{Endian, Pread};
+
+/*
+Sources:
+    https://github.com/torvalds/linux/blob/master/include/linux/moduleparam.h
+    https://github.com/torvalds/linux/blob/master/include/linux/module.h
+    https://github.com/torvalds/linux/blob/master/scripts/mod/modpost.c
+*/
+
+// struct modversion_info is an unsigned long CRC followed by the name, 64 bytes in total
+const MODVERSION_INFO_SIZE: usize = 64;
+
+fn parse_versions(elf: &Elf<'_>, data: &[u8]) -> Vec<String> {
+    let endian = if elf.little_endian {
+        Endian::Little
+    } else {
+        Endian::Big
+    };
+    let crc_size = if elf.is_64 { 8 } else { 4 };
+
+    data.chunks_exact(MODVERSION_INFO_SIZE)
+        .filter_map(|entry| {
+            let crc = if elf.is_64 {
+                entry.pread_with::<u64>(0, endian).ok()?
+            } else {
+                entry.pread_with::<u32>(0, endian).ok()? as u64
+            };
+            let name = read_c_str(&entry[crc_size..]);
+            (!name.is_empty()).then(|| format!("{}: {:#010x}", name, crc))
+        })
+        .collect()
+}
+
+/// Reads `.modinfo` and `__versions` if the object is a kernel module. The modules in `depends=` are returned as the libraries.
+pub(crate) fn parse_kernel_module(
+    elf: &Elf<'_>,
+    buffer: &[u8],
+) -> Option<(KernelModuleInfo, Vec<String>)> {
+    if elf.header.e_type != ET_REL {
+        return None;
+    }
+    let modinfo = section_data(elf, buffer, ".modinfo")?;
+
+    // The section is a list of NUL-terminated "key=value" strings and some keys (like author and alias) repeat
+    let entries: Vec<(String, String)> = modinfo
+        .split(|b| *b == 0)
+        .filter_map(|entry| {
+            let entry = String::from_utf8_lossy(entry);
+            let (key, value) = entry.split_once('=')?;
+            Some((key.to_owned(), value.to_owned()))
+        })
+        .collect();
+    let values = |key: &str| -> Vec<String> {
+        entries
+            .iter()
+            .filter(|(k, _)| k == key)
+            .map(|(_, value)| value.clone())
+            .collect()
+    };
+    let value = |key: &str| option_to_c_string(values(key).first());
+    let depends: Vec<String> = values("depends")
+        .iter()
+        .flat_map(|modules| modules.split(','))
+        .filter(|module| !module.is_empty())
+        .map(|module| module.to_owned())
+        .collect();
+
+    let symbol_versions = section_data(elf, buffer, "__versions")
+        .map(|data| parse_versions(elf, data))
+        .unwrap_or_default();
+
+    let info = KernelModuleInfo {
+        is_kernel_module: true,
+        name: value("name"),
+        license: value("license"),
+        vermagic: value("vermagic"),
+        srcversion: value("srcversion"),
+        version: value("version"),
+        description: value("description"),
+        authors: CharVec::from(values("author")),
+        aliases: CharVec::from(values("alias")),
+        symbol_versions: CharVec::from(symbol_versions),
+    };
+
+    Some((info, depends))
+}
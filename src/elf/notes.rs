@@ -0,0 +1,194 @@
+use crate::{debug::to_hex, types::ElfOS};
+use goblin::elf::{note::Note, Elf};
+use scroll::{Endian, Pread};
+
+/*
+Sources:
+    https://refspecs.linuxfoundation.org/LSB_1.2.0/gLSB/noteabitag.html
+    https://android.googlesource.com/platform/bionic/+/master/libc/arch-common/bionic/abi_note.S
+    https://github.com/freebsd/freebsd-src/blob/main/sys/sys/elf_common.h
+    https://www.netbsd.org/docs/kernel/elf-notes.html
+    https://github.com/golang/go/blob/master/src/cmd/link/internal/ld/elf.go
+    https://gitlab.com/x86-psABIs/x86-64-ABI (Program Property)
+    https://github.com/ARM-software/abi-aa/blob/main/sysvabi64/sysvabi64.rst (Program Property)
+*/
+
+const NT_ABI_TAG: u32 = 1;
+const NT_GNU_BUILD_ID: u32 = 3;
+const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+const NT_GO_BUILD_ID: u32 = 4;
+
+const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+const GNU_PROPERTY_1_NEEDED: u32 = 0xb0008000;
+const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000;
+const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002;
+const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 0xc0008002;
+
+const GNU_PROPERTY_1_NEEDED_FLAGS: [&str; 1] = ["indirect external access"];
+const AARCH64_FEATURE_1_FLAGS: [&str; 3] = ["BTI", "PAC", "GCS"];
+const X86_FEATURE_1_FLAGS: [&str; 4] = ["IBT", "SHSTK", "LAM_U48", "LAM_U57"];
+const X86_ISA_1_FLAGS: [&str; 4] = ["x86-64-baseline", "x86-64-v2", "x86-64-v3", "x86-64-v4"];
+
+/// The information found in the notes of an ELF object.
+#[derive(Default)]
+pub(crate) struct ElfNotes {
+    pub(crate) os: Option<ElfOS>,
+    pub(crate) os_version: Option<String>,
+    pub(crate) is_go: bool,
+    pub(crate) build_id: Option<String>,
+    /// The program properties in `.note.gnu.property` as "name: value" strings.
+    pub(crate) properties: Vec<String>,
+}
+
+fn read_u32(note: &Note<'_>, index: usize, endian: Endian) -> Option<u32> {
+    note.desc.pread_with::<u32>(index * 4, endian).ok()
+}
+
+fn parse_gnu_abi_tag(note: &Note<'_>, endian: Endian, notes: &mut ElfNotes) {
+    let Some(os) = read_u32(note, 0, endian) else {
+        return;
+    };
+    notes.os = match os {
+        0 => Some(ElfOS::Linux),
+        1 => Some(ElfOS::GNUHurd),
+        2 => Some(ElfOS::Solaris),
+        3 => Some(ElfOS::FreeBSD),
+        _ => return,
+    };
+    if let (Some(major), Some(minor), Some(patch)) = (
+        read_u32(note, 1, endian),
+        read_u32(note, 2, endian),
+        read_u32(note, 3, endian),
+    ) {
+        notes.os_version = Some(format!("{}.{}.{}", major, minor, patch));
+    }
+}
+
+// Unlike the flags of the headers, the bits of the properties are indexes into the names
+fn property_flag_names(bits: u32, names: &[&str]) -> String {
+    let mut flags: Vec<String> = names
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| bits & (1 << index) != 0)
+        .map(|(_, name)| name.to_string())
+        .collect();
+    let unknown = bits & !((1 << names.len()) - 1);
+    if unknown != 0 {
+        flags.push(format!("{:#x}", unknown));
+    }
+
+    if flags.is_empty() {
+        "none".to_owned()
+    } else {
+        flags.join(", ")
+    }
+}
+
+// The descriptor is an array of (type, size, data) entries and each entry is padded to the word size
+fn parse_gnu_properties(note: &Note<'_>, endian: Endian, is_64: bool, notes: &mut ElfNotes) {
+    let alignment = if is_64 { 8 } else { 4 };
+    let mut offset = 0;
+
+    while let (Ok(pr_type), Ok(pr_datasz)) = (
+        note.desc.pread_with::<u32>(offset, endian),
+        note.desc.pread_with::<u32>(offset + 4, endian),
+    ) {
+        let Some(data_end) = (offset + 8).checked_add(pr_datasz as usize) else {
+            break;
+        };
+        let Some(data) = note.desc.get(offset + 8..data_end) else {
+            break;
+        };
+        let word = data.pread_with::<u32>(0, endian).ok();
+
+        let property = match (pr_type, word) {
+            (GNU_PROPERTY_STACK_SIZE, _) => {
+                let size = if is_64 {
+                    data.pread_with::<u64>(0, endian).ok()
+                } else {
+                    word.map(|w| w as u64)
+                };
+                size.map(|size| format!("Stack size: {:#x}", size))
+            }
+            (GNU_PROPERTY_NO_COPY_ON_PROTECTED, _) => Some("No copy on protected".to_owned()),
+            (GNU_PROPERTY_1_NEEDED, Some(bits)) => Some(format!(
+                "Needed: {}",
+                property_flag_names(bits, &GNU_PROPERTY_1_NEEDED_FLAGS)
+            )),
+            (GNU_PROPERTY_AARCH64_FEATURE_1_AND, Some(bits)) => Some(format!(
+                "AArch64 features: {}",
+                property_flag_names(bits, &AARCH64_FEATURE_1_FLAGS)
+            )),
+            (GNU_PROPERTY_X86_FEATURE_1_AND, Some(bits)) => Some(format!(
+                "x86 features: {}",
+                property_flag_names(bits, &X86_FEATURE_1_FLAGS)
+            )),
+            (GNU_PROPERTY_X86_ISA_1_NEEDED, Some(bits)) => Some(format!(
+                "x86 ISA needed: {}",
+                property_flag_names(bits, &X86_ISA_1_FLAGS)
+            )),
+            _ => None,
+        };
+        notes.properties.extend(property);
+
+        // The end is inside the descriptor, so rounding it up doesn't overflow
+        offset = (data_end + alignment - 1) & !(alignment - 1);
+    }
+}
+
+fn parse_note(note: &Note<'_>, endian: Endian, is_64: bool, notes: &mut ElfNotes) {
+    // Go pads the name with NUL bytes instead of counting only the terminator
+    match (note.name.trim_end_matches('\0'), note.n_type) {
+        ("GNU", NT_ABI_TAG) => parse_gnu_abi_tag(note, endian, notes),
+        ("GNU", NT_GNU_BUILD_ID) if !note.desc.is_empty() => {
+            notes.build_id = Some(to_hex(note.desc))
+        }
+        ("GNU", NT_GNU_PROPERTY_TYPE_0) => parse_gnu_properties(note, endian, is_64, notes),
+        ("Android", NT_ABI_TAG) => {
+            notes.os = Some(ElfOS::Android);
+            notes.os_version = read_u32(note, 0, endian).map(|api| format!("API level {}", api));
+        }
+        // __FreeBSD_version is formatted as MMmmXXX
+        ("FreeBSD", NT_ABI_TAG) => {
+            notes.os = Some(ElfOS::FreeBSD);
+            notes.os_version = read_u32(note, 0, endian)
+                .map(|v| format!("{}.{} ({})", v / 100000, v / 1000 % 100, v));
+        }
+        // __NetBSD_Version__ is formatted as MMmmrrpp00
+        ("NetBSD", NT_ABI_TAG) => {
+            notes.os = Some(ElfOS::NetBSD);
+            notes.os_version = read_u32(note, 0, endian)
+                .map(|v| format!("{}.{} ({})", v / 100000000, v / 1000000 % 100, v));
+        }
+        // The descriptor of the OpenBSD note is always zero
+        ("OpenBSD", NT_ABI_TAG) => notes.os = Some(ElfOS::OpenBSD),
+        ("Haiku", _) => notes.os = Some(ElfOS::Haiku),
+        // Core dumps of Linux carry the extended register sets in notes named "LINUX"
+        ("LINUX", _) if notes.os.is_none() => notes.os = Some(ElfOS::Linux),
+        ("Go", NT_GO_BUILD_ID) => notes.is_go = true,
+        _ => {}
+    }
+}
+
+/// Reads the notes from the sections and, if the section headers are stripped, from the program headers.
+pub(crate) fn parse_notes(elf: &Elf<'_>, buffer: &[u8]) -> ElfNotes {
+    let mut notes = ElfNotes::default();
+    let endian = if elf.little_endian {
+        Endian::Little
+    } else {
+        Endian::Big
+    };
+
+    let iterator = elf
+        .iter_note_sections(buffer, None)
+        .or_else(|| elf.iter_note_headers(buffer));
+    for note in iterator.into_iter().flatten() {
+        let Ok(note) = note else {
+            break;
+        };
+        parse_note(&note, endian, elf.is_64, &mut notes);
+    }
+
+    notes
+}
@@ -0,0 +1,70 @@
+use crate::{
+    debug::option_to_c_string,
+    dex::read_version,
+    elf::vaddr_to_offset,
+    structs::DexInfo,
+    types::{ART_INSTRUCTION_SET, ART_INSTRUCTION_SET_LEGACY},
+};
+use goblin::elf::{
+    header::{EM_386, EM_AARCH64, EM_ARM, EM_MIPS, EM_RISCV, EM_X86_64},
+    Elf,
+};
+use scroll::{Endian, Pread};
+
+/*
+Sources:
+    https://android.googlesource.com/platform/art/+/refs/heads/main/runtime/oat/oat.h
+    https://android.googlesource.com/platform/art/+/refs/heads/main/libartbase/arch/instruction_set.h
+*/
+
+const OAT_MAGIC: &[u8] = b"oat\n";
+
+// The numbers of the instruction sets changed when RISC-V was added, so the one which matches the machine of the ELF header is chosen
+fn instruction_set(value: u32, e_machine: u16) -> Option<&'static str> {
+    let machine = |instruction_set: &str| match instruction_set {
+        "arm" => EM_ARM,
+        "arm64" => EM_AARCH64,
+        "riscv64" => EM_RISCV,
+        "x86" => EM_386,
+        "x86_64" => EM_X86_64,
+        _ => EM_MIPS,
+    };
+    let candidates = [
+        ART_INSTRUCTION_SET.get(&value),
+        ART_INSTRUCTION_SET_LEGACY.get(&value),
+    ];
+
+    candidates
+        .iter()
+        .flatten()
+        .find(|instruction_set| machine(instruction_set) == e_machine)
+        .or(candidates[0].as_ref())
+        .map(|instruction_set| **instruction_set)
+}
+
+/// Reads the OAT header which `oatdata` points to if the object was compiled by dex2oat.
+pub(crate) fn parse_oat(elf: &Elf<'_>, buffer: &[u8]) -> Option<DexInfo> {
+    let oatdata = elf
+        .dynsyms
+        .iter()
+        .find(|sym| elf.dynstrtab.get_at(sym.st_name) == Some("oatdata"))?;
+    let header = buffer.get(vaddr_to_offset(elf, oatdata.st_value)?..)?;
+    if !header.starts_with(OAT_MAGIC) {
+        return None;
+    }
+    let endian = if elf.little_endian {
+        Endian::Little
+    } else {
+        Endian::Big
+    };
+    // The checksum is followed by the instruction set, its features and the number of the DEX files
+    let value = header.pread_with::<u32>(12, endian).ok()?;
+    let dex_file_count = header.pread_with::<u32>(20, endian).ok()?;
+
+    Some(DexInfo {
+        version: option_to_c_string(read_version(header)),
+        dex_file_count,
+        instruction_set: option_to_c_string(instruction_set(value, elf.header.e_machine)),
+        ..Default::default()
+    })
+}
@@ -0,0 +1,132 @@
+use crate::elf::{notes::ElfNotes, section_data};
+use gimli::{EndianSlice, RunTimeEndian, SectionId};
+use goblin::elf::{section_header::SHF_COMPRESSED, Elf};
+
+/*
+Sources:
+    https://gcc.gnu.org/onlinedocs/gcc/Code-Gen-Options.html (-frecord-gcc-switches)
+    https://dwarfstd.org/doc/DWARF5.pdf (DW_AT_producer)
+*/
+
+fn push_unique(toolchain: &mut Vec<String>, entry: &str) {
+    let entry = entry.trim();
+    if !entry.is_empty() && !toolchain.iter().any(|e| e == entry) {
+        toolchain.push(entry.to_owned());
+    }
+}
+
+// Both sections are lists of NUL-terminated strings
+fn split_strings(data: &[u8]) -> impl Iterator<Item = String> + '_ {
+    data.split(|b| *b == 0)
+        .map(|entry| String::from_utf8_lossy(entry).into_owned())
+}
+
+// Compressed sections (`SHF_COMPRESSED`) can't be read without decompressing them
+fn uncompressed_section_data<'a>(elf: &Elf<'_>, buffer: &'a [u8], name: &str) -> &'a [u8] {
+    let is_compressed = elf.section_headers.iter().any(|s| {
+        elf.shdr_strtab.get_at(s.sh_name) == Some(name) && s.sh_flags & SHF_COMPRESSED as u64 != 0
+    });
+    if is_compressed {
+        return &[];
+    }
+
+    section_data(elf, buffer, name).unwrap_or(&[])
+}
+
+/// Reads the producers of the compilation units in `.debug_info`.
+fn find_producers(elf: &Elf<'_>, buffer: &[u8]) -> Vec<String> {
+    let endian = if elf.little_endian {
+        RunTimeEndian::Little
+    } else {
+        RunTimeEndian::Big
+    };
+    let load = |id: SectionId| -> Result<EndianSlice<'_, RunTimeEndian>, gimli::Error> {
+        Ok(EndianSlice::new(
+            uncompressed_section_data(elf, buffer, id.name()),
+            endian,
+        ))
+    };
+    let Ok(dwarf) = gimli::Dwarf::load(load) else {
+        return Vec::new();
+    };
+
+    let mut producers = Vec::new();
+    let mut units = dwarf.units();
+    while let Ok(Some(header)) = units.next() {
+        let Ok(unit) = dwarf.unit(header) else {
+            continue;
+        };
+        let mut entries = unit.entries();
+        // The producer is an attribute of the root entry of the unit
+        let Ok(Some((_, entry))) = entries.next_dfs() else {
+            continue;
+        };
+        if let Some(producer) = entry
+            .attr_value(gimli::DW_AT_producer)
+            .ok()
+            .flatten()
+            .and_then(|value| dwarf.attr_string(&unit, value).ok())
+        {
+            push_unique(&mut producers, &producer.to_string_lossy());
+        }
+    }
+
+    producers
+}
+
+fn has_section(elf: &Elf<'_>, name: &str) -> bool {
+    elf.section_headers
+        .iter()
+        .any(|s| elf.shdr_strtab.get_at(s.sh_name) == Some(name))
+}
+
+///
+/// Lists the compilers, linkers and the other tools which built the object.
+///
+/// The entries come from (in order):
+/// - `.comment`: Version strings of the compilers and the linkers (Like: "GCC: (GNU) 14.2.1", "Linker: LLD 18.1.8")
+/// - `DW_AT_producer`: The compilers of the compilation units in `.debug_info` with their options
+/// - `.GCC.command.line`: The options recorded by `-frecord-gcc-switches`
+/// - `.note.gnu.property`: The hardening features and the ISA level the toolchain marked the object with
+/// - Go (with the version in the build information) and Rust (detected by `parse_rust`), if the languages are not already named by the entries above
+///
+pub(crate) fn find_toolchain(
+    elf: &Elf<'_>,
+    buffer: &[u8],
+    notes: &ElfNotes,
+    go_version: Option<&str>,
+    is_rust: bool,
+) -> Vec<String> {
+    let mut toolchain = Vec::new();
+
+    for entry in split_strings(uncompressed_section_data(elf, buffer, ".comment")) {
+        push_unique(&mut toolchain, &entry);
+    }
+    for producer in find_producers(elf, buffer) {
+        push_unique(&mut toolchain, &producer);
+    }
+    for switches in split_strings(uncompressed_section_data(elf, buffer, ".GCC.command.line")) {
+        if !switches.trim().is_empty() {
+            push_unique(&mut toolchain, &format!("GCC command line: {}", switches));
+        }
+    }
+    for property in &notes.properties {
+        push_unique(&mut toolchain, &format!("GNU property: {}", property));
+    }
+
+    let mentions = |pat: &str| toolchain.iter().any(|entry| entry.contains(pat));
+    let is_go = go_version.is_some()
+        || notes.is_go
+        || has_section(elf, ".gopclntab")
+        || has_section(elf, ".go.buildinfo");
+    let is_go = is_go && !mentions("Go cmd/compile");
+    let is_rust = is_rust && !mentions("rustc");
+    if is_go {
+        toolchain.push(go_version.map_or("Go".to_owned(), |v| format!("Go: {}", v)));
+    }
+    if is_rust {
+        toolchain.push("Rust".to_owned());
+    }
+
+    toolchain
+}
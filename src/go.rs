@@ -0,0 +1,188 @@
+use crate::{
+    debug::{option_to_c_string, read_c_str},
+    structs::{CharVec, GoBuildInfo},
+};
+use scroll::{Endian, Pread};
+
+/*
+Sources:
+    https://github.com/golang/go/blob/master/src/debug/buildinfo/buildinfo.go
+    https://github.com/golang/go/blob/master/src/runtime/debug/mod.go
+    https://github.com/golang/go/blob/master/src/cmd/go/internal/modload/build.go (the sentinels of modinfo)
+*/
+
+const BUILD_INFO_MAGIC: &[u8] = b"\xff Go buildinf:";
+const BUILD_INFO_ALIGNMENT: usize = 16;
+const BUILD_INFO_HEADER_SIZE: usize = 32;
+const FLAGS_ENDIAN_BIG: u8 = 0x1;
+const FLAGS_VERSION_INLINE: u8 = 0x2;
+// The module information is wrapped by 16-byte sentinels to be found in the binary
+const MOD_INFO_SENTINEL_SIZE: usize = 16;
+
+///
+/// The loadable parts of a Go binary with their virtual addresses.
+///
+/// Binaries built before Go 1.18 store pointers to the strings instead of the strings.
+///
+pub(crate) struct GoMemory<'a> {
+    pub(crate) regions: Vec<(u64, &'a [u8])>,
+}
+
+impl<'a> GoMemory<'a> {
+    fn read(&self, address: u64, size: u64) -> Option<&'a [u8]> {
+        self.regions.iter().find_map(|(start, data)| {
+            let offset = address.checked_sub(*start)?;
+            let end = offset.checked_add(size)?;
+            data.get(offset as usize..end as usize)
+        })
+    }
+}
+
+fn read_uvarint(data: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0u64;
+    for (index, byte) in data.iter().take(10).enumerate() {
+        value |= ((byte & 0x7f) as u64) << (index * 7);
+        if byte & 0x80 == 0 {
+            return Some((value, index + 1));
+        }
+    }
+
+    None
+}
+
+fn read_inline_strings(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (length, size) = read_uvarint(data)?;
+    let version_end = size.checked_add(length as usize)?;
+    let version = data.get(size..version_end)?;
+    let data = &data[version_end..];
+    let (length, size) = read_uvarint(data)?;
+    let mod_info = data.get(size..size.checked_add(length as usize)?)?;
+
+    Some((version, mod_info))
+}
+
+// A Go string is a pointer to the bytes followed by the length
+fn read_string_pointer<'a>(
+    header: &[u8],
+    index: usize,
+    pointer_size: usize,
+    endian: Endian,
+    memory: &GoMemory<'a>,
+) -> Option<&'a [u8]> {
+    let read_word = |data: &[u8], offset: usize| -> Option<u64> {
+        match pointer_size {
+            8 => data.pread_with::<u64>(offset, endian).ok(),
+            4 => data
+                .pread_with::<u32>(offset, endian)
+                .ok()
+                .map(|w| w as u64),
+            _ => None,
+        }
+    };
+    let string_header_address = read_word(header, 16 + index * pointer_size)?;
+    let string_header = memory.read(string_header_address, 2 * pointer_size as u64)?;
+    let address = read_word(string_header, 0)?;
+    let length = read_word(string_header, pointer_size)?;
+
+    memory.read(address, length)
+}
+
+fn find_header(data: &[u8]) -> Option<&[u8]> {
+    (0..data.len())
+        .step_by(BUILD_INFO_ALIGNMENT)
+        .map(|offset| &data[offset..])
+        .find(|rest| rest.len() >= BUILD_INFO_HEADER_SIZE && rest.starts_with(BUILD_INFO_MAGIC))
+}
+
+fn strip_sentinels(mod_info: &[u8]) -> &[u8] {
+    if mod_info.len() > 2 * MOD_INFO_SENTINEL_SIZE
+        && mod_info[mod_info.len() - MOD_INFO_SENTINEL_SIZE - 1] == b'\n'
+    {
+        &mod_info[MOD_INFO_SENTINEL_SIZE..mod_info.len() - MOD_INFO_SENTINEL_SIZE]
+    } else {
+        mod_info
+    }
+}
+
+///
+/// Decodes the module information which is the output of `go version -m` without the Go version.
+///
+/// The lines are tab-separated and their first fields are:
+/// - path: The package path of the main package
+/// - mod: The main module (path, version and checksum)
+/// - dep: A dependency (path, version and checksum)
+/// - =>: The replacement of the previous module
+/// - build: A build setting as key=value
+///
+fn parse_mod_info(go_version: &str, mod_info: &[u8]) -> GoBuildInfo {
+    let mut path = None;
+    let mut main_module = None;
+    let mut dependencies: Vec<String> = vec![];
+    let mut build_settings: Vec<String> = vec![];
+
+    let mod_info = String::from_utf8_lossy(strip_sentinels(mod_info));
+    for line in mod_info.lines() {
+        let mut fields = line.split('\t');
+        let kind = fields.next().unwrap_or_default();
+        // The fields are cut at NUL bytes like the C strings they are given as
+        let rest: Vec<&str> = fields
+            .map(|field| field.split('\0').next().unwrap_or_default())
+            .filter(|field| !field.is_empty())
+            .collect();
+        match kind {
+            "path" => path = rest.first().map(|p| p.to_string()),
+            "mod" => main_module = Some(rest.join(" ")),
+            "dep" => dependencies.push(rest.join(" ")),
+            "=>" => {
+                if let Some(dependency) = dependencies.last_mut() {
+                    *dependency = format!("{} => {}", dependency, rest.join(" "));
+                } else if let Some(module) = main_module.as_mut() {
+                    *module = format!("{} => {}", module, rest.join(" "));
+                }
+            }
+            "build" => build_settings.push(rest.join(" ")),
+            _ => {}
+        }
+    }
+
+    GoBuildInfo {
+        is_go: true,
+        go_version: option_to_c_string(Some(go_version)),
+        path: option_to_c_string(path),
+        main_module: option_to_c_string(main_module),
+        dependencies: CharVec::from(dependencies),
+        build_settings: CharVec::from(build_settings),
+    }
+}
+
+///
+/// Searches the build information in `candidates` (the `.go.buildinfo` section or the writable data of the binary) and decodes it.
+///
+/// Returns the Go version with the decoded information.
+///
+pub(crate) fn parse_go_build_info(
+    candidates: &[&[u8]],
+    memory: &GoMemory<'_>,
+) -> Option<(String, GoBuildInfo)> {
+    let header = candidates.iter().find_map(|data| find_header(data))?;
+    let pointer_size = header[BUILD_INFO_MAGIC.len()] as usize;
+    let flags = header[BUILD_INFO_MAGIC.len() + 1];
+
+    let (go_version, mod_info) = if flags & FLAGS_VERSION_INLINE != 0 {
+        read_inline_strings(&header[BUILD_INFO_HEADER_SIZE..])?
+    } else {
+        let endian = if flags & FLAGS_ENDIAN_BIG != 0 {
+            Endian::Big
+        } else {
+            Endian::Little
+        };
+        (
+            read_string_pointer(header, 0, pointer_size, endian, memory)?,
+            read_string_pointer(header, 1, pointer_size, endian, memory).unwrap_or_default(),
+        )
+    };
+    let go_version = read_c_str(go_version);
+    let go = parse_mod_info(&go_version, mod_info);
+
+    Some((go_version, go))
+}
@@ -1,5 +1,10 @@
 use crate::{
-    structs::{CharVec, Debugging, ParsingError, ULDDObj},
+    structs::{
+        AoutInfo, CharVec, CoreDumpInfo, CoreThread, CoreThreadVec, Debugging, DexInfo, DotNetInfo,
+        ElfDebugInfo, ElfDynamicInfo, GoBuildInfo, JavaInfo, KernelModuleInfo, MzInfo, PackageInfo,
+        ParsingError, PeAnomalies, PeDebugInfo, PeLoadInfo, RustInfo, SectionInfo, SectionInfoVec,
+        StrippingInfo, U64Vec, ULDDObj, WasmInfo, XcoffInfo,
+    },
     ULDDObjResult, ULDDObjResultVec,
 };
 use anstream::{eprintln as a_eprintln, println as a_println};
@@ -51,6 +56,48 @@ impl From<Vec<ULDDObjResult>> for ULDDObjResultVec {
     }
 }
 
+impl From<Vec<CoreThread>> for CoreThreadVec {
+    fn from(value: Vec<CoreThread>) -> Self {
+        CoreThreadVec {
+            capacity: value.capacity(),
+            length: value.len(),
+            vec: if value.is_empty() {
+                null_mut()
+            } else {
+                ManuallyDrop::new(value).as_mut_ptr()
+            },
+        }
+    }
+}
+
+impl From<Vec<SectionInfo>> for SectionInfoVec {
+    fn from(value: Vec<SectionInfo>) -> Self {
+        SectionInfoVec {
+            capacity: value.capacity(),
+            length: value.len(),
+            vec: if value.is_empty() {
+                null_mut()
+            } else {
+                ManuallyDrop::new(value).as_mut_ptr()
+            },
+        }
+    }
+}
+
+impl From<Vec<u64>> for U64Vec {
+    fn from(value: Vec<u64>) -> Self {
+        U64Vec {
+            capacity: value.capacity(),
+            length: value.len(),
+            vec: if value.is_empty() {
+                null_mut()
+            } else {
+                ManuallyDrop::new(value).as_mut_ptr()
+            },
+        }
+    }
+}
+
 impl Default for CharVec {
     fn default() -> Self {
         Self {
@@ -61,13 +108,19 @@ impl Default for CharVec {
     }
 }
 
+// The strings read from the files may have NUL bytes in them, so they are cut at the first one like C reads them
+fn to_c_string_lossy(mut bytes: Vec<u8>) -> *mut c_char {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    bytes.truncate(end);
+
+    CString::new(bytes).unwrap_or_default().into_raw()
+}
+
 impl From<Vec<&str>> for CharVec {
     fn from(val: Vec<&str>) -> Self {
         let vector: Vec<*mut c_char> = val
             .into_iter()
-            .map(|item| unsafe {
-                CString::from_vec_unchecked(item.to_string().into_bytes()).into_raw()
-            })
+            .map(|item| to_c_string_lossy(item.as_bytes().to_vec()))
             .collect();
 
         CharVec::from(vector)
@@ -78,15 +131,34 @@ impl From<&mut Vec<&str>> for CharVec {
     fn from(val: &mut Vec<&str>) -> Self {
         let vector: Vec<*mut c_char> = std::mem::take(val)
             .into_iter()
-            .map(|item| unsafe {
-                CString::from_vec_unchecked(item.to_string().into_bytes()).into_raw()
-            })
+            .map(|item| to_c_string_lossy(item.as_bytes().to_vec()))
             .collect();
 
         CharVec::from(vector)
     }
 }
 
+impl From<Vec<String>> for CharVec {
+    fn from(val: Vec<String>) -> Self {
+        let vector: Vec<*mut c_char> = val
+            .into_iter()
+            .map(|item| to_c_string_lossy(item.into_bytes()))
+            .collect();
+
+        CharVec::from(vector)
+    }
+}
+
+impl Default for U64Vec {
+    fn default() -> Self {
+        Self {
+            capacity: 0,
+            length: 0,
+            vec: null_mut(),
+        }
+    }
+}
+
 impl From<CharVec> for Vec<*mut c_char> {
     fn from(value: CharVec) -> Self {
         if value.vec.is_null() || value.length == 0 {
@@ -102,20 +174,12 @@ where
     T: Display,
 {
     fn to_c_string(self) -> *mut c_char {
-        let mut value = self.to_string();
-        value.push('\0');
-        let c_string = match CString::from_vec_with_nul(value.into_bytes()) {
-            Ok(string) => string,
-            Err(error) => {
-                Debugging::Fatal("converting the string into a C string".to_owned()).print(true);
-                panic!("{}", error)
-            }
-        };
-        c_string.into_raw()
+        to_c_string_lossy(self.to_string().into_bytes())
     }
 }
 
 impl DropCString for *mut c_char {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
     fn drop_c_string(self) {
         unsafe {
             if !self.is_null() {
@@ -135,6 +199,223 @@ impl DropCString for CharVec {
     }
 }
 
+impl DropCString for U64Vec {
+    fn drop_c_string(self) {
+        if !self.vec.is_null() && self.length != 0 {
+            let _ = unsafe { Vec::from_raw_parts(self.vec, self.length, self.capacity) };
+        }
+    }
+}
+
+impl DropCString for PeDebugInfo {
+    fn drop_c_string(self) {
+        self.entries.drop_c_string();
+        self.pdb_path.drop_c_string();
+        self.pdb_guid.drop_c_string();
+        self.pogo_sections.drop_c_string();
+        self.vc_features.drop_c_string();
+        self.repro_hash.drop_c_string();
+        self.ex_dll_characteristics.drop_c_string();
+    }
+}
+
+impl DropCString for DotNetInfo {
+    fn drop_c_string(self) {
+        self.runtime_version.drop_c_string();
+        self.assembly_name.drop_c_string();
+        self.assembly_version.drop_c_string();
+        self.culture.drop_c_string();
+        self.public_key_token.drop_c_string();
+        self.target_framework.drop_c_string();
+        self.assembly_references.drop_c_string();
+    }
+}
+
+impl DropCString for PeLoadInfo {
+    fn drop_c_string(self) {
+        self.entry_point_section.drop_c_string();
+        self.tls_callbacks.drop_c_string();
+        self.guard_flags.drop_c_string();
+        self.hybrid_kind.drop_c_string();
+    }
+}
+
+impl DropCString for PeAnomalies {
+    fn drop_c_string(self) {
+        self.section_anomalies.drop_c_string();
+    }
+}
+
+impl DropCString for ElfDebugInfo {
+    fn drop_c_string(self) {
+        self.build_id.drop_c_string();
+        self.debuglink.drop_c_string();
+        self.debugaltlink.drop_c_string();
+        self.debugaltlink_build_id.drop_c_string();
+        self.debug_file.drop_c_string();
+    }
+}
+
+impl DropCString for StrippingInfo {
+    fn drop_c_string(self) {
+        self.status.drop_c_string();
+        self.debug_info.drop_c_string();
+    }
+}
+
+impl DropCString for CoreThreadVec {
+    fn drop_c_string(self) {
+        if !self.vec.is_null() && self.length != 0 {
+            let threads = unsafe { Vec::from_raw_parts(self.vec, self.length, self.capacity) };
+            for thread in threads {
+                thread.registers.drop_c_string();
+            }
+        }
+    }
+}
+
+impl DropCString for SectionInfoVec {
+    fn drop_c_string(self) {
+        if !self.vec.is_null() && self.length != 0 {
+            let sections = unsafe { Vec::from_raw_parts(self.vec, self.length, self.capacity) };
+            for section in sections {
+                section.name.drop_c_string();
+                section.segment_name.drop_c_string();
+                section.section_type.drop_c_string();
+                section.permissions.drop_c_string();
+            }
+        }
+    }
+}
+
+impl DropCString for CoreDumpInfo {
+    fn drop_c_string(self) {
+        self.process_name.drop_c_string();
+        self.process_arguments.drop_c_string();
+        self.threads.drop_c_string();
+        self.auxv.drop_c_string();
+        self.mapped_files.drop_c_string();
+    }
+}
+
+impl DropCString for ElfDynamicInfo {
+    fn drop_c_string(self) {
+        self.soname.drop_c_string();
+        self.rpath.drop_c_string();
+        self.runpath.drop_c_string();
+        self.flags.drop_c_string();
+        self.flags_1.drop_c_string();
+        self.audit.drop_c_string();
+        self.filter.drop_c_string();
+        self.auxiliary.drop_c_string();
+        self.preinit_array.drop_c_string();
+        self.init_array.drop_c_string();
+        self.fini_array.drop_c_string();
+    }
+}
+
+impl DropCString for KernelModuleInfo {
+    fn drop_c_string(self) {
+        self.name.drop_c_string();
+        self.license.drop_c_string();
+        self.vermagic.drop_c_string();
+        self.srcversion.drop_c_string();
+        self.version.drop_c_string();
+        self.description.drop_c_string();
+        self.authors.drop_c_string();
+        self.aliases.drop_c_string();
+        self.symbol_versions.drop_c_string();
+    }
+}
+
+impl DropCString for GoBuildInfo {
+    fn drop_c_string(self) {
+        self.go_version.drop_c_string();
+        self.path.drop_c_string();
+        self.main_module.drop_c_string();
+        self.dependencies.drop_c_string();
+        self.build_settings.drop_c_string();
+    }
+}
+
+impl DropCString for RustInfo {
+    fn drop_c_string(self) {
+        self.rustc_version.drop_c_string();
+        self.rustc_commit.drop_c_string();
+        self.mangling.drop_c_string();
+        self.root_crate.drop_c_string();
+        self.dependencies.drop_c_string();
+    }
+}
+
+impl DropCString for WasmInfo {
+    fn drop_c_string(self) {
+        self.module_name.drop_c_string();
+        self.wasi.drop_c_string();
+        self.imports.drop_c_string();
+        self.exports.drop_c_string();
+        self.memories.drop_c_string();
+        self.tables.drop_c_string();
+        self.custom_sections.drop_c_string();
+        self.target_features.drop_c_string();
+    }
+}
+
+impl DropCString for XcoffInfo {
+    fn drop_c_string(self) {
+        self.module_type.drop_c_string();
+        self.flags.drop_c_string();
+        self.library_path.drop_c_string();
+    }
+}
+
+impl DropCString for MzInfo {
+    fn drop_c_string(self) {
+        self.module_name.drop_c_string();
+        self.description.drop_c_string();
+        self.entry_point.drop_c_string();
+        self.stack_pointer.drop_c_string();
+        self.flags.drop_c_string();
+        self.linker_version.drop_c_string();
+        self.extenders.drop_c_string();
+        self.packers.drop_c_string();
+    }
+}
+
+impl DropCString for AoutInfo {
+    fn drop_c_string(self) {
+        self.magic.drop_c_string();
+    }
+}
+
+impl DropCString for JavaInfo {
+    fn drop_c_string(self) {
+        self.java_release.drop_c_string();
+        self.class_name.drop_c_string();
+        self.super_class.drop_c_string();
+        self.interfaces.drop_c_string();
+        self.access_flags.drop_c_string();
+        self.source_file.drop_c_string();
+    }
+}
+
+impl DropCString for DexInfo {
+    fn drop_c_string(self) {
+        self.version.drop_c_string();
+        self.instruction_set.drop_c_string();
+    }
+}
+
+impl DropCString for PackageInfo {
+    fn drop_c_string(self) {
+        self.format.drop_c_string();
+        self.name.drop_c_string();
+        self.version.drop_c_string();
+        self.architecture.drop_c_string();
+        self.dependencies.drop_c_string();
+    }
+}
+
 impl ErrorToInt for goblin::error::Error {
     fn to_int(&self) -> i64 {
         match self {
@@ -171,6 +452,310 @@ impl Default for ULDDObj {
             cpu_subtype: null_mut(),
             interpreter: null_mut(),
             libraries: Default::default(),
+            pe_debug: Default::default(),
+            dotnet: Default::default(),
+            pe_load: Default::default(),
+            pe_anomalies: Default::default(),
+            import_hash: null_mut(),
+            os_version: null_mut(),
+            elf_debug: Default::default(),
+            stripping: Default::default(),
+            core: Default::default(),
+            kernel_module: Default::default(),
+            toolchain: Default::default(),
+            go: Default::default(),
+            rust: Default::default(),
+            elf_dynamic: Default::default(),
+            sections: Default::default(),
+            segments: Default::default(),
+            wasm: Default::default(),
+            xcoff: Default::default(),
+            mz: Default::default(),
+            aout: Default::default(),
+            java: Default::default(),
+            dex: Default::default(),
+            package: Default::default(),
+        }
+    }
+}
+
+impl Default for PeDebugInfo {
+    fn default() -> Self {
+        Self {
+            entries: Default::default(),
+            pdb_path: null_mut(),
+            pdb_guid: null_mut(),
+            pdb_age: 0,
+            pogo_sections: Default::default(),
+            vc_features: Default::default(),
+            repro_hash: null_mut(),
+            ex_dll_characteristics: Default::default(),
+        }
+    }
+}
+
+impl Default for DotNetInfo {
+    fn default() -> Self {
+        Self {
+            is_dotnet: false,
+            runtime_version: null_mut(),
+            assembly_name: null_mut(),
+            assembly_version: null_mut(),
+            culture: null_mut(),
+            public_key_token: null_mut(),
+            target_framework: null_mut(),
+            is_il_only: false,
+            requires_32bit: false,
+            is_ready_to_run: false,
+            assembly_references: Default::default(),
+        }
+    }
+}
+
+impl Default for PeLoadInfo {
+    fn default() -> Self {
+        Self {
+            entry_point: 0,
+            entry_point_section: null_mut(),
+            tls_callbacks: Default::default(),
+            security_cookie: 0,
+            se_handler_table: 0,
+            se_handler_count: 0,
+            guard_flags: Default::default(),
+            chpe_metadata: 0,
+            hybrid_kind: null_mut(),
+            exception_directory_size: 0,
+        }
+    }
+}
+
+impl Default for ElfDebugInfo {
+    fn default() -> Self {
+        Self {
+            build_id: null_mut(),
+            debuglink: null_mut(),
+            debuglink_crc: 0,
+            debugaltlink: null_mut(),
+            debugaltlink_build_id: null_mut(),
+            debug_file: null_mut(),
+        }
+    }
+}
+
+impl Default for StrippingInfo {
+    fn default() -> Self {
+        Self {
+            status: null_mut(),
+            has_symbols: false,
+            debug_info: Default::default(),
+        }
+    }
+}
+
+impl Default for CoreThreadVec {
+    fn default() -> Self {
+        Self {
+            capacity: 0,
+            length: 0,
+            vec: null_mut(),
+        }
+    }
+}
+
+impl Default for SectionInfoVec {
+    fn default() -> Self {
+        Self {
+            capacity: 0,
+            length: 0,
+            vec: null_mut(),
+        }
+    }
+}
+
+impl Default for CoreDumpInfo {
+    fn default() -> Self {
+        Self {
+            is_core: false,
+            process_name: null_mut(),
+            process_arguments: null_mut(),
+            pid: 0,
+            signal: 0,
+            threads: Default::default(),
+            auxv: Default::default(),
+            mapped_files: Default::default(),
+        }
+    }
+}
+
+impl Default for ElfDynamicInfo {
+    fn default() -> Self {
+        Self {
+            soname: null_mut(),
+            rpath: Default::default(),
+            runpath: Default::default(),
+            flags: Default::default(),
+            flags_1: Default::default(),
+            audit: Default::default(),
+            filter: Default::default(),
+            auxiliary: Default::default(),
+            has_textrel: false,
+            init: 0,
+            fini: 0,
+            preinit_array: Default::default(),
+            init_array: Default::default(),
+            fini_array: Default::default(),
+        }
+    }
+}
+
+impl Default for KernelModuleInfo {
+    fn default() -> Self {
+        Self {
+            is_kernel_module: false,
+            name: null_mut(),
+            license: null_mut(),
+            vermagic: null_mut(),
+            srcversion: null_mut(),
+            version: null_mut(),
+            description: null_mut(),
+            authors: Default::default(),
+            aliases: Default::default(),
+            symbol_versions: Default::default(),
+        }
+    }
+}
+
+impl Default for GoBuildInfo {
+    fn default() -> Self {
+        Self {
+            is_go: false,
+            go_version: null_mut(),
+            path: null_mut(),
+            main_module: null_mut(),
+            dependencies: Default::default(),
+            build_settings: Default::default(),
+        }
+    }
+}
+
+impl Default for RustInfo {
+    fn default() -> Self {
+        Self {
+            is_rust: false,
+            rustc_version: null_mut(),
+            rustc_commit: null_mut(),
+            mangling: null_mut(),
+            root_crate: null_mut(),
+            dependencies: Default::default(),
+        }
+    }
+}
+
+impl Default for WasmInfo {
+    fn default() -> Self {
+        Self {
+            version: 0,
+            is_component: false,
+            module_name: null_mut(),
+            wasi: null_mut(),
+            imports: Default::default(),
+            exports: Default::default(),
+            memories: Default::default(),
+            tables: Default::default(),
+            custom_sections: Default::default(),
+            target_features: Default::default(),
+        }
+    }
+}
+
+impl Default for XcoffInfo {
+    fn default() -> Self {
+        Self {
+            entry_point: 0,
+            toc: 0,
+            module_type: null_mut(),
+            flags: Default::default(),
+            library_path: null_mut(),
+            text_size: 0,
+            data_size: 0,
+            bss_size: 0,
+            max_stack: 0,
+            max_data: 0,
+        }
+    }
+}
+
+impl Default for MzInfo {
+    fn default() -> Self {
+        Self {
+            module_name: null_mut(),
+            description: null_mut(),
+            entry_point: null_mut(),
+            stack_pointer: null_mut(),
+            flags: Default::default(),
+            linker_version: null_mut(),
+            relocations: 0,
+            minimum_memory: 0,
+            maximum_memory: 0,
+            extenders: Default::default(),
+            packers: Default::default(),
+        }
+    }
+}
+
+impl Default for AoutInfo {
+    fn default() -> Self {
+        Self {
+            magic: null_mut(),
+            text_size: 0,
+            data_size: 0,
+            bss_size: 0,
+            entry_point: 0,
+            symbols_size: 0,
+            is_dynamic: false,
+        }
+    }
+}
+
+impl Default for JavaInfo {
+    fn default() -> Self {
+        Self {
+            major_version: 0,
+            minor_version: 0,
+            java_release: null_mut(),
+            uses_preview_features: false,
+            class_name: null_mut(),
+            super_class: null_mut(),
+            interfaces: Default::default(),
+            access_flags: Default::default(),
+            source_file: null_mut(),
+        }
+    }
+}
+
+impl Default for DexInfo {
+    fn default() -> Self {
+        Self {
+            version: null_mut(),
+            class_count: 0,
+            string_count: 0,
+            type_count: 0,
+            method_count: 0,
+            field_count: 0,
+            dex_file_count: 0,
+            instruction_set: null_mut(),
+        }
+    }
+}
+
+impl Default for PackageInfo {
+    fn default() -> Self {
+        Self {
+            format: null_mut(),
+            name: null_mut(),
+            version: null_mut(),
+            architecture: null_mut(),
+            dependencies: CharVec::default(),
         }
     }
 }
@@ -0,0 +1,334 @@
+use crate::{
+    debug::{classify_stripping, debug_objects, flag_names, merge_members, option_to_c_string},
+    impls::{ErrorToInt, StringToCString},
+    structs::{CharVec, Debugging, JavaInfo, ParsingError, ULDDObj, ULDDObjResult, ULDDOptions},
+    types::{DebugInfoFormat, DebugInfoLocation, JavaFileType, JAVA_ACCESS_FLAGS},
+};
+use goblin::error::Error;
+use scroll::{Pread, BE};
+use std::ptr::null_mut;
+
+/*
+Sources:
+    https://docs.oracle.com/javase/specs/jvms/se21/html/jvms-4.html
+*/
+
+pub(crate) const JAVA_CLASS_MAGIC: &[u8] = b"\xca\xfe\xba\xbe";
+// Multi architecture Mach-O files have the same magic number, followed by the number of the architectures.
+// Class files have the minor and the major version there, and the first major version is 45.
+const MAX_FAT_ARCHES: u32 = 30;
+
+const ACC_INTERFACE: u16 = 0x0200;
+const ACC_ANNOTATION: u16 = 0x2000;
+const ACC_ENUM: u16 = 0x4000;
+const ACC_MODULE: u16 = 0x8000;
+const PREVIEW_MINOR_VERSION: u16 = 0xffff;
+
+enum Constant {
+    Utf8(String),
+    Class(u16),
+    Other,
+}
+
+struct Reader<'a> {
+    buffer: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.buffer.gread::<u8>(&mut self.offset)?)
+    }
+
+    fn u16(&mut self) -> Result<u16, Error> {
+        Ok(self.buffer.gread_with::<u16>(&mut self.offset, BE)?)
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        Ok(self.buffer.gread_with::<u32>(&mut self.offset, BE)?)
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        let buffer = self.buffer;
+        let bytes = self
+            .offset
+            .checked_add(length)
+            .and_then(|end| buffer.get(self.offset..end))
+            .ok_or_else(|| {
+                Error::Malformed(format!(
+                    "{} bytes at {:#x} are out of the class file",
+                    length, self.offset
+                ))
+            })?;
+        self.offset += length;
+        Ok(bytes)
+    }
+}
+
+struct JavaClass {
+    major_version: u16,
+    minor_version: u16,
+    access_flags: u16,
+    class_name: Option<String>,
+    super_class: Option<String>,
+    interfaces: Vec<String>,
+    source_file: Option<String>,
+    has_debug_attributes: bool,
+}
+
+///
+/// Tells class files from multi architecture Mach-O files, which also start with 0xCAFEBABE.
+///
+pub(crate) fn is_java_class(buffer: &[u8]) -> bool {
+    buffer.starts_with(JAVA_CLASS_MAGIC)
+        && buffer
+            .pread_with::<u32>(4, BE)
+            .is_ok_and(|value| value > MAX_FAT_ARCHES)
+}
+
+///
+/// Returns the Java release which introduced the class file version.
+///
+pub(crate) fn java_release(major_version: u16) -> Option<String> {
+    match major_version {
+        45 => Some("JDK 1.1".to_owned()),
+        46..=48 => Some(format!("J2SE 1.{}", major_version - 44)),
+        49 => Some("J2SE 5.0".to_owned()),
+        50.. => Some(format!("Java SE {}", major_version - 44)),
+        _ => None,
+    }
+}
+
+fn read_constant_pool(reader: &mut Reader) -> Result<Vec<Constant>, Error> {
+    let count = reader.u16()? as usize;
+    // The indexes start from 1
+    let mut constants = vec![Constant::Other];
+    while constants.len() < count {
+        let tag = reader.u8()?;
+        let constant = match tag {
+            1 => {
+                let length = reader.u16()? as usize;
+                let bytes = reader.bytes(length)?;
+                // Modified UTF-8 encodes NUL as two bytes, so a raw NUL byte is never valid
+                if bytes.contains(&0) {
+                    return Err(Error::Malformed(format!(
+                        "The constant pool entry {} has a NUL byte in its string",
+                        constants.len()
+                    )));
+                }
+                Constant::Utf8(String::from_utf8_lossy(bytes).into_owned())
+            }
+            7 => Constant::Class(reader.u16()?),
+            8 | 16 | 19 | 20 => {
+                reader.u16()?;
+                Constant::Other
+            }
+            15 => {
+                reader.bytes(3)?;
+                Constant::Other
+            }
+            3 | 4 | 9 | 10 | 11 | 12 | 17 | 18 => {
+                reader.u32()?;
+                Constant::Other
+            }
+            // Longs and doubles take two entries
+            5 | 6 => {
+                reader.bytes(8)?;
+                constants.push(Constant::Other);
+                Constant::Other
+            }
+            tag => {
+                return Err(Error::Malformed(format!(
+                    "The constant pool entry {} has an unknown tag ({})",
+                    constants.len(),
+                    tag
+                )))
+            }
+        };
+        constants.push(constant);
+    }
+
+    Ok(constants)
+}
+
+fn utf8(constants: &[Constant], index: u16) -> Option<&str> {
+    match constants.get(index as usize) {
+        Some(Constant::Utf8(string)) => Some(string),
+        _ => None,
+    }
+}
+
+// The names of the classes are written with slashes (Like: java/lang/Object)
+fn class_name(constants: &[Constant], index: u16) -> Option<String> {
+    match constants.get(index as usize) {
+        Some(Constant::Class(name)) => utf8(constants, *name).map(|name| name.replace('/', ".")),
+        _ => None,
+    }
+}
+
+// Returns the names and the bytes of the attributes
+fn read_attributes<'a>(
+    reader: &mut Reader<'a>,
+    constants: &'a [Constant],
+) -> Result<Vec<(&'a str, &'a [u8])>, Error> {
+    let count = reader.u16()?;
+    let mut attributes = vec![];
+    for _ in 0..count {
+        let name = utf8(constants, reader.u16()?).unwrap_or_default();
+        let length = reader.u32()? as usize;
+        attributes.push((name, reader.bytes(length)?));
+    }
+
+    Ok(attributes)
+}
+
+// The line numbers and the local variables are in the attributes of the code of the methods
+fn has_debug_attributes(code: &[u8], constants: &[Constant]) -> Result<bool, Error> {
+    let mut reader = Reader {
+        buffer: code,
+        offset: 4,
+    };
+    let code_length = reader.u32()? as usize;
+    reader.bytes(code_length)?;
+    let exception_table_length = reader.u16()? as usize;
+    reader.bytes(exception_table_length * 8)?;
+
+    Ok(read_attributes(&mut reader, constants)?
+        .iter()
+        .any(|(name, _)| {
+            [
+                "LineNumberTable",
+                "LocalVariableTable",
+                "LocalVariableTypeTable",
+            ]
+            .contains(name)
+        }))
+}
+
+fn read_class(buffer: &[u8]) -> Result<JavaClass, Error> {
+    let mut reader = Reader { buffer, offset: 4 };
+    let minor_version = reader.u16()?;
+    let major_version = reader.u16()?;
+    let constants = read_constant_pool(&mut reader)?;
+    let access_flags = reader.u16()?;
+    let this_class = class_name(&constants, reader.u16()?);
+    // Only java.lang.Object and modules have no super class
+    let super_class = class_name(&constants, reader.u16()?);
+    let interface_count = reader.u16()?;
+    let interfaces = (0..interface_count)
+        .map(|_| Ok(class_name(&constants, reader.u16()?).unwrap_or_default()))
+        .collect::<Result<Vec<String>, Error>>()?;
+
+    let mut has_debug = false;
+    // The fields and the methods have the same layout
+    for is_method in [false, true] {
+        let count = reader.u16()?;
+        for _ in 0..count {
+            reader.bytes(6)?;
+            for (name, bytes) in read_attributes(&mut reader, &constants)? {
+                if is_method && name == "Code" && !has_debug {
+                    has_debug = has_debug_attributes(bytes, &constants)?;
+                }
+            }
+        }
+    }
+    let source_file = read_attributes(&mut reader, &constants)?
+        .iter()
+        .find(|(name, _)| *name == "SourceFile")
+        .and_then(|(_, bytes)| bytes.pread_with::<u16>(0, BE).ok())
+        .and_then(|index| utf8(&constants, index).map(str::to_owned));
+
+    Ok(JavaClass {
+        major_version,
+        minor_version,
+        access_flags,
+        class_name: this_class,
+        super_class,
+        interfaces,
+        source_file,
+        has_debug_attributes: has_debug,
+    })
+}
+
+pub(crate) fn parse_java_class(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+) -> ULDDObjResult {
+    let class = match read_class(buffer) {
+        Ok(class) => class,
+        Err(error) => {
+            Debugging::Error(format!(
+                "Error while parsing the Java class file named '{}'{}\nDetails:\n{}",
+                file_name,
+                merge_members(member_names),
+                error
+            ))
+            .print(options.debugging);
+
+            return ULDDObjResult {
+                error: ParsingError {
+                    code: error.to_int(),
+                    explanation: error.to_c_string(),
+                },
+                obj: ULDDObj {
+                    file_name: file_name.to_c_string(),
+                    member_name: CharVec::from(member_names),
+                    executable_format: "Java class".to_c_string(),
+                    ..Default::default()
+                },
+            };
+        }
+    };
+    debug_objects(
+        file_name,
+        member_names,
+        "a Java class file",
+        options.debugging,
+    );
+
+    let file_type = match class.access_flags {
+        flags if flags & ACC_MODULE != 0 => JavaFileType::Module,
+        flags if flags & ACC_ANNOTATION != 0 => JavaFileType::Annotation,
+        flags if flags & ACC_INTERFACE != 0 => JavaFileType::Interface,
+        flags if flags & ACC_ENUM != 0 => JavaFileType::Enum,
+        _ => JavaFileType::Class,
+    };
+    let debug_info = if class.has_debug_attributes {
+        vec![(DebugInfoFormat::JavaAttributes, DebugInfoLocation::Embedded)]
+    } else {
+        vec![]
+    };
+    // The names of the fields and the methods can't be stripped, but obfuscators (Like: ProGuard) remove SourceFile
+    let (is_stripped, stripping) = classify_stripping(class.source_file.is_some(), &debug_info);
+
+    ULDDObjResult {
+        error: ParsingError::default(),
+        obj: ULDDObj {
+            file_name: file_name.to_c_string(),
+            member_name: CharVec::from(member_names),
+            executable_format: "Java class".to_c_string(),
+            is_64: false,
+            os_type: null_mut(),
+            file_type: file_type.to_c_string(),
+            is_stripped,
+            cpu_type: "JVM".to_c_string(),
+            cpu_subtype: null_mut(),
+            interpreter: null_mut(),
+            stripping,
+            java: JavaInfo {
+                major_version: class.major_version,
+                minor_version: class.minor_version,
+                java_release: option_to_c_string(java_release(class.major_version)),
+                uses_preview_features: class.minor_version == PREVIEW_MINOR_VERSION,
+                class_name: option_to_c_string(class.class_name),
+                super_class: option_to_c_string(class.super_class),
+                interfaces: CharVec::from(class.interfaces),
+                access_flags: flag_names(class.access_flags as u64, &JAVA_ACCESS_FLAGS),
+                source_file: option_to_c_string(class.source_file),
+            },
+            ..Default::default()
+        },
+    }
+}
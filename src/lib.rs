@@ -29,68 +29,222 @@
 //! [^3]: That doesn't mean I am liable for any damages done by this project and files you parsed. Take your own risk!
 //!
 use crate::impls::{DropCString, ErrorToInt, StringToCString};
-use archive::parse_archive;
+use aout::parse_aout;
+use archive::{parse_archive, parse_big_archive, BIG_ARCHIVE_MAGIC};
 use coff::parse_coff;
+use compression::{is_compressed, parse_compressed};
 use debug::merge_members;
+use dex::{is_dex, parse_dex};
 use elf::parse_elf;
-use goblin::Object;
+use goblin::{
+    archive::MAGIC as ARCHIVE_MAGIC,
+    elf::header::ELFMAG,
+    mach::{
+        fat::FAT_MAGIC,
+        header::{MH_CIGAM, MH_CIGAM_64, MH_MAGIC, MH_MAGIC_64},
+    },
+    Object,
+};
+use java::{is_java_class, parse_java_class};
 use mach::parse_mach;
+use mz::{parse_mz, MZ_MAGICS};
 use owo_colors::OwoColorize;
+use package::{is_deb, parse_deb, parse_rpm, RPM_MAGIC};
 use pe::parse_pe;
+use scroll::{Pread, BE};
 use std::ffi::{c_char, CStr};
-use structs::{CharVec, Debugging, ParsingError, ULDDObj, ULDDObjResult, ULDDObjResultVec};
+use structs::{
+    CharVec, Debugging, ParsingError, ULDDObj, ULDDObjResult, ULDDObjResultVec, ULDDOptions,
+};
+use tar::{is_tar, parse_tar};
+use wasm::{parse_wasm, WASM_MAGIC};
+use xcoff::{parse_xcoff, XCOFF32_MAGIC, XCOFF64_MAGIC, XCOFF64_OLD_MAGIC};
+use zip::{parse_zip, Budget, EMPTY_ZIP_MAGIC, ZIP_MAGIC};
 
+#[doc(hidden)]
+pub mod aout;
 #[doc(hidden)]
 pub mod archive;
 #[doc(hidden)]
 pub mod coff;
 #[doc(hidden)]
+pub mod compression;
+#[doc(hidden)]
+pub mod cpio;
+#[doc(hidden)]
 pub mod debug;
 #[doc(hidden)]
+pub mod dex;
+#[doc(hidden)]
 pub mod elf;
 #[doc(hidden)]
+pub mod go;
+#[doc(hidden)]
 pub mod impls;
 #[doc(hidden)]
+pub mod java;
+#[doc(hidden)]
 pub mod mach;
 #[doc(hidden)]
+pub mod mz;
+#[doc(hidden)]
+pub mod package;
+#[doc(hidden)]
 pub mod pe;
+#[doc(hidden)]
+pub mod rust;
+#[doc(hidden)]
+pub mod tar;
+#[cfg(test)]
+mod test_utils;
+#[doc(hidden)]
+pub mod wasm;
+#[doc(hidden)]
+pub mod xcoff;
+#[doc(hidden)]
+pub mod zip;
 
 pub mod structs;
 pub mod types;
 
+// Enough to tell multi architecture Mach-O files from class files and to find the magic number of tar files
+const PEEK_SIZE: usize = 512;
+
+// Only the objects and the containers which may have objects in them are extracted from the containers
+fn is_object(head: &[u8]) -> bool {
+    let magic = head.pread_with::<u32>(0, BE).unwrap_or_default();
+    let is_mach = [MH_MAGIC, MH_CIGAM, MH_MAGIC_64, MH_CIGAM_64].contains(&magic)
+        || (magic == FAT_MAGIC && !is_java_class(head));
+    let is_xcoff =
+        [XCOFF32_MAGIC, XCOFF64_MAGIC, XCOFF64_OLD_MAGIC].contains(&((magic >> 16) as u16));
+
+    is_mach
+        || is_xcoff
+        || is_dex(head)
+        || is_tar(head)
+        || is_compressed(head)
+        || [
+            ELFMAG.as_slice(),
+            ARCHIVE_MAGIC,
+            BIG_ARCHIVE_MAGIC,
+            RPM_MAGIC,
+            WASM_MAGIC,
+            ZIP_MAGIC,
+            EMPTY_ZIP_MAGIC,
+        ]
+        .iter()
+        .chain(MZ_MAGICS.iter())
+        .any(|magic| head.starts_with(magic))
+}
+
 fn parse_objects<'a>(
     file_name: &'a str,
     buffer: &'a [u8],
     member_names: &mut Vec<&'a str>,
     objects: &mut Vec<ULDDObjResult>,
-    debugging: bool,
+    budget: &mut Budget,
+    options: &ULDDOptions,
 ) {
+    let debugging = options.debugging;
+    // The error is reported once and the rest of the objects are skipped
+    match budget.spend_object() {
+        Some(Ok(())) => (),
+        Some(Err(error)) => {
+            Debugging::Error(format!(
+                "Error while parsing the binary named '{}'{}\nDetails:\n{}",
+                file_name,
+                merge_members(member_names),
+                error
+            ))
+            .print(debugging);
+            return objects.push(ULDDObjResult {
+                error: ParsingError {
+                    code: error.to_int(),
+                    explanation: error.to_c_string(),
+                },
+                obj: ULDDObj {
+                    file_name: file_name.to_c_string(),
+                    member_name: CharVec::from(member_names),
+                    ..Default::default()
+                },
+            });
+        }
+        None => return,
+    }
+    // goblin doesn't know the formats below, so they are detected by their magic numbers first
+    if buffer.starts_with(WASM_MAGIC) {
+        return objects.push(parse_wasm(file_name, buffer, member_names, options));
+    }
+    if buffer.starts_with(BIG_ARCHIVE_MAGIC) {
+        return parse_big_archive(file_name, buffer, member_names, objects, budget, options);
+    }
+    if let Some(&[first, second]) = buffer.get(0..2) {
+        let magic = u16::from_be_bytes([first, second]);
+        if [XCOFF32_MAGIC, XCOFF64_MAGIC, XCOFF64_OLD_MAGIC].contains(&magic) {
+            return objects.push(parse_xcoff(file_name, buffer, member_names, options));
+        }
+    }
+    // Multi architecture Mach-O files have the same magic number, so the rest is left to goblin
+    if is_java_class(buffer) {
+        return objects.push(parse_java_class(file_name, buffer, member_names, options));
+    }
+    if is_dex(buffer) {
+        return objects.push(parse_dex(file_name, buffer, member_names, options));
+    }
+    if is_tar(buffer) {
+        return parse_tar(file_name, buffer, member_names, objects, budget, options);
+    }
+    if is_compressed(buffer) {
+        return parse_compressed(file_name, buffer, member_names, objects, budget, options);
+    }
+    if buffer.starts_with(RPM_MAGIC) {
+        return parse_rpm(file_name, buffer, member_names, objects, budget, options);
+    }
+    if buffer.starts_with(ZIP_MAGIC) || buffer.starts_with(EMPTY_ZIP_MAGIC) {
+        return parse_zip(file_name, buffer, member_names, objects, budget, options);
+    }
+    if MZ_MAGICS.iter().any(|magic| buffer.starts_with(magic)) {
+        if let Some(object) = parse_mz(file_name, buffer, member_names, options) {
+            return objects.push(object);
+        }
+    }
+
     match Object::parse(buffer) {
+        Ok(Object::Archive(archive)) if is_deb(&archive) => {
+            parse_deb(file_name, buffer, archive, member_names, objects, budget, options)
+        }
         Ok(Object::Archive(archive)) => {
-            parse_archive(file_name, buffer, archive, member_names, objects, debugging)
+            parse_archive(file_name, buffer, archive, member_names, objects, budget, options)
         }
 
         Ok(Object::Mach(mach)) => {
-            parse_mach(file_name, buffer, mach, member_names, objects, debugging)
+            parse_mach(file_name, buffer, mach, member_names, objects, options)
         }
 
         Ok(Object::Elf(elf)) => {
             objects.push(parse_elf(
                 file_name,
+                buffer,
                 elf,
                 buffer[0x7],
                 member_names,
-                debugging,
+                options,
             ));
         }
 
-        Ok(Object::PE(pe)) => objects.push(parse_pe(file_name, pe, member_names, debugging)),
+        Ok(Object::PE(pe)) => {
+            objects.push(parse_pe(file_name, buffer, pe, member_names, options))
+        }
 
         Ok(Object::COFF(coff)) => {
-            objects.push(parse_coff(file_name, coff, member_names, debugging));
+            objects.push(parse_coff(file_name, coff, member_names, options));
         }
 
         Ok(Object::Unknown(magic_number)) => {
+            // a.out has no magic number of its own, so it is the last resort for unknown binaries
+            if let Some(object) = parse_aout(file_name, buffer, member_names, options) {
+                return objects.push(object);
+            }
             let msg = format!(
                 "The binary named '{}'{} has a unknown magic number (in big-endian): {}",
                 file_name,
@@ -178,6 +332,29 @@ pub unsafe extern "C" fn read_obj(
     buffer_size: usize,
     debugging: bool,
 ) -> ULDDObjResultVec {
+    let options = ULDDOptions {
+        debugging,
+        ..Default::default()
+    };
+
+    read_obj_with_options(file_name, buffer, buffer_size, options)
+}
+
+///
+/// Parses the given buffer with the given [`ULDDOptions`] and returns a vector of parsed binaries.
+///
+/// # Safety
+///
+/// It has the same requirements as [`read_obj`].
+///
+#[no_mangle]
+pub unsafe extern "C" fn read_obj_with_options(
+    file_name: *const c_char,
+    buffer: *const u8,
+    buffer_size: usize,
+    options: ULDDOptions,
+) -> ULDDObjResultVec {
+    let debugging = options.debugging;
     let (buf, f_name) = unsafe {
         let s = match CStr::from_ptr(file_name).to_str() {
             Ok(string_slice) => string_slice,
@@ -191,7 +368,14 @@ pub unsafe extern "C" fn read_obj(
     };
 
     let mut objects = vec![];
-    parse_objects(f_name, buf, &mut vec![], &mut objects, debugging);
+    parse_objects(
+        f_name,
+        buf,
+        &mut vec![],
+        &mut objects,
+        &mut Budget::new(),
+        &options,
+    );
     let (total, success, failed): (usize, usize, usize) = {
         let t = objects.len();
         let (mut s, mut f) = (0, 0);
@@ -253,6 +437,29 @@ pub unsafe extern "C" fn free_obj(obj: ULDDObjResultVec, debugging: bool) -> u8
         o.interpreter.drop_c_string();
         o.member_name.drop_c_string();
         o.libraries.drop_c_string();
+        o.pe_debug.drop_c_string();
+        o.dotnet.drop_c_string();
+        o.pe_load.drop_c_string();
+        o.pe_anomalies.drop_c_string();
+        o.import_hash.drop_c_string();
+        o.os_version.drop_c_string();
+        o.elf_debug.drop_c_string();
+        o.stripping.drop_c_string();
+        o.core.drop_c_string();
+        o.kernel_module.drop_c_string();
+        o.toolchain.drop_c_string();
+        o.go.drop_c_string();
+        o.rust.drop_c_string();
+        o.elf_dynamic.drop_c_string();
+        o.sections.drop_c_string();
+        o.segments.drop_c_string();
+        o.wasm.drop_c_string();
+        o.xcoff.drop_c_string();
+        o.mz.drop_c_string();
+        o.aout.drop_c_string();
+        o.java.drop_c_string();
+        o.dex.drop_c_string();
+        o.package.drop_c_string();
 
         Debugging::Affirmative(format!("{}. object is deallocated", index + 1)).print(debugging);
     }
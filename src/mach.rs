@@ -1,15 +1,28 @@
 use std::ffi::c_char;
 use crate::{
-    debug::{debug_objects, merge_members},
-    structs::{CharVec, Debugging, ParsingError, ULDDObj, ULDDObjResult},
+    debug::{classify_stripping, debug_objects, merge_members, permissions_to_c_string},
+    go::{parse_go_build_info, GoMemory},
+    rust::{parse_rust, AUDITABLE_SECTION},
+    structs::{
+        CharVec, Debugging, GoBuildInfo, ParsingError, RustInfo, SectionInfo, SectionInfoVec,
+        ULDDObj, ULDDObjResult, ULDDOptions,
+    },
     types::{
-        MachOCpuType, MachOOs, MACH_O_ARM_CPU_SUBTYPE, MACH_O_CPUTYPE, MACH_O_FILE_TYPE,
+        DebugInfoFormat, DebugInfoLocation, MachOCpuType, MachOOs, MACH_O_ARM_CPU_SUBTYPE, MACH_O_CPUTYPE, MACH_O_FILE_TYPE,
         MACH_O_X86_CPU_SUBTYPE,
     },
 };
-use goblin::mach::{load_command::CommandVariant::BuildVersion, Mach, MachO};
+use goblin::mach::{
+    constants::{
+        SECTION_TYPE, S_ATTR_PURE_INSTRUCTIONS, S_ATTR_SOME_INSTRUCTIONS, S_GB_ZEROFILL,
+        S_THREAD_LOCAL_ZEROFILL, S_ZEROFILL, VM_PROT_EXECUTE, VM_PROT_READ, VM_PROT_WRITE,
+    },
+    load_command::CommandVariant::BuildVersion,
+    symbols::{N_EXT, N_LSYM, N_OSO, N_PSYM, N_RSYM, N_SECT, N_SLINE, N_TYPE},
+    Mach, MachO,
+};
 use std::ptr::null_mut;
-use crate::debug::option_to_c_string;
+use crate::debug::{hash_names, option_to_c_string};
 use crate::impls::{ErrorToInt, StringToCString};
 
 fn find_os_mach(mach: &MachO<'_>) -> *mut c_char {
@@ -77,14 +90,73 @@ fn decode_further(mach: &MachO<'_>) {
 }
 */
 
+/*
+Sources:
+    https://github.com/apple-oss-distributions/cctools/blob/main/include/mach-o/stab.h
+    https://wiki.dwarfstd.org/Apple's_%22Lazy%22_DWARF_Scheme.md
+*/
+fn find_debug_info_mach(mach_o: &MachO<'_>) -> (bool, Vec<(DebugInfoFormat, DebugInfoLocation)>) {
+    let mut has_symbols = false;
+    let mut debug_info = vec![];
+    for (_, nlist) in mach_o.symbols().filter_map(|symbol| symbol.ok()) {
+        if nlist.is_stab() {
+            match nlist.n_type {
+                // The debug map of the linker. DWARF stays in the object files named by N_OSO entries.
+                N_OSO => debug_info.push((DebugInfoFormat::DWARF, DebugInfoLocation::Referenced)),
+                N_SLINE | N_LSYM | N_PSYM | N_RSYM => {
+                    debug_info.push((DebugInfoFormat::STABS, DebugInfoLocation::Embedded))
+                }
+                _ => {}
+            }
+        } else if nlist.n_type & N_TYPE == N_SECT && nlist.n_type & N_EXT == 0 {
+            // strip removes local symbols, the external ones are needed by the dynamic linker
+            has_symbols = true;
+        }
+    }
+
+    // Object files and dSYM bundles keep DWARF in the sections of the __DWARF segment
+    let has_dwarf = mach_o.segments.iter().any(|segment| {
+        segment.sections().is_ok_and(|sections| {
+            sections
+                .iter()
+                .any(|(section, _)| section.segname().is_ok_and(|name| name == "__DWARF"))
+        })
+    });
+    if has_dwarf {
+        debug_info.push((DebugInfoFormat::DWARF, DebugInfoLocation::Embedded));
+    }
+
+    (has_symbols, debug_info)
+}
+
+// Source: https://github.com/threatstream/symhash
+fn symhash(mach_o: &MachO<'_>) -> Option<String> {
+    let mut imports: Vec<&str> = mach_o
+        .symbols
+        .as_ref()?
+        .iter()
+        .filter_map(|symbol| symbol.ok())
+        .filter(|(_, nlist)| !nlist.is_stab() && nlist.is_undefined() && nlist.is_global())
+        .map(|(name, _)| name)
+        .collect();
+    imports.sort_unstable();
+
+    if imports.is_empty() {
+        None
+    } else {
+        Some(hash_names(&imports))
+    }
+}
+
 pub(crate) fn parse_mach<'a>(
     file_name: &'a str,
     buffer: &[u8],
     mach: Mach,
     member_names: &mut Vec<&'a str>,
     objects: &mut Vec<ULDDObjResult>,
-    debugging: bool,
+    options: &ULDDOptions,
 ) {
+    let debugging = options.debugging;
     match mach {
         Mach::Fat(fat) => {
             debug_objects(
@@ -124,7 +196,7 @@ pub(crate) fn parse_mach<'a>(
                             &format!("{}. file", index + 1),
                             member_names,
                             mach_o,
-                            debugging,
+                            options,
                         ))
                     }
                     Err(error) => {
@@ -147,16 +219,136 @@ pub(crate) fn parse_mach<'a>(
             }
         }
         Mach::Binary(binary) => {
-            objects.push(parse_mach_o(file_name, member_names, binary, debugging))
+            objects.push(parse_mach_o(file_name, member_names, binary, options))
+        }
+    }
+}
+
+fn find_go_build_info_mach(mach_o: &MachO<'_>) -> Option<GoBuildInfo> {
+    let section = mach_o.segments.iter().find_map(|segment| {
+        let sections = segment.sections().ok()?;
+        sections
+            .into_iter()
+            .find(|(section, _)| section.name().is_ok_and(|name| name == "__go_buildinfo"))
+            .map(|(_, data)| data)
+    });
+    // Old Go versions don't have a separate section, so the whole __DATA segment is searched
+    let candidates: Vec<&[u8]> = match section {
+        Some(data) => vec![data],
+        None => mach_o
+            .segments
+            .iter()
+            .filter(|segment| segment.name().is_ok_and(|name| name == "__DATA"))
+            .map(|segment| segment.data)
+            .collect(),
+    };
+    let memory = GoMemory {
+        regions: mach_o
+            .segments
+            .iter()
+            .map(|segment| (segment.vmaddr, segment.data))
+            .collect(),
+    };
+
+    parse_go_build_info(&candidates, &memory).map(|(_, go)| go)
+}
+
+fn find_rust_mach(mach_o: &MachO<'_>) -> RustInfo {
+    let symbols: Vec<&str> = mach_o
+        .symbols()
+        .filter_map(|symbol| symbol.ok())
+        .map(|(name, _)| name)
+        .collect();
+    let sections: Vec<_> = mach_o
+        .segments
+        .iter()
+        .filter_map(|segment| Some((segment.initprot, segment.sections().ok()?)))
+        .flat_map(|(protection, sections)| {
+            sections
+                .into_iter()
+                .map(move |(section, data)| (protection, section, data))
+        })
+        .collect();
+    let find_section = |name: &str| {
+        sections
+            .iter()
+            .find(|(_, section, _)| section.name().is_ok_and(|n| n == name))
+    };
+    // cargo-auditable places the section into the __DATA segment
+    let auditable = find_section(AUDITABLE_SECTION).map(|(_, _, data)| *data);
+    // The strings are in the sections of read-only segments which don't have instructions (Like: `__TEXT,__const`)
+    let data: Vec<&[u8]> = sections
+        .iter()
+        .filter(|(protection, section, _)| {
+            protection & VM_PROT_WRITE == 0
+                && section.flags & (S_ATTR_PURE_INSTRUCTIONS | S_ATTR_SOME_INSTRUCTIONS) == 0
+                && !ZEROFILL_SECTION_TYPES.contains(&(section.flags & SECTION_TYPE))
+        })
+        .map(|(_, _, data)| *data)
+        .collect();
+
+    parse_rust(
+        None,
+        find_section(".rustc").is_some(),
+        symbols.into_iter(),
+        auditable,
+        &data,
+    )
+}
+
+// The sections which don't take space in the file
+const ZEROFILL_SECTION_TYPES: [u32; 3] = [S_ZEROFILL, S_GB_ZEROFILL, S_THREAD_LOCAL_ZEROFILL];
+
+fn list_sections_mach(mach_o: &MachO<'_>) -> (SectionInfoVec, SectionInfoVec) {
+    let mut sections: Vec<SectionInfo> = vec![];
+    let mut segments: Vec<SectionInfo> = vec![];
+    for segment in mach_o.segments.iter() {
+        let protection = |prot: u32| {
+            permissions_to_c_string(
+                prot & VM_PROT_READ != 0,
+                prot & VM_PROT_WRITE != 0,
+                prot & VM_PROT_EXECUTE != 0,
+            )
+        };
+        let segment_name = segment.name().ok();
+        segments.push(SectionInfo {
+            name: option_to_c_string(segment_name),
+            segment_name: null_mut(),
+            section_type: null_mut(),
+            virtual_address: segment.vmaddr,
+            virtual_size: segment.vmsize,
+            file_offset: segment.fileoff,
+            file_size: segment.filesize,
+            permissions: protection(segment.initprot),
+            flags: segment.flags as u64,
+            alignment: 0,
+        });
+
+        for (section, _) in segment.sections().unwrap_or_default() {
+            let is_zerofill = ZEROFILL_SECTION_TYPES.contains(&(section.flags & SECTION_TYPE));
+            sections.push(SectionInfo {
+                name: option_to_c_string(section.name().ok()),
+                segment_name: option_to_c_string(section.segname().ok()),
+                section_type: null_mut(),
+                virtual_address: section.addr,
+                virtual_size: section.size,
+                file_offset: section.offset as u64,
+                file_size: if is_zerofill { 0 } else { section.size },
+                permissions: protection(segment.initprot),
+                flags: section.flags as u64,
+                alignment: 1u64.checked_shl(section.align).unwrap_or(0),
+            });
         }
     }
+
+    (SectionInfoVec::from(sections), SectionInfoVec::from(segments))
 }
 
 fn parse_mach_o(
     file_name: &str,
     member_names: &mut Vec<&str>,
     mach_o: MachO,
-    debugging: bool,
+    options: &ULDDOptions,
 ) -> ULDDObjResult {
     let mut mach_o = mach_o;
     let file_type = option_to_c_string(MACH_O_FILE_TYPE.get(&mach_o.header.filetype));
@@ -182,13 +374,20 @@ fn parse_mach_o(
         }
     };
 
-    let is_stripped = !mach_o
-        .symbols
-        .as_ref()
-        .is_some_and(|v| v.iter().any(|s| s.is_ok_and(|(x, _)| x.contains("debug"))));
+    let (has_symbols, debug_info) = find_debug_info_mach(&mach_o);
+    let (is_stripped, stripping) = classify_stripping(has_symbols, &debug_info);
+
+    let import_hash = option_to_c_string(symhash(&mach_o));
+    let go = find_go_build_info_mach(&mach_o).unwrap_or_default();
+    let rust = find_rust_mach(&mach_o);
 
     mach_o.libs.retain(|lib| lib != &"self");
-    debug_objects(file_name, member_names, "a Mach-O binary", debugging);
+    let (sections, segments) = if options.list_sections {
+        list_sections_mach(&mach_o)
+    } else {
+        Default::default()
+    };
+    debug_objects(file_name, member_names, "a Mach-O binary", options.debugging);
 
     ULDDObjResult {
         error: ParsingError::default(),
@@ -204,6 +403,13 @@ fn parse_mach_o(
             cpu_subtype,
             interpreter: null_mut(),
             libraries: CharVec::from(mach_o.libs),
+            import_hash,
+            stripping,
+            go,
+            rust,
+            sections,
+            segments,
+            ..Default::default()
         },
     }
 }
@@ -0,0 +1,227 @@
+use crate::{
+    debug::{debug_objects, merge_members, read_c_str},
+    impls::{ErrorToInt, StringToCString},
+    structs::{CharVec, Debugging, MzInfo, ParsingError, ULDDObj, ULDDObjResult, ULDDOptions},
+    types::{DebugInfoFormat, DebugInfoLocation},
+};
+use dos::parse_dos;
+use goblin::error::Error;
+use le::parse_le;
+use ne::parse_ne;
+use scroll::{Pread, LE};
+
+mod dos;
+mod le;
+mod ne;
+
+/*
+Sources:
+    https://wiki.osdev.org/MZ
+    http://www.techhelpmanual.com/354-exe_file_header_layout.html
+*/
+
+// Some linkers of MS-DOS wrote the magic number in big-endian
+pub(crate) const MZ_MAGICS: [&[u8]; 2] = [b"MZ", b"ZM"];
+// The offset of `e_lfanew` which points to the extended header
+const EXTENDED_HEADER_POINTER: usize = 0x3c;
+// Plain MS-DOS executables have no stub, so the signatures are searched in the beginning of the file
+const STUB_SEARCH_LIMIT: usize = 256 * 1024;
+
+const EXTENDER_SIGNATURES: [(&[u8], &str); 8] = [
+    (b"DOS/4GW", "DOS/4GW"),
+    (b"DOS/4G ", "DOS/4G"),
+    (b"PMODE/W", "PMODE/W"),
+    (b"DOS/32A", "DOS/32A"),
+    (b"CWSDPMI", "CWSDPMI"),
+    (b"go32stub", "DJGPP go32"),
+    (b"CauseWay", "CauseWay"),
+    (b"Phar Lap", "Phar Lap"),
+];
+
+const PACKER_SIGNATURES: [(&[u8], &str); 6] = [
+    (b"LZ91", "LZEXE"),
+    (b"LZ09", "LZEXE"),
+    (b"PKLITE", "PKLITE"),
+    (b"UPX!", "UPX"),
+    (b"Packed file is corrupt", "EXEPACK"),
+    (b"WWPACK", "WWPACK"),
+];
+
+///
+/// Reads a length-prefixed string (Like: the names in the tables of NE and LE/LX).
+///
+/// Returns the string (cut at the first NUL byte) with the offset after it.
+///
+pub(crate) fn read_pascal_string(buffer: &[u8], offset: usize) -> Option<(String, usize)> {
+    let length = *buffer.get(offset)? as usize;
+    let string = buffer.get(offset + 1..offset + 1 + length)?;
+
+    Some((read_c_str(string), offset + 1 + length))
+}
+
+///
+/// Reads the first entry of a resident or non-resident names table.
+///
+/// The first entry is the module name in the resident names table and the description in the non-resident names table.
+///
+pub(crate) fn read_first_name(buffer: &[u8], offset: usize) -> Option<String> {
+    // The offset of the non-resident names table is zero if the module doesn't have it
+    if offset == 0 {
+        return None;
+    }
+    read_pascal_string(buffer, offset)
+        .map(|(name, _)| name)
+        .filter(|name| !name.is_empty())
+}
+
+///
+/// Looks for the CodeView information which linkers of MS-DOS, Windows 3.x and OS/2 append to the executables.
+///
+/// The information starts and ends with an "NBxx" signature. The ending signature is followed by the distance to the start from the end of the file.
+/// LE/LX executables also record the offset of the information in their headers (`debug_info`).
+///
+pub(crate) fn find_codeview(
+    buffer: &[u8],
+    debug_info: Option<usize>,
+) -> Vec<(DebugInfoFormat, DebugInfoLocation)> {
+    let is_signature = |offset: usize| {
+        buffer.get(offset..offset + 4).is_some_and(|signature| {
+            signature.starts_with(b"NB") && signature[2..].iter().all(u8::is_ascii_digit)
+        })
+    };
+    let from_header = debug_info.is_some_and(is_signature);
+    let from_end = buffer.len() >= 8 && is_signature(buffer.len() - 8) && {
+        let distance = buffer
+            .pread_with::<u32>(buffer.len() - 4, LE)
+            .unwrap_or_default() as usize;
+        buffer.len().checked_sub(distance).is_some_and(is_signature)
+    };
+
+    if from_header || from_end {
+        vec![(DebugInfoFormat::CodeView, DebugInfoLocation::Embedded)]
+    } else {
+        vec![]
+    }
+}
+
+fn to_result(
+    file_name: &str,
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+    executable_format: &str,
+    result: Result<ULDDObj, Error>,
+) -> ULDDObjResult {
+    match result {
+        Ok(obj) => {
+            debug_objects(
+                file_name,
+                member_names,
+                &format!("an {} binary", executable_format),
+                options.debugging,
+            );
+            ULDDObjResult {
+                error: ParsingError::default(),
+                obj: ULDDObj {
+                    file_name: file_name.to_c_string(),
+                    member_name: CharVec::from(member_names),
+                    ..obj
+                },
+            }
+        }
+        Err(error) => {
+            Debugging::Error(format!(
+                "Error while parsing the {} binary named '{}'{}\nDetails:\n{}",
+                executable_format,
+                file_name,
+                merge_members(member_names),
+                error
+            ))
+            .print(options.debugging);
+
+            ULDDObjResult {
+                error: ParsingError {
+                    code: error.to_int(),
+                    explanation: error.to_c_string(),
+                },
+                obj: ULDDObj {
+                    file_name: file_name.to_c_string(),
+                    member_name: CharVec::from(member_names),
+                    executable_format: executable_format.to_c_string(),
+                    ..Default::default()
+                },
+            }
+        }
+    }
+}
+
+// Returns the names of the signatures found in the given bytes without duplicates
+fn find_signatures(stub: &[u8], signatures: &[(&[u8], &str)]) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    for (signature, name) in signatures {
+        let is_found = stub
+            .windows(signature.len())
+            .any(|window| window == *signature);
+        if is_found && !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+    }
+
+    names
+}
+
+///
+/// Parses the executables which start with the MZ stub of MS-DOS but are not PE files.
+///
+/// The executable is a plain MS-DOS executable if `e_lfanew` doesn't point to an NE, LE, LX or PE header.
+/// It returns `None` for PE files, which are left to goblin.
+///
+pub(crate) fn parse_mz(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+) -> Option<ULDDObjResult> {
+    let header = buffer
+        .pread_with::<u32>(EXTENDED_HEADER_POINTER, LE)
+        .ok()
+        .map(|header| header as usize);
+    let signature = header.and_then(|header| buffer.get(header..header.checked_add(4)?));
+    let (executable_format, result, stub) = match (signature, header) {
+        (Some(b"PE\0\0"), _) => return None,
+        (Some([b'N', b'E', ..]), Some(header)) => {
+            ("NE", parse_ne(buffer, header, options), &buffer[..header])
+        }
+        (Some([b'L', b'E', ..]), Some(header)) => (
+            "LE",
+            parse_le(buffer, header, false, options),
+            &buffer[..header],
+        ),
+        (Some([b'L', b'X', ..]), Some(header)) => (
+            "LX",
+            parse_le(buffer, header, true, options),
+            &buffer[..header],
+        ),
+        _ => (
+            "MZ",
+            parse_dos(buffer),
+            &buffer[..buffer.len().min(STUB_SEARCH_LIMIT)],
+        ),
+    };
+    // The stubs of bound executables are the extenders themselves (Like: DOS/4GW in front of LE)
+    let result = result.map(|obj| ULDDObj {
+        mz: MzInfo {
+            extenders: CharVec::from(find_signatures(stub, &EXTENDER_SIGNATURES)),
+            packers: CharVec::from(find_signatures(stub, &PACKER_SIGNATURES)),
+            ..obj.mz
+        },
+        ..obj
+    });
+
+    Some(to_result(
+        file_name,
+        member_names,
+        options,
+        executable_format,
+        result,
+    ))
+}
@@ -0,0 +1,67 @@
+use crate::{
+    debug::classify_stripping,
+    impls::StringToCString,
+    mz::find_codeview,
+    structs::{MzInfo, ULDDObj},
+    types::MzFileType,
+};
+use goblin::error::Error;
+use scroll::{Pread, LE};
+use std::ptr::null_mut;
+
+// Source: http://www.techhelpmanual.com/354-exe_file_header_layout.html
+
+const PAGE_SIZE: usize = 512;
+const PARAGRAPH_SIZE: u32 = 16;
+// The header ends at the relocation table of the oldest executables
+const MINIMUM_HEADER_SIZE: usize = 0x1c;
+
+pub(crate) fn parse_dos(buffer: &[u8]) -> Result<ULDDObj, Error> {
+    if buffer.len() < MINIMUM_HEADER_SIZE {
+        return Err(Error::BufferTooShort(MINIMUM_HEADER_SIZE, "bytes"));
+    }
+    let read_u16 = |offset: usize| buffer.pread_with::<u16>(offset, LE);
+
+    let last_page_size = read_u16(0x02)? as usize;
+    let page_count = read_u16(0x04)? as usize;
+    let relocations = read_u16(0x06)?;
+    let header_size = read_u16(0x08)? as usize * PARAGRAPH_SIZE as usize;
+    let minimum_allocation = read_u16(0x0a)? as u32;
+    let maximum_allocation = read_u16(0x0c)? as u32;
+    let (ss, sp) = (read_u16(0x0e)?, read_u16(0x10)?);
+    let (ip, cs) = (read_u16(0x14)?, read_u16(0x16)?);
+
+    // The last page is partially used unless its size is zero
+    let image_end = match last_page_size {
+        0 => page_count * PAGE_SIZE,
+        size => page_count.saturating_sub(1) * PAGE_SIZE + size,
+    };
+    if header_size > buffer.len() || header_size > image_end {
+        return Err(Error::Malformed(format!(
+            "The header size ({:#x}) is bigger than the file or the load image",
+            header_size
+        )));
+    }
+    let (is_stripped, stripping) = classify_stripping(false, &find_codeview(buffer, None));
+
+    Ok(ULDDObj {
+        executable_format: "MZ".to_c_string(),
+        is_64: false,
+        os_type: "MS-DOS".to_c_string(),
+        file_type: MzFileType::Executable.to_c_string(),
+        is_stripped,
+        cpu_type: "Intel 8086".to_c_string(),
+        cpu_subtype: null_mut(),
+        interpreter: null_mut(),
+        stripping,
+        mz: MzInfo {
+            entry_point: format!("{:04X}:{:04X}", cs, ip).to_c_string(),
+            stack_pointer: format!("{:04X}:{:04X}", ss, sp).to_c_string(),
+            relocations,
+            minimum_memory: minimum_allocation * PARAGRAPH_SIZE,
+            maximum_memory: maximum_allocation * PARAGRAPH_SIZE,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
@@ -0,0 +1,220 @@
+use crate::{
+    debug::{classify_stripping, flag_name_list, option_to_c_string, permissions_to_c_string},
+    impls::StringToCString,
+    mz::{find_codeview, read_first_name, read_pascal_string},
+    structs::{CharVec, MzInfo, SectionInfo, SectionInfoVec, ULDDObj, ULDDOptions},
+    types::{MzFileType, LE_CPU_TYPE, LE_FLAGS, MZ_APPLICATION_TYPE, MZ_TARGET_OS},
+};
+use goblin::error::Error;
+use scroll::{Pread, LE};
+use std::ptr::null_mut;
+
+// Source: http://www.edm2.com/index.php/LX_-_Linear_eXecutable_Module_Format_Description
+
+const OBJECT_ENTRY_SIZE: usize = 24;
+const LX_PAGE_ENTRY_SIZE: usize = 8;
+const APPLICATION_TYPE_MASK: u32 = 0x0000_0300;
+const MODULE_TYPE_MASK: u32 = 0x0003_8000;
+const OBJECT_READABLE: u32 = 0x0001;
+const OBJECT_WRITABLE: u32 = 0x0002;
+const OBJECT_EXECUTABLE: u32 = 0x0004;
+// The byte and the word orders are 0 for little-endian
+const LITTLE_ENDIAN: u8 = 0;
+
+fn module_type(flags: u32) -> MzFileType {
+    match flags & MODULE_TYPE_MASK {
+        0x0000_8000 | 0x0001_8000 => MzFileType::DynamicLinkLibrary,
+        0x0002_0000 => MzFileType::PhysicalDeviceDriver,
+        0x0002_8000 => MzFileType::VirtualDeviceDriver,
+        _ => MzFileType::Executable,
+    }
+}
+
+///
+/// Lists the objects with the location of their pages in the file.
+///
+/// LE stores the pages in order with a fixed size (except the last page of the module), so only the page table of LX is read for the offset and the size of every page.
+///
+fn list_objects_le(buffer: &[u8], header: usize, is_lx: bool) -> Result<SectionInfoVec, Error> {
+    let read_u16 = |offset: usize| buffer.pread_with::<u16>(offset, LE);
+    let read_u32 = |offset: usize| buffer.pread_with::<u32>(offset, LE);
+
+    let page_count = read_u32(header + 0x14)? as u64;
+    let page_size = read_u32(header + 0x28)? as u64;
+    // It is the page offset shift in LX and the size of the last page in LE
+    let shift_or_last_page = read_u32(header + 0x2c)?;
+    let object_table = header + read_u32(header + 0x40)? as usize;
+    let object_count = read_u32(header + 0x44)? as usize;
+    let page_table = header + read_u32(header + 0x48)? as usize;
+    let data_pages = read_u32(header + 0x80)? as u64;
+    // The page table of LX is read once and cut at the end of the file, so the objects which claim many pages don't read it again
+    let lx_pages: Vec<(u64, u64)> = if is_lx {
+        (0..page_count as usize)
+            .map_while(|index| {
+                let entry = page_table.checked_add(index * LX_PAGE_ENTRY_SIZE)?;
+                Some((
+                    (read_u32(entry).ok()? as u64) << (shift_or_last_page & 0x1f),
+                    read_u16(entry + 4).ok()? as u64,
+                ))
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+    // The sizes of the pages before each page
+    let lx_sizes: Vec<u64> = std::iter::once(0)
+        .chain(lx_pages.iter().scan(0, |total, (_, size)| {
+            *total += size;
+            Some(*total)
+        }))
+        .collect();
+    let table_pages = if is_lx {
+        lx_pages.len() as u64
+    } else {
+        page_count
+    };
+
+    let objects = (0..object_count)
+        .map(|index| {
+            let entry = object_table + index * OBJECT_ENTRY_SIZE;
+            let virtual_size = read_u32(entry)? as u64;
+            let base = read_u32(entry + 4)? as u64;
+            let flags = read_u32(entry + 8)?;
+            // Page numbers start from 1
+            let first_page = (read_u32(entry + 12)? as u64).max(1);
+            let object_pages = read_u32(entry + 16)? as u64;
+
+            let last_page = first_page + object_pages.saturating_sub(1);
+            if object_pages != 0 && last_page > table_pages {
+                return Err(Error::Malformed(format!(
+                    "The pages of the object {} are past the page table ({} > {} pages)",
+                    index + 1,
+                    last_page,
+                    table_pages
+                )));
+            }
+
+            let (file_offset, file_size) = if object_pages == 0 {
+                (0, 0)
+            } else if is_lx {
+                let (offset, _) = lx_pages[first_page as usize - 1];
+                let size = lx_sizes[last_page as usize] - lx_sizes[first_page as usize - 1];
+                (data_pages + offset, size)
+            } else {
+                let mut size = object_pages * page_size;
+                if last_page == page_count {
+                    size = size - page_size + shift_or_last_page as u64;
+                }
+                (data_pages + (first_page - 1) * page_size, size)
+            };
+
+            Ok(SectionInfo {
+                name: if flags & OBJECT_EXECUTABLE != 0 {
+                    "CODE"
+                } else {
+                    "DATA"
+                }
+                .to_c_string(),
+                segment_name: null_mut(),
+                section_type: null_mut(),
+                virtual_address: base,
+                virtual_size,
+                file_offset,
+                file_size,
+                permissions: permissions_to_c_string(
+                    flags & OBJECT_READABLE != 0,
+                    flags & OBJECT_WRITABLE != 0,
+                    flags & OBJECT_EXECUTABLE != 0,
+                ),
+                flags: flags as u64,
+                alignment: page_size,
+            })
+        })
+        .collect::<Result<Vec<SectionInfo>, Error>>()?;
+
+    Ok(SectionInfoVec::from(objects))
+}
+
+pub(crate) fn parse_le(
+    buffer: &[u8],
+    header: usize,
+    is_lx: bool,
+    options: &ULDDOptions,
+) -> Result<ULDDObj, Error> {
+    let read_u8 = |offset: usize| buffer.pread::<u8>(header + offset);
+    let read_u16 = |offset: usize| buffer.pread_with::<u16>(header + offset, LE);
+    let read_u32 = |offset: usize| buffer.pread_with::<u32>(header + offset, LE);
+
+    if read_u8(0x02)? != LITTLE_ENDIAN || read_u8(0x03)? != LITTLE_ENDIAN {
+        return Err(Error::Malformed(
+            "Big-endian LE/LX executables are not supported".to_owned(),
+        ));
+    }
+    let cpu_type = read_u16(0x08)?;
+    let os_type = read_u16(0x0a)?;
+    let flags = read_u32(0x10)?;
+    let entry_object = read_u32(0x18)?;
+    let entry_point = read_u32(0x1c)?;
+    let stack_object = read_u32(0x20)?;
+    let stack_pointer = read_u32(0x24)?;
+    let resident_names = header + read_u32(0x58)? as usize;
+    let import_table = header + read_u32(0x70)? as usize;
+    let import_count = read_u32(0x74)?;
+    let non_resident_names = read_u32(0x88)? as usize;
+    let debug_info = read_u32(0x98)? as usize;
+
+    let mut libraries = vec![];
+    let mut offset = import_table;
+    for index in 0..import_count {
+        let (name, next) = read_pascal_string(buffer, offset).ok_or_else(|| {
+            Error::Malformed(format!(
+                "The name of the imported module {} is out of the file",
+                index + 1
+            ))
+        })?;
+        libraries.push(name);
+        offset = next;
+    }
+    let mut flag_list = flag_name_list(
+        (flags & !(APPLICATION_TYPE_MASK | MODULE_TYPE_MASK)) as u64,
+        &LE_FLAGS,
+    );
+    if let Some(application_type) =
+        MZ_APPLICATION_TYPE.get(&(((flags & APPLICATION_TYPE_MASK) >> 8) as u16))
+    {
+        flag_list.push(application_type.to_string());
+    }
+    // The object numbers start from 1, so 0 means that there is no entry point (Like: libraries)
+    let address =
+        |object: u32, address: u32| (object != 0).then(|| format!("{}:{:08X}", object, address));
+    let segments = if options.list_sections {
+        list_objects_le(buffer, header, is_lx)?
+    } else {
+        Default::default()
+    };
+    let debug_info = Some(debug_info).filter(|offset| *offset != 0);
+    let (is_stripped, stripping) = classify_stripping(false, &find_codeview(buffer, debug_info));
+
+    Ok(ULDDObj {
+        executable_format: if is_lx { "LX" } else { "LE" }.to_c_string(),
+        is_64: false,
+        os_type: option_to_c_string(MZ_TARGET_OS.get(&os_type)),
+        file_type: module_type(flags).to_c_string(),
+        is_stripped,
+        cpu_type: option_to_c_string(LE_CPU_TYPE.get(&cpu_type)),
+        cpu_subtype: null_mut(),
+        interpreter: null_mut(),
+        libraries: CharVec::from(libraries),
+        stripping,
+        segments,
+        mz: MzInfo {
+            module_name: option_to_c_string(read_first_name(buffer, resident_names)),
+            description: option_to_c_string(read_first_name(buffer, non_resident_names)),
+            entry_point: option_to_c_string(address(entry_object, entry_point)),
+            stack_pointer: option_to_c_string(address(stack_object, stack_pointer)),
+            flags: CharVec::from(flag_list),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
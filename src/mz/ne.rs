@@ -0,0 +1,185 @@
+use crate::{
+    debug::{classify_stripping, flag_name_list, option_to_c_string, permissions_to_c_string},
+    impls::StringToCString,
+    mz::{find_codeview, read_first_name, read_pascal_string},
+    structs::{CharVec, MzInfo, SectionInfo, SectionInfoVec, ULDDObj, ULDDOptions},
+    types::{MzFileType, MZ_APPLICATION_TYPE, MZ_TARGET_OS, NE_FLAGS},
+};
+use goblin::error::Error;
+use scroll::{Pread, LE};
+use std::ptr::null_mut;
+
+// Source: https://wiki.osdev.org/NE
+
+const SEGMENT_ENTRY_SIZE: usize = 8;
+const FLAG_LIBRARY_MODULE: u16 = 0x8000;
+const FLAG_80286: u16 = 0x0020;
+const FLAG_80386: u16 = 0x0040;
+const APPLICATION_TYPE_MASK: u16 = 0x0700;
+const SEGMENT_DATA: u16 = 0x0001;
+// Code segments are execute-only and data segments are read-only if it is set
+const SEGMENT_READ_ONLY: u16 = 0x0080;
+
+// Both addresses are stored as offset:segment in a double word
+fn far_pointer(value: u32) -> Option<String> {
+    let (segment, offset) = ((value >> 16) as u16, value as u16);
+    (segment != 0).then(|| format!("{:04X}:{:04X}", segment, offset))
+}
+
+fn list_segments_ne(
+    buffer: &[u8],
+    table: usize,
+    count: u16,
+    alignment_shift: u16,
+) -> Result<SectionInfoVec, Error> {
+    let alignment = 1u64.checked_shl(alignment_shift as u32).unwrap_or(0);
+    let segments = (0..count as usize)
+        .map(|index| {
+            let entry = table + index * SEGMENT_ENTRY_SIZE;
+            let sector = buffer.pread_with::<u16>(entry, LE)? as u64;
+            let length = buffer.pread_with::<u16>(entry + 2, LE)? as u64;
+            let flags = buffer.pread_with::<u16>(entry + 4, LE)?;
+            let minimum_allocation = buffer.pread_with::<u16>(entry + 6, LE)? as u64;
+            // Zero means 64 KiB for the sizes and "no data in the file" for the sector
+            let file_size = match (sector, length) {
+                (0, _) => 0,
+                (_, 0) => 0x10000,
+                (_, length) => length,
+            };
+            let virtual_size = match minimum_allocation {
+                0 => 0x10000,
+                size => size,
+            };
+            let is_data = flags & SEGMENT_DATA != 0;
+            let is_read_only = flags & SEGMENT_READ_ONLY != 0;
+
+            Ok(SectionInfo {
+                name: if is_data { "DATA" } else { "CODE" }.to_c_string(),
+                segment_name: null_mut(),
+                section_type: null_mut(),
+                virtual_address: 0,
+                virtual_size,
+                file_offset: sector * alignment,
+                file_size,
+                permissions: permissions_to_c_string(
+                    is_data || !is_read_only,
+                    is_data && !is_read_only,
+                    !is_data,
+                ),
+                flags: flags as u64,
+                alignment,
+            })
+        })
+        .collect::<Result<Vec<SectionInfo>, Error>>()?;
+
+    Ok(SectionInfoVec::from(segments))
+}
+
+// The module reference table has the offsets of the names in the imported names table
+fn read_imported_modules(
+    buffer: &[u8],
+    references: usize,
+    count: u16,
+    names: usize,
+) -> Result<Vec<String>, Error> {
+    (0..count as usize)
+        .map(|index| {
+            let offset = buffer.pread_with::<u16>(references + index * 2, LE)? as usize;
+            read_pascal_string(buffer, names + offset)
+                .map(|(name, _)| name)
+                .ok_or_else(|| {
+                    Error::Malformed(format!(
+                        "The name of the imported module {} is out of the file",
+                        index + 1
+                    ))
+                })
+        })
+        .collect()
+}
+
+pub(crate) fn parse_ne(
+    buffer: &[u8],
+    header: usize,
+    options: &ULDDOptions,
+) -> Result<ULDDObj, Error> {
+    let read_u8 = |offset: usize| buffer.pread::<u8>(header + offset);
+    let read_u16 = |offset: usize| buffer.pread_with::<u16>(header + offset, LE);
+    let read_u32 = |offset: usize| buffer.pread_with::<u32>(header + offset, LE);
+
+    let linker_version = format!("{}.{}", read_u8(0x02)?, read_u8(0x03)?);
+    let flags = read_u16(0x0c)?;
+    let entry_point = far_pointer(read_u32(0x14)?);
+    let stack_pointer = far_pointer(read_u32(0x18)?);
+    let segment_count = read_u16(0x1c)?;
+    let module_count = read_u16(0x1e)?;
+    let segment_table = header + read_u16(0x22)? as usize;
+    let resident_names = header + read_u16(0x26)? as usize;
+    let module_references = header + read_u16(0x28)? as usize;
+    let imported_names = header + read_u16(0x2a)? as usize;
+    let non_resident_names = read_u32(0x2c)? as usize;
+    let alignment_shift = read_u16(0x32)?;
+    let target_os = read_u8(0x36)?;
+    let windows_version = (read_u8(0x3f)?, read_u8(0x3e)?);
+
+    // Windows 1.x and 2.x executables don't set the target OS
+    let os_type = match MZ_TARGET_OS.get(&(target_os as u16)) {
+        Some(os) => Some(*os),
+        None if windows_version != (0, 0) => Some("Windows"),
+        None => None,
+    };
+    let os_version = match (os_type, windows_version) {
+        (Some("Windows"), (major, minor)) if (major, minor) != (0, 0) => {
+            Some(format!("{}.{}", major, minor))
+        }
+        _ => None,
+    };
+    let file_type = if flags & FLAG_LIBRARY_MODULE != 0 {
+        MzFileType::DynamicLinkLibrary
+    } else {
+        MzFileType::Executable
+    };
+    let cpu_type = if flags & FLAG_80386 != 0 {
+        "Intel 386"
+    } else if flags & FLAG_80286 != 0 {
+        "Intel 80286"
+    } else {
+        "Intel 8086"
+    };
+    let mut flag_list = flag_name_list((flags & !APPLICATION_TYPE_MASK) as u64, &NE_FLAGS);
+    if let Some(application_type) = MZ_APPLICATION_TYPE.get(&((flags & APPLICATION_TYPE_MASK) >> 8))
+    {
+        flag_list.push(application_type.to_string());
+    }
+    let libraries = read_imported_modules(buffer, module_references, module_count, imported_names)?;
+    let segments = if options.list_sections {
+        list_segments_ne(buffer, segment_table, segment_count, alignment_shift)?
+    } else {
+        Default::default()
+    };
+    let (is_stripped, stripping) = classify_stripping(false, &find_codeview(buffer, None));
+
+    Ok(ULDDObj {
+        executable_format: "NE".to_c_string(),
+        is_64: false,
+        os_type: option_to_c_string(os_type),
+        file_type: file_type.to_c_string(),
+        is_stripped,
+        cpu_type: cpu_type.to_c_string(),
+        cpu_subtype: null_mut(),
+        interpreter: null_mut(),
+        libraries: CharVec::from(libraries),
+        os_version: option_to_c_string(os_version),
+        stripping,
+        segments,
+        mz: MzInfo {
+            module_name: option_to_c_string(read_first_name(buffer, resident_names)),
+            description: option_to_c_string(read_first_name(buffer, non_resident_names)),
+            entry_point: option_to_c_string(entry_point),
+            stack_pointer: option_to_c_string(stack_pointer),
+            flags: CharVec::from(flag_list),
+            linker_version: linker_version.to_c_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
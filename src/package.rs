@@ -0,0 +1,649 @@
+use crate::{
+    compression::{decompress_stream, is_compressed},
+    cpio::{is_cpio, parse_cpio},
+    debug::{debug_objects, merge_members, option_to_c_string},
+    impls::{ErrorToInt, StringToCString},
+    structs::{CharVec, Debugging, PackageInfo, ParsingError, ULDDObj, ULDDObjResult, ULDDOptions},
+    tar::{is_tar, parse_installed_tar, read_entries},
+    zip::{Budget, MAX_NESTING_DEPTH},
+};
+use goblin::{archive::Archive, error::Error};
+use scroll::{Pread, BE};
+use std::borrow::Cow;
+
+/*
+Sources:
+    https://man7.org/linux/man-pages/man5/deb.5.html
+    https://www.debian.org/doc/debian-policy/ch-controlfields.html
+    https://rpm-software-management.github.io/rpm/manual/format_v4.html
+    https://rpm-software-management.github.io/rpm/manual/tags.html
+*/
+
+pub(crate) const RPM_MAGIC: &[u8] = b"\xed\xab\xee\xdb";
+const DEBIAN_BINARY: &str = "debian-binary";
+
+const RPM_LEAD_SIZE: usize = 96;
+const RPM_HEADER_MAGIC: &[u8] = b"\x8e\xad\xe8\x01";
+const RPM_INDEX_ENTRY_SIZE: usize = 16;
+
+const RPM_INT32_TYPE: u32 = 4;
+const RPM_STRING_TYPE: u32 = 6;
+const RPM_STRING_ARRAY_TYPE: u32 = 8;
+const RPM_I18NSTRING_TYPE: u32 = 9;
+
+const RPMTAG_NAME: u32 = 1000;
+const RPMTAG_VERSION: u32 = 1001;
+const RPMTAG_RELEASE: u32 = 1002;
+const RPMTAG_EPOCH: u32 = 1003;
+const RPMTAG_ARCH: u32 = 1022;
+const RPMTAG_REQUIREFLAGS: u32 = 1048;
+const RPMTAG_REQUIRENAME: u32 = 1049;
+const RPMTAG_REQUIREVERSION: u32 = 1050;
+const RPMTAG_PAYLOADFORMAT: u32 = 1124;
+const RPMTAG_PAYLOADCOMPRESSOR: u32 = 1125;
+
+const RPMSENSE_LESS: u32 = 1 << 1;
+const RPMSENSE_GREATER: u32 = 1 << 2;
+const RPMSENSE_EQUAL: u32 = 1 << 3;
+const RPMSENSE_RPMLIB: u32 = 1 << 24;
+
+/// The metadata of a package which is given to all of its objects.
+struct Package {
+    format: &'static str,
+    name: Option<String>,
+    version: Option<String>,
+    architecture: Option<String>,
+    dependencies: Vec<String>,
+}
+
+impl Package {
+    fn new(format: &'static str) -> Package {
+        Package {
+            format,
+            name: None,
+            version: None,
+            architecture: None,
+            dependencies: vec![],
+        }
+    }
+
+    fn to_package_info(&self) -> PackageInfo {
+        PackageInfo {
+            format: self.format.to_c_string(),
+            name: option_to_c_string(self.name.as_deref()),
+            version: option_to_c_string(self.version.as_deref()),
+            architecture: option_to_c_string(self.architecture.as_deref()),
+            dependencies: CharVec::from(self.dependencies.clone()),
+        }
+    }
+}
+
+/// Names the files in packages with the paths they are installed to (Like: /usr/bin/ls for ./usr/bin/ls).
+pub(crate) fn installed_path(path: &str) -> String {
+    format!("/{}", path.trim_start_matches("./").trim_start_matches('/'))
+}
+
+/// Tells Debian packages from other `ar` archives by their first member.
+pub(crate) fn is_deb(archive: &Archive) -> bool {
+    archive
+        .get_at(0)
+        .is_some_and(|member| member.extended_name() == DEBIAN_BINARY)
+}
+
+// The objects in the packages in this package (Like: .deb files in the .tar files of .rpm files) keep the metadata of their own packages
+fn give_package(package: &Package, objects: &mut [ULDDObjResult]) {
+    for object in objects
+        .iter_mut()
+        .filter(|object| object.obj.package.format.is_null())
+    {
+        object.obj.package = package.to_package_info();
+    }
+}
+
+// The package itself is given as an object as well, so its metadata is read even if it has no objects or its payload is broken
+fn to_package_object(
+    file_name: &str,
+    file_type: &str,
+    member_names: &mut Vec<&str>,
+) -> ULDDObjResult {
+    ULDDObjResult {
+        error: ParsingError::default(),
+        obj: ULDDObj {
+            file_name: file_name.to_c_string(),
+            member_name: CharVec::from(member_names),
+            file_type: file_type.to_c_string(),
+            ..Default::default()
+        },
+    }
+}
+
+fn to_error(
+    file_name: &str,
+    error: Error,
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+) -> ULDDObjResult {
+    Debugging::Error(format!(
+        "Error while reading the package named '{}'{}\nDetails:\n{}",
+        file_name,
+        merge_members(member_names),
+        error
+    ))
+    .print(options.debugging);
+
+    ULDDObjResult {
+        error: ParsingError {
+            code: error.to_int(),
+            explanation: error.to_c_string(),
+        },
+        obj: ULDDObj {
+            file_name: file_name.to_c_string(),
+            member_name: CharVec::from(member_names),
+            file_type: "Archive".to_c_string(),
+            ..Default::default()
+        },
+    }
+}
+
+fn check_depth(member_names: &[&str]) -> Result<(), Error> {
+    if member_names.len() >= MAX_NESTING_DEPTH {
+        return Err(Error::Malformed(format!(
+            "The package is nested deeper than {} members",
+            MAX_NESTING_DEPTH
+        )));
+    }
+
+    Ok(())
+}
+
+// The members of Debian packages are tar files which may be compressed
+fn read_tar_member<'a>(data: &'a [u8], budget: &mut Budget) -> Result<Cow<'a, [u8]>, Error> {
+    if is_compressed(data) {
+        return decompress_stream(data, budget).map(Cow::Owned);
+    }
+    if !is_tar(data) {
+        return Err(Error::Malformed(
+            "The member is not a tar file or a tar file compressed with gzip, xz or Zstandard"
+                .to_owned(),
+        ));
+    }
+
+    Ok(Cow::Borrowed(data))
+}
+
+// The fields are "Name: value" and the lines which start with a space or a tab continue the value of the field before them
+fn read_control(control: &str, package: &mut Package) {
+    let mut fields: Vec<(&str, String)> = vec![];
+    for line in control.lines() {
+        // The lines are cut at NUL bytes, since the values become C strings
+        let line = line.split('\0').next().unwrap_or_default();
+        match fields.last_mut() {
+            Some((_, value)) if line.starts_with([' ', '\t']) => {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+            _ => {
+                if let Some((name, value)) = line.split_once(':') {
+                    fields.push((name.trim(), value.trim().to_owned()));
+                }
+            }
+        }
+    }
+
+    for (name, value) in fields {
+        match name {
+            "Package" => package.name = Some(value),
+            "Version" => package.version = Some(value),
+            "Architecture" => package.architecture = Some(value),
+            // The packages which must be configured before the package is unpacked are needed as well
+            "Pre-Depends" | "Depends" => package.dependencies.extend(
+                value
+                    .split(',')
+                    .map(|dependency| dependency.split_whitespace().collect::<Vec<_>>().join(" "))
+                    .filter(|dependency| !dependency.is_empty()),
+            ),
+            _ => (),
+        }
+    }
+}
+
+fn read_control_member(
+    data: &[u8],
+    package: &mut Package,
+    budget: &mut Budget,
+) -> Result<(), Error> {
+    let contents = read_tar_member(data, budget)?;
+    let entries = read_entries(&contents)?;
+    let control = entries
+        .iter()
+        .find(|entry| entry.path.trim_start_matches("./") == "control")
+        .ok_or_else(|| Error::Malformed("The control file is not in the member".to_owned()))?;
+    read_control(&String::from_utf8_lossy(control.data), package);
+
+    Ok(())
+}
+
+///
+/// Parses the objects in the data member (`data.tar`, `data.tar.gz`, `data.tar.xz` or `data.tar.zst`) of Debian packages.
+///
+/// The objects are named with the paths they are installed to and they get the name, the version and the dependencies
+/// in the `control` file of the control member. The `debian-binary` member only has the version of the format, so it is skipped.
+///
+pub(crate) fn parse_deb<'a>(
+    file_name: &'a str,
+    buffer: &'a [u8],
+    archive: Archive<'a>,
+    member_names: &mut Vec<&'a str>,
+    objects: &mut Vec<ULDDObjResult>,
+    budget: &mut Budget,
+    options: &ULDDOptions,
+) {
+    if let Err(error) = check_depth(member_names) {
+        return objects.push(to_error(file_name, error, member_names, options));
+    }
+    let start = objects.len();
+    objects.push(to_package_object(file_name, "Debian package", member_names));
+    let mut package = Package::new("deb");
+    let mut names: Vec<&str> = member_names.to_vec();
+    names.push(file_name);
+    debug_objects(file_name, &mut names, "a Debian package", options.debugging);
+
+    let mut has_data = false;
+    // The members are read in their order in the package, as the names from goblin are not ordered
+    let members = (0..archive.len()).filter_map(|index| archive.get_at(index));
+    for member in members.map(|member| member.extended_name()) {
+        let data = match archive.extract(member, buffer) {
+            Ok(data) => data,
+            Err(error) => {
+                objects.push(to_error(member, error, &mut names.clone(), options));
+                continue;
+            }
+        };
+        if member.starts_with("control.tar") {
+            if let Err(error) = read_control_member(data, &mut package, budget) {
+                objects.push(to_error(member, error, &mut names.clone(), options));
+            }
+        } else if member.starts_with("data.tar") {
+            has_data = true;
+            match read_tar_member(data, budget) {
+                Ok(contents) => parse_installed_tar(
+                    member,
+                    &contents,
+                    &mut names.clone(),
+                    objects,
+                    budget,
+                    options,
+                ),
+                Err(error) => objects.push(to_error(member, error, &mut names.clone(), options)),
+            }
+        }
+    }
+    if !has_data {
+        let error = Error::Malformed("The Debian package doesn't have a data member".to_owned());
+        objects.push(to_error(file_name, error, member_names, options));
+    }
+
+    give_package(&package, &mut objects[start..]);
+}
+
+/// The index and the data of a header of RPM packages.
+struct RpmHeader<'a> {
+    // The tag, the type, the offset in the data and the count of each entry
+    entries: Vec<(u32, u32, usize, usize)>,
+    data: &'a [u8],
+}
+
+impl RpmHeader<'_> {
+    fn find(&self, tag: u32, types: &[u32]) -> Option<(usize, usize)> {
+        self.entries
+            .iter()
+            .find(|(entry_tag, entry_type, ..)| *entry_tag == tag && types.contains(entry_type))
+            .map(|(_, _, offset, count)| (*offset, *count))
+    }
+
+    // The strings of an array follow each other
+    fn strings(&self, tag: u32) -> Vec<String> {
+        let Some((offset, count)) = self.find(
+            tag,
+            &[RPM_STRING_TYPE, RPM_STRING_ARRAY_TYPE, RPM_I18NSTRING_TYPE],
+        ) else {
+            return vec![];
+        };
+        let mut rest = self.data.get(offset..).unwrap_or_default();
+
+        (0..count)
+            .map_while(|_| {
+                let end = rest.iter().position(|b| *b == 0)?;
+                let string = String::from_utf8_lossy(&rest[..end]).into_owned();
+                rest = &rest[end + 1..];
+                Some(string)
+            })
+            .collect()
+    }
+
+    // Only the first string of the translated strings is read
+    fn string(&self, tag: u32) -> Option<String> {
+        self.strings(tag).into_iter().next()
+    }
+
+    fn numbers(&self, tag: u32) -> Vec<u32> {
+        let Some((offset, count)) = self.find(tag, &[RPM_INT32_TYPE]) else {
+            return vec![];
+        };
+
+        (0..count)
+            .map_while(|index| self.data.pread_with::<u32>(offset + index * 4, BE).ok())
+            .collect()
+    }
+}
+
+// Returns the header and its end
+fn read_rpm_header(buffer: &[u8], offset: usize) -> Result<(RpmHeader<'_>, usize), Error> {
+    let header = buffer.get(offset..).unwrap_or_default();
+    if !header.starts_with(RPM_HEADER_MAGIC) {
+        return Err(Error::Malformed(format!(
+            "The header at {:#x} doesn't start with the magic number of RPM headers",
+            offset
+        )));
+    }
+    let entry_count = header.pread_with::<u32>(8, BE)? as usize;
+    let data_size = header.pread_with::<u32>(12, BE)? as usize;
+    let data_start = entry_count
+        .checked_mul(RPM_INDEX_ENTRY_SIZE)
+        .and_then(|size| size.checked_add(16))
+        .filter(|start| *start <= header.len())
+        .ok_or(Error::BufferTooShort(
+            entry_count,
+            "entries of the RPM header",
+        ))?;
+    let data = data_start
+        .checked_add(data_size)
+        .and_then(|end| header.get(data_start..end))
+        .ok_or(Error::BufferTooShort(data_size, "bytes of the RPM header"))?;
+
+    let entries = (0..entry_count)
+        .map(|index| {
+            let entry = 16 + index * RPM_INDEX_ENTRY_SIZE;
+            Ok((
+                header.pread_with::<u32>(entry, BE)?,
+                header.pread_with::<u32>(entry + 4, BE)?,
+                header.pread_with::<u32>(entry + 8, BE)? as usize,
+                header.pread_with::<u32>(entry + 12, BE)? as usize,
+            ))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok((RpmHeader { entries, data }, offset + data_start + data_size))
+}
+
+// The requirements are written like `rpm -qR` (Like: libc.so.6(GLIBC_2.34)(64bit) and bash >= 5.0)
+fn read_requirements(header: &RpmHeader) -> Vec<String> {
+    let flags = header.numbers(RPMTAG_REQUIREFLAGS);
+    let versions = header.strings(RPMTAG_REQUIREVERSION);
+    let mut requirements: Vec<String> = vec![];
+    for (index, name) in header.strings(RPMTAG_REQUIRENAME).into_iter().enumerate() {
+        let flags = flags.get(index).copied().unwrap_or_default();
+        if flags & RPMSENSE_RPMLIB != 0 || name.starts_with("rpmlib(") {
+            continue;
+        }
+        let operator = match flags & (RPMSENSE_LESS | RPMSENSE_GREATER | RPMSENSE_EQUAL) {
+            RPMSENSE_LESS => "<",
+            RPMSENSE_GREATER => ">",
+            RPMSENSE_EQUAL => "=",
+            operator if operator == RPMSENSE_LESS | RPMSENSE_EQUAL => "<=",
+            operator if operator == RPMSENSE_GREATER | RPMSENSE_EQUAL => ">=",
+            _ => "",
+        };
+        let requirement = match versions.get(index) {
+            Some(version) if !version.is_empty() && !operator.is_empty() => {
+                format!("{} {} {}", name, operator, version)
+            }
+            _ => name,
+        };
+        // The same requirement is written again for each scriptlet which needs it
+        if !requirements.contains(&requirement) {
+            requirements.push(requirement);
+        }
+    }
+
+    requirements
+}
+
+// The lead is followed by the signature header, which is padded to 8 bytes, and the header. Returns the header and the start of the payload.
+fn read_rpm_headers(buffer: &[u8]) -> Result<(RpmHeader<'_>, usize), Error> {
+    let (_, signature_end) = read_rpm_header(buffer, RPM_LEAD_SIZE)?;
+
+    read_rpm_header(buffer, signature_end.next_multiple_of(8))
+}
+
+fn read_rpm_package(header: &RpmHeader) -> Package {
+    let version = header.string(RPMTAG_VERSION).map(|version| {
+        let epoch = header
+            .numbers(RPMTAG_EPOCH)
+            .first()
+            .map(|epoch| format!("{}:", epoch));
+        let release = header
+            .string(RPMTAG_RELEASE)
+            .map(|release| format!("-{}", release));
+        format!(
+            "{}{}{}",
+            epoch.unwrap_or_default(),
+            version,
+            release.unwrap_or_default()
+        )
+    });
+
+    Package {
+        format: "rpm",
+        name: header.string(RPMTAG_NAME),
+        version,
+        architecture: header.string(RPMTAG_ARCH),
+        dependencies: read_requirements(header),
+    }
+}
+
+fn read_rpm_payload<'a>(
+    header: &RpmHeader,
+    payload: &'a [u8],
+    budget: &mut Budget,
+) -> Result<Cow<'a, [u8]>, Error> {
+    if let Some(format) = header
+        .string(RPMTAG_PAYLOADFORMAT)
+        .filter(|format| format != "cpio")
+    {
+        return Err(Error::Malformed(format!(
+            "The payload format ({}) is not supported",
+            format
+        )));
+    }
+    if is_compressed(payload) {
+        return decompress_stream(payload, budget).map(Cow::Owned);
+    }
+    if !is_cpio(payload) {
+        return Err(Error::Malformed(format!(
+            "The payload is compressed with {} which is not supported",
+            header
+                .string(RPMTAG_PAYLOADCOMPRESSOR)
+                .unwrap_or("an unknown compressor".to_owned())
+        )));
+    }
+
+    Ok(Cow::Borrowed(payload))
+}
+
+///
+/// Parses the objects in the cpio payload of RPM packages.
+///
+/// The objects are named with the paths they are installed to and they get the name, the version, the architecture and the
+/// requirements in the header of the package. The payloads compressed with bzip2 and LZMA (not xz) are not supported.
+///
+pub(crate) fn parse_rpm(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    objects: &mut Vec<ULDDObjResult>,
+    budget: &mut Budget,
+    options: &ULDDOptions,
+) {
+    let start = objects.len();
+    let headers = check_depth(member_names).and_then(|_| read_rpm_headers(buffer));
+    let (header, payload_start) = match headers {
+        Ok(headers) => headers,
+        Err(error) => {
+            objects.push(to_error(file_name, error, member_names, options));
+            return give_package(&Package::new("rpm"), &mut objects[start..]);
+        }
+    };
+    let package = read_rpm_package(&header);
+    objects.push(to_package_object(file_name, "RPM package", member_names));
+
+    let payload = buffer.get(payload_start..).unwrap_or_default();
+    match read_rpm_payload(&header, payload, budget) {
+        Ok(payload) => {
+            debug_objects(file_name, member_names, "an RPM package", options.debugging);
+            parse_cpio(file_name, &payload, member_names, objects, budget, options);
+        }
+        Err(error) => objects.push(to_error(file_name, error, member_names, options)),
+    }
+
+    give_package(&package, &mut objects[start..]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{ar, cpio, elf, gzip, parse, read_string, summarize, tar};
+
+    const CONTROL: &[u8] =
+        b"Package: hello\nVersion: 2.10-3\nArchitecture: amd64\nDepends: libc6 (>= 2.34)\n";
+
+    fn deb(data: &[u8]) -> Vec<u8> {
+        let control = tar(&[("./control", b'0', CONTROL)]);
+        ar(&[
+            (DEBIAN_BINARY, b"2.0\n"),
+            ("control.tar", &control),
+            ("data.tar.gz", &gzip(data)),
+        ])
+    }
+
+    // The signature header is empty and the header has the name, the version, the release, the architecture and the requirements
+    fn rpm(payload: &[u8]) -> Vec<u8> {
+        let mut buffer = RPM_MAGIC.to_vec();
+        buffer.resize(RPM_LEAD_SIZE, 0);
+        buffer.extend([RPM_HEADER_MAGIC, &[0; 12]].concat());
+
+        let entries: [(u32, u32, &[u8], u32); 6] = [
+            (RPMTAG_NAME, RPM_STRING_TYPE, b"hello\0", 1),
+            (RPMTAG_VERSION, RPM_STRING_TYPE, b"2.10\0", 1),
+            (RPMTAG_RELEASE, RPM_STRING_TYPE, b"3.fc40\0", 1),
+            (RPMTAG_ARCH, RPM_STRING_TYPE, b"x86_64\0", 1),
+            (
+                RPMTAG_REQUIRENAME,
+                RPM_STRING_ARRAY_TYPE,
+                b"libc.so.6()(64bit)\0rpmlib(PayloadIsXz)\0",
+                2,
+            ),
+            (
+                RPMTAG_REQUIREFLAGS,
+                RPM_INT32_TYPE,
+                &[0, 0, 0, 0, 1, 0, 0, 0],
+                2,
+            ),
+        ];
+        let mut index = vec![];
+        let mut data: Vec<u8> = vec![];
+        for (tag, kind, value, count) in entries {
+            for field in [tag, kind, data.len() as u32, count] {
+                index.extend(field.to_be_bytes());
+            }
+            data.extend(value);
+        }
+        buffer.extend(RPM_HEADER_MAGIC);
+        buffer.extend([0; 4]);
+        buffer.extend((entries.len() as u32).to_be_bytes());
+        buffer.extend((data.len() as u32).to_be_bytes());
+        buffer.extend(index);
+        buffer.extend(data);
+        buffer.extend(payload);
+
+        buffer
+    }
+
+    fn package_names(objects: &[ULDDObjResult]) -> Vec<(Option<String>, Option<String>)> {
+        objects
+            .iter()
+            .map(|object| {
+                (
+                    read_string(object.obj.package.name),
+                    read_string(object.obj.package.version),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn deb_objects_are_named_with_their_installed_paths() {
+        let data = tar(&[("./usr/bin/hello", b'0', &elf())]);
+        let objects = parse("hello.deb", &deb(&data));
+
+        assert_eq!(
+            summarize(&objects),
+            [
+                ("hello.deb".to_owned(), vec![], 0),
+                (
+                    "/usr/bin/hello".to_owned(),
+                    vec!["hello.deb".to_owned(), "data.tar.gz".to_owned()],
+                    0
+                ),
+            ]
+        );
+        let package = (Some("hello".to_owned()), Some("2.10-3".to_owned()));
+        assert_eq!(package_names(&objects), [package.clone(), package]);
+    }
+
+    #[test]
+    fn rpm_objects_are_named_with_their_installed_paths() {
+        let payload = cpio(&[("./usr/lib64/libhello.so", 1, 1, &elf())]);
+        let objects = parse("hello.rpm", &rpm(&payload));
+
+        assert_eq!(
+            summarize(&objects),
+            [
+                ("hello.rpm".to_owned(), vec![], 0),
+                (
+                    "/usr/lib64/libhello.so".to_owned(),
+                    vec!["hello.rpm".to_owned()],
+                    0
+                ),
+            ]
+        );
+        let package = (Some("hello".to_owned()), Some("2.10-3.fc40".to_owned()));
+        assert_eq!(package_names(&objects), [package.clone(), package]);
+        assert_eq!(
+            read_requirements(&read_rpm_headers(&rpm(&payload)).unwrap().0),
+            ["libc.so.6()(64bit)"]
+        );
+    }
+
+    #[test]
+    fn packages_without_objects_keep_their_metadata() {
+        let data = tar(&[("./usr/share/doc/hello/README", b'0', b"hello")]);
+        let objects = parse("hello.deb", &deb(&data));
+        assert_eq!(summarize(&objects), [("hello.deb".to_owned(), vec![], 0)]);
+        assert_eq!(
+            read_string(objects[0].obj.file_type).as_deref(),
+            Some("Debian package")
+        );
+
+        // The payload is not a cpio archive, but the package is still given with its metadata
+        let objects = parse("hello.rpm", &rpm(b"not a payload"));
+        assert_eq!(
+            summarize(&objects)
+                .iter()
+                .map(|(_, _, code)| *code)
+                .collect::<Vec<_>>(),
+            [0, -1]
+        );
+        assert_eq!(package_names(&objects)[0].0.as_deref(), Some("hello"));
+    }
+}
@@ -1,14 +1,43 @@
 use std::ffi::c_char;
 use crate::{
-    debug::debug_objects,
-    structs::{CharVec, ParsingError, ULDDObj, ULDDObjResult},
-    types::{PeOS, PeSubsystem, PE_ARCH, PE_SUBSYSTEM},
+    debug::{classify_stripping, debug_objects},
+    go::{parse_go_build_info, GoMemory},
+    rust::{parse_rust, AUDITABLE_SECTION},
+    coff::list_sections_coff,
+    structs::{CharVec, GoBuildInfo, ParsingError, RustInfo, ULDDObj, ULDDObjResult, ULDDOptions},
+    types::{DebugInfoFormat, DebugInfoLocation, PeOS, PeSubsystem, PE_ARCH, PE_SUBSYSTEM},
+};
+use anomalies::find_anomalies;
+use clr::parse_clr;
+use debug_directory::parse_debug_directory;
+use imphash::imphash;
+use load_config::parse_load_info;
+use goblin::pe::{
+    options::ParseOptions,
+    section_table::{
+        SectionTable, IMAGE_SCN_CNT_INITIALIZED_DATA, IMAGE_SCN_MEM_EXECUTE, IMAGE_SCN_MEM_WRITE,
+    },
+    utils::find_offset,
+    PE,
 };
-use goblin::pe::{characteristic::IMAGE_FILE_DEBUG_STRIPPED, PE};
 use std::ptr::null_mut;
 use crate::debug::option_to_c_string;
 use crate::impls::StringToCString;
 
+mod anomalies;
+mod clr;
+mod debug_directory;
+mod imphash;
+mod load_config;
+
+pub(crate) fn rva_to_offset(pe: &PE<'_>, rva: usize) -> Option<usize> {
+    let file_alignment = pe
+        .header
+        .optional_header
+        .map(|h| h.windows_fields.file_alignment)?;
+    find_offset(rva, &pe.sections, file_alignment, &ParseOptions::default())
+}
+
 fn find_os_pe(pe: &PE<'_>) -> *mut c_char {
     let Some(optional_header) = pe
         .header
@@ -38,14 +67,93 @@ fn find_os_pe(pe: &PE<'_>) -> *mut c_char {
     os.to_c_string()
 }
 
+// The Go linker puts the build information at the start of .data
+fn find_go_build_info_pe(buffer: &[u8], pe: &PE<'_>) -> Option<GoBuildInfo> {
+    let sections: Vec<_> = pe
+        .sections
+        .iter()
+        .filter_map(|s| {
+            let start = s.pointer_to_raw_data as usize;
+            let data = buffer.get(start..start.checked_add(s.size_of_raw_data as usize)?)?;
+            // The image base comes from the file, so the sections past the end of the address space are skipped
+            let address = (pe.image_base as u64).checked_add(s.virtual_address as u64)?;
+            Some((s.characteristics, address, data))
+        })
+        .collect();
+    let candidates: Vec<&[u8]> = sections
+        .iter()
+        .filter(|(characteristics, _, _)| {
+            characteristics & (IMAGE_SCN_CNT_INITIALIZED_DATA | IMAGE_SCN_MEM_WRITE)
+                == IMAGE_SCN_CNT_INITIALIZED_DATA | IMAGE_SCN_MEM_WRITE
+        })
+        .map(|(_, _, data)| *data)
+        .collect();
+    let memory = GoMemory {
+        regions: sections
+            .iter()
+            .map(|(_, address, data)| (*address, *data))
+            .collect(),
+    };
+
+    parse_go_build_info(&candidates, &memory).map(|(_, go)| go)
+}
+
+// PE images rarely have symbols other than the exports
+fn find_rust_pe(buffer: &[u8], pe: &PE<'_>) -> RustInfo {
+    let section_data = |s: &SectionTable| {
+        let start = s.pointer_to_raw_data as usize;
+        buffer.get(start..start.checked_add(s.size_of_raw_data as usize)?)
+    };
+    let auditable = pe
+        .sections
+        .iter()
+        .find(|s| s.name().is_ok_and(|name| name == AUDITABLE_SECTION))
+        .and_then(section_data);
+    // The strings are in the read-only data sections (Like: `.rdata`)
+    let data: Vec<&[u8]> = pe
+        .sections
+        .iter()
+        .filter(|s| {
+            s.characteristics
+                & (IMAGE_SCN_CNT_INITIALIZED_DATA | IMAGE_SCN_MEM_WRITE | IMAGE_SCN_MEM_EXECUTE)
+                == IMAGE_SCN_CNT_INITIALIZED_DATA
+        })
+        .filter_map(section_data)
+        .collect();
+
+    parse_rust(
+        None,
+        pe.sections
+            .iter()
+            .any(|s| s.name().is_ok_and(|name| name == ".rustc")),
+        pe.exports.iter().filter_map(|export| export.name),
+        auditable,
+        &data,
+    )
+}
+
 pub(crate) fn parse_pe(
     file_name: &str,
+    buffer: &[u8],
     pe: PE,
     member_names: &mut Vec<&str>,
-    debugging: bool,
+    options: &ULDDOptions,
 ) -> ULDDObjResult {
-    let is_stripped = pe.header.coff_header.characteristics & IMAGE_FILE_DEBUG_STRIPPED
-        == IMAGE_FILE_DEBUG_STRIPPED;
+    let debugging = options.debugging;
+    // Modern linkers never set IMAGE_FILE_DEBUG_STRIPPED, so the debug directory and the COFF symbol table are checked instead
+    let debug_directory = parse_debug_directory(buffer, &pe);
+    let has_coff_symbols = pe.header.coff_header.pointer_to_symbol_table != 0
+        && pe.header.coff_header.number_of_symbol_table != 0;
+    let mut debug_info = debug_directory.debug_info();
+    // MinGW leaves DWARF in the image unless it is stripped
+    if pe
+        .sections
+        .iter()
+        .any(|s| s.name().is_ok_and(|name| name.starts_with(".debug_")))
+    {
+        debug_info.push((DebugInfoFormat::DWARF, DebugInfoLocation::Embedded));
+    }
+    let (is_stripped, stripping) = classify_stripping(has_coff_symbols, &debug_info);
     let cpu_type = option_to_c_string(PE_ARCH.get(&pe.header.coff_header.machine));
     let file_type = pe
         .header
@@ -65,6 +173,26 @@ pub(crate) fn parse_pe(
             null_mut()
         }
     };
+    let (dotnet, assembly_names) = parse_clr(buffer, &pe);
+    let pe_load = parse_load_info(buffer, &pe);
+    let pe_anomalies = find_anomalies(buffer, &pe);
+    let import_hash = option_to_c_string(imphash(&pe));
+    let go = find_go_build_info_pe(buffer, &pe).unwrap_or_default();
+    let rust = find_rust_pe(buffer, &pe);
+    let sections = if options.list_sections {
+        list_sections_coff(
+            &pe.sections,
+            pe.image_base as u64,
+            pe.header
+                .optional_header
+                .map(|h| h.windows_fields.section_alignment),
+        )
+    } else {
+        Default::default()
+    };
+    if dotnet.is_dotnet {
+        debug_objects(file_name, member_names, "a .NET assembly", debugging);
+    }
     let executable_format = if pe.is_64 {
         debug_objects(file_name, member_names, "a PE32+ binary", debugging);
         "PE32+".to_c_string()
@@ -85,7 +213,23 @@ pub(crate) fn parse_pe(
             cpu_type,
             cpu_subtype: null_mut(),
             interpreter,
-            libraries: CharVec::from(pe.libraries),
+            libraries: CharVec::from(
+                pe.libraries
+                    .iter()
+                    .map(|library| library.to_string())
+                    .chain(assembly_names)
+                    .collect::<Vec<String>>(),
+            ),
+            pe_debug: debug_directory.to_pe_debug_info(),
+            dotnet,
+            pe_load,
+            pe_anomalies,
+            import_hash,
+            stripping,
+            go,
+            rust,
+            sections,
+            ..Default::default()
         },
     }
 }
@@ -0,0 +1,136 @@
+use crate::structs::{CharVec, PeAnomalies};
+use goblin::pe::{
+    section_table::{IMAGE_SCN_MEM_EXECUTE, IMAGE_SCN_MEM_WRITE},
+    PE,
+};
+use scroll::{Pread, LE};
+
+/*
+Sources:
+    https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#section-table-section-headers
+    https://practicalsecurityanalytics.com/pe-checksum/
+*/
+
+// Offset of the `CheckSum` field from the start of the optional header. It is the same in PE32 and PE32+.
+const CHECKSUM_OFFSET: usize = 64;
+
+// Ratio of the virtual size to the raw size after which the section is most likely unpacked at runtime
+const MAX_VIRTUAL_TO_RAW_RATIO: u32 = 10;
+
+// Names emitted by MSVC, MinGW and Clang
+const STANDARD_SECTION_NAMES: &[&str] = &[
+    ".text", ".data", ".rdata", ".bss", ".idata", ".edata", ".pdata", ".xdata", ".reloc", ".rsrc",
+    ".tls", ".CRT", ".didat", ".gfids", ".giats", ".gljmp", ".gehcont", ".00cfg", ".textbss",
+    ".sxdata", ".orpc", ".retplne", ".voltbl", ".fptable", ".msvcjmc", ".buildid", ".rodata",
+    ".eh_fram", ".eh_frame", ".init", ".fini", ".ctors", ".dtors", ".drectve", ".a64xrm",
+];
+
+fn align_up(value: u32, alignment: u32) -> u32 {
+    if alignment == 0 {
+        value
+    } else {
+        value.div_ceil(alignment).saturating_mul(alignment)
+    }
+}
+
+fn compute_checksum(buffer: &[u8], checksum_offset: usize) -> u32 {
+    let mut sum: u64 = 0;
+    for (index, word) in buffer.chunks(2).enumerate() {
+        let offset = index * 2;
+        if offset == checksum_offset || offset == checksum_offset + 2 {
+            continue;
+        }
+        let word = match word {
+            [low, high] => u16::from_le_bytes([*low, *high]),
+            [low] => *low as u16,
+            _ => 0,
+        };
+        sum += word as u64;
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+    sum = (sum & 0xFFFF) + (sum >> 16);
+
+    (sum as u32).wrapping_add(buffer.len() as u32)
+}
+
+fn find_section_anomalies(buffer: &[u8], pe: &PE, file_alignment: u32) -> Vec<String> {
+    let mut anomalies = vec![];
+    for section in &pe.sections {
+        let name = section.name().unwrap_or_default();
+        let characteristics = section.characteristics;
+
+        if characteristics & IMAGE_SCN_MEM_WRITE != 0 && characteristics & IMAGE_SCN_MEM_EXECUTE != 0
+        {
+            anomalies.push(format!("Section '{}' is writable and executable", name));
+        }
+
+        if section.size_of_raw_data == 0 && characteristics & IMAGE_SCN_MEM_EXECUTE != 0 {
+            anomalies.push(format!(
+                "Section '{}' is executable but has no raw data",
+                name
+            ));
+        } else if section.size_of_raw_data != 0
+            && section.virtual_size / section.size_of_raw_data > MAX_VIRTUAL_TO_RAW_RATIO
+        {
+            anomalies.push(format!(
+                "Section '{}' has a virtual size ({:#x}) much larger than its raw size ({:#x})",
+                name, section.virtual_size, section.size_of_raw_data
+            ));
+        } else if section.virtual_size != 0
+            && section.size_of_raw_data > align_up(section.virtual_size, file_alignment)
+        {
+            anomalies.push(format!(
+                "Section '{}' has a raw size ({:#x}) larger than its virtual size ({:#x})",
+                name, section.size_of_raw_data, section.virtual_size
+            ));
+        }
+
+        let raw_end = section.pointer_to_raw_data as u64 + section.size_of_raw_data as u64;
+        if section.size_of_raw_data != 0 && raw_end > buffer.len() as u64 {
+            anomalies.push(format!("Section '{}' extends beyond the end of the file", name));
+        }
+
+        if !STANDARD_SECTION_NAMES.contains(&name) && !name.starts_with(".debug") {
+            anomalies.push(format!("Section '{}' has a non-standard name", name));
+        }
+    }
+
+    anomalies
+}
+
+pub(crate) fn find_anomalies(buffer: &[u8], pe: &PE) -> PeAnomalies {
+    let Some(optional_header) = pe.header.optional_header else {
+        return PeAnomalies::default();
+    };
+    let file_alignment = optional_header.windows_fields.file_alignment;
+
+    // The certificate table is not mapped into memory but it is not a part of the overlay either
+    let sections_end = pe
+        .sections
+        .iter()
+        .filter(|s| s.size_of_raw_data != 0)
+        .map(|s| s.pointer_to_raw_data as u64 + s.size_of_raw_data as u64)
+        .max()
+        .unwrap_or(0);
+    let data_end = optional_header
+        .data_directories
+        .get_certificate_table()
+        .map(|dd| dd.virtual_address as u64 + dd.size as u64)
+        .filter(|end| *end <= buffer.len() as u64)
+        .map_or(sections_end, |end| end.max(sections_end));
+    let overlay_size = (buffer.len() as u64).saturating_sub(data_end);
+
+    let checksum_offset = pe.header.dos_header.pe_pointer as usize + 4 + 20 + CHECKSUM_OFFSET;
+    let stored_checksum = buffer.pread_with::<u32>(checksum_offset, LE).unwrap_or(0);
+    let computed_checksum = compute_checksum(buffer, checksum_offset);
+
+    PeAnomalies {
+        has_overlay: overlay_size != 0,
+        overlay_offset: if overlay_size != 0 { data_end } else { 0 },
+        overlay_size,
+        stored_checksum,
+        computed_checksum,
+        is_checksum_valid: stored_checksum == computed_checksum,
+        section_anomalies: CharVec::from(find_section_anomalies(buffer, pe, file_alignment)),
+    }
+}
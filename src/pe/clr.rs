@@ -0,0 +1,530 @@
+use crate::{
+    debug::option_to_c_string,
+    impls::StringToCString,
+    pe::rva_to_offset,
+    structs::{CharVec, DotNetInfo},
+};
+use goblin::pe::{data_directories::DataDirectory, PE};
+use scroll::{Pread, LE};
+
+/*
+Sources:
+    https://ecma-international.org/publications-and-standards/standards/ecma-335/ (Partition II)
+    https://github.com/dotnet/runtime/blob/main/docs/design/coreclr/botr/readytorun-format.md
+*/
+
+const COMIMAGE_FLAGS_ILONLY: u32 = 0x01;
+const COMIMAGE_FLAGS_32BITREQUIRED: u32 = 0x02;
+const COMIMAGE_FLAGS_32BITPREFERRED: u32 = 0x20000;
+
+const METADATA_SIGNATURE: u32 = 0x424A_5342;
+const READY_TO_RUN_SIGNATURE: u32 = 0x0052_5452;
+
+const ASSEMBLY_FLAGS_PUBLIC_KEY: u32 = 0x0001;
+
+// The flag of HeapSizes which tells that 4 bytes of extra data follow the row counts
+const HEAP_SIZES_EXTRA_DATA: u8 = 0x40;
+
+const TARGET_FRAMEWORK_ATTRIBUTE: &str = "TargetFrameworkAttribute";
+
+// Table numbers which are used directly
+const TYPE_REF: usize = 0x01;
+const MEMBER_REF: usize = 0x0A;
+const CUSTOM_ATTRIBUTE: usize = 0x0C;
+const ASSEMBLY: usize = 0x20;
+const ASSEMBLY_REF: usize = 0x23;
+
+const TABLE_COUNT: usize = 0x2D;
+
+#[derive(Clone, Copy)]
+enum Column {
+    Fixed(usize),
+    StringIndex,
+    GuidIndex,
+    BlobIndex,
+    TableIndex(usize),
+    CodedIndex(&'static [usize]),
+}
+
+// Tag tables of the coded indices. `usize::MAX` marks unused tags.
+const TYPE_DEF_OR_REF: &[usize] = &[0x02, 0x01, 0x1B];
+const HAS_CONSTANT: &[usize] = &[0x04, 0x08, 0x17];
+const HAS_CUSTOM_ATTRIBUTE: &[usize] = &[
+    0x06, 0x04, 0x01, 0x02, 0x08, 0x09, 0x0A, 0x00, 0x0E, 0x17, 0x14, 0x11, 0x1A, 0x1B, 0x20,
+    0x23, 0x26, 0x27, 0x28, 0x2A, 0x2C, 0x2B,
+];
+const HAS_FIELD_MARSHAL: &[usize] = &[0x04, 0x08];
+const HAS_DECL_SECURITY: &[usize] = &[0x02, 0x06, 0x20];
+const MEMBER_REF_PARENT: &[usize] = &[0x02, 0x01, 0x1A, 0x06, 0x1B];
+const HAS_SEMANTICS: &[usize] = &[0x14, 0x17];
+const METHOD_DEF_OR_REF: &[usize] = &[0x06, 0x0A];
+const MEMBER_FORWARDED: &[usize] = &[0x04, 0x06];
+const IMPLEMENTATION: &[usize] = &[0x26, 0x23, 0x27];
+const CUSTOM_ATTRIBUTE_TYPE: &[usize] = &[usize::MAX, usize::MAX, 0x06, 0x0A, usize::MAX];
+const RESOLUTION_SCOPE: &[usize] = &[0x00, 0x1A, 0x23, 0x01];
+const TYPE_OR_METHOD_DEF: &[usize] = &[0x02, 0x06];
+
+use Column::{
+    BlobIndex as Blob, CodedIndex as Coded, Fixed, GuidIndex as Guid, StringIndex as Str,
+    TableIndex as Table,
+};
+
+// Source: ECMA-335 Partition II, Chapter 22
+const SCHEMA: [&[Column]; TABLE_COUNT] = [
+    /* 0x00 Module */ &[Fixed(2), Str, Guid, Guid, Guid],
+    /* 0x01 TypeRef */ &[Coded(RESOLUTION_SCOPE), Str, Str],
+    /* 0x02 TypeDef */
+    &[Fixed(4), Str, Str, Coded(TYPE_DEF_OR_REF), Table(0x04), Table(0x06)],
+    /* 0x03 FieldPtr */ &[Table(0x04)],
+    /* 0x04 Field */ &[Fixed(2), Str, Blob],
+    /* 0x05 MethodPtr */ &[Table(0x06)],
+    /* 0x06 MethodDef */ &[Fixed(4), Fixed(2), Fixed(2), Str, Blob, Table(0x08)],
+    /* 0x07 ParamPtr */ &[Table(0x08)],
+    /* 0x08 Param */ &[Fixed(2), Fixed(2), Str],
+    /* 0x09 InterfaceImpl */ &[Table(0x02), Coded(TYPE_DEF_OR_REF)],
+    /* 0x0A MemberRef */ &[Coded(MEMBER_REF_PARENT), Str, Blob],
+    /* 0x0B Constant */ &[Fixed(2), Coded(HAS_CONSTANT), Blob],
+    /* 0x0C CustomAttribute */
+    &[Coded(HAS_CUSTOM_ATTRIBUTE), Coded(CUSTOM_ATTRIBUTE_TYPE), Blob],
+    /* 0x0D FieldMarshal */ &[Coded(HAS_FIELD_MARSHAL), Blob],
+    /* 0x0E DeclSecurity */ &[Fixed(2), Coded(HAS_DECL_SECURITY), Blob],
+    /* 0x0F ClassLayout */ &[Fixed(2), Fixed(4), Table(0x02)],
+    /* 0x10 FieldLayout */ &[Fixed(4), Table(0x04)],
+    /* 0x11 StandAloneSig */ &[Blob],
+    /* 0x12 EventMap */ &[Table(0x02), Table(0x14)],
+    /* 0x13 EventPtr */ &[Table(0x14)],
+    /* 0x14 Event */ &[Fixed(2), Str, Coded(TYPE_DEF_OR_REF)],
+    /* 0x15 PropertyMap */ &[Table(0x02), Table(0x17)],
+    /* 0x16 PropertyPtr */ &[Table(0x17)],
+    /* 0x17 Property */ &[Fixed(2), Str, Blob],
+    /* 0x18 MethodSemantics */ &[Fixed(2), Table(0x06), Coded(HAS_SEMANTICS)],
+    /* 0x19 MethodImpl */
+    &[Table(0x02), Coded(METHOD_DEF_OR_REF), Coded(METHOD_DEF_OR_REF)],
+    /* 0x1A ModuleRef */ &[Str],
+    /* 0x1B TypeSpec */ &[Blob],
+    /* 0x1C ImplMap */ &[Fixed(2), Coded(MEMBER_FORWARDED), Str, Table(0x1A)],
+    /* 0x1D FieldRVA */ &[Fixed(4), Table(0x04)],
+    /* 0x1E EncLog */ &[Fixed(4), Fixed(4)],
+    /* 0x1F EncMap */ &[Fixed(4)],
+    /* 0x20 Assembly */
+    &[Fixed(4), Fixed(2), Fixed(2), Fixed(2), Fixed(2), Fixed(4), Blob, Str, Str],
+    /* 0x21 AssemblyProcessor */ &[Fixed(4)],
+    /* 0x22 AssemblyOS */ &[Fixed(4), Fixed(4), Fixed(4)],
+    /* 0x23 AssemblyRef */
+    &[Fixed(2), Fixed(2), Fixed(2), Fixed(2), Fixed(4), Blob, Str, Str, Blob],
+    /* 0x24 AssemblyRefProcessor */ &[Fixed(4), Table(0x23)],
+    /* 0x25 AssemblyRefOS */ &[Fixed(4), Fixed(4), Fixed(4), Table(0x23)],
+    /* 0x26 File */ &[Fixed(4), Str, Blob],
+    /* 0x27 ExportedType */ &[Fixed(4), Fixed(4), Str, Str, Coded(IMPLEMENTATION)],
+    /* 0x28 ManifestResource */ &[Fixed(4), Fixed(4), Str, Coded(IMPLEMENTATION)],
+    /* 0x29 NestedClass */ &[Table(0x02), Table(0x02)],
+    /* 0x2A GenericParam */ &[Fixed(2), Fixed(2), Coded(TYPE_OR_METHOD_DEF), Str],
+    /* 0x2B MethodSpec */ &[Coded(METHOD_DEF_OR_REF), Blob],
+    /* 0x2C GenericParamConstraint */ &[Table(0x2A), Coded(TYPE_DEF_OR_REF)],
+];
+
+#[derive(Default)]
+struct Heaps<'a> {
+    strings: &'a [u8],
+    blobs: &'a [u8],
+}
+
+impl Heaps<'_> {
+    fn string(&self, index: usize) -> Option<String> {
+        let bytes = self.strings.get(index..)?;
+        let end = bytes.iter().position(|b| *b == 0)?;
+        Some(String::from_utf8_lossy(&bytes[..end]).into_owned())
+    }
+
+    fn blob(&self, index: usize) -> Option<&[u8]> {
+        let bytes = self.blobs.get(index..)?;
+        let (length, size) = read_compressed_u32(bytes)?;
+        bytes.get(size..size + length as usize)
+    }
+}
+
+// Source: ECMA-335 Partition II, 23.2 (Blobs and signatures)
+fn read_compressed_u32(bytes: &[u8]) -> Option<(u32, usize)> {
+    let first = *bytes.first()? as u32;
+    match first {
+        _ if first & 0x80 == 0 => Some((first, 1)),
+        _ if first & 0xC0 == 0x80 => Some((((first & 0x3F) << 8) | *bytes.get(1)? as u32, 2)),
+        _ if first & 0xE0 == 0xC0 => Some((
+            ((first & 0x1F) << 24)
+                | (*bytes.get(1)? as u32) << 16
+                | (*bytes.get(2)? as u32) << 8
+                | *bytes.get(3)? as u32,
+            4,
+        )),
+        _ => None,
+    }
+}
+
+struct Tables<'a> {
+    bytes: &'a [u8],
+    heap_sizes: u8,
+    rows: [u32; TABLE_COUNT],
+    offsets: [usize; TABLE_COUNT],
+    row_sizes: [usize; TABLE_COUNT],
+}
+
+impl<'a> Tables<'a> {
+    fn parse(bytes: &'a [u8]) -> Option<Self> {
+        let heap_sizes: u8 = bytes.pread(6).ok()?;
+        let valid: u64 = bytes.pread_with(8, LE).ok()?;
+        let mut offset = 24;
+        let mut rows = [0_u32; TABLE_COUNT];
+        for (table, row_count) in rows.iter_mut().enumerate() {
+            if valid & (1 << table) != 0 {
+                *row_count = bytes.gread_with(&mut offset, LE).ok()?;
+            }
+        }
+        // Tables after GenericParamConstraint are only used by portable PDBs but their row counts are still present
+        offset += (TABLE_COUNT..64).filter(|t| valid & (1 << t) != 0).count() * 4;
+        if heap_sizes & HEAP_SIZES_EXTRA_DATA != 0 {
+            offset += 4;
+        }
+
+        let mut tables = Tables {
+            bytes,
+            heap_sizes,
+            rows,
+            offsets: [0; TABLE_COUNT],
+            row_sizes: [0; TABLE_COUNT],
+        };
+        for (table, columns) in SCHEMA.iter().enumerate() {
+            tables.row_sizes[table] = columns.iter().map(|c| tables.column_size(*c)).sum();
+            tables.offsets[table] = offset;
+            offset += tables.row_sizes[table] * tables.rows[table] as usize;
+        }
+
+        Some(tables)
+    }
+
+    fn index_size(&self, rows: u32, tag_bits: u32) -> usize {
+        if rows < (1 << (16 - tag_bits)) {
+            2
+        } else {
+            4
+        }
+    }
+
+    fn column_size(&self, column: Column) -> usize {
+        match column {
+            Fixed(size) => size,
+            Str => 2 + 2 * (self.heap_sizes & 0x01 != 0) as usize,
+            Guid => 2 + 2 * (self.heap_sizes & 0x02 != 0) as usize,
+            Blob => 2 + 2 * (self.heap_sizes & 0x04 != 0) as usize,
+            Table(table) => self.index_size(self.rows[table], 0),
+            Coded(tags) => {
+                let tag_bits = usize::BITS - (tags.len() - 1).leading_zeros();
+                let max_rows = tags
+                    .iter()
+                    .filter_map(|t| self.rows.get(*t))
+                    .max()
+                    .copied()
+                    .unwrap_or(0);
+                self.index_size(max_rows, tag_bits)
+            }
+        }
+    }
+
+    /// Reads the columns of the given row (1-based like metadata tokens) as integers.
+    fn row(&self, table: usize, row: u32) -> Option<Vec<usize>> {
+        if row == 0 || row > self.rows[table] {
+            return None;
+        }
+        let mut offset = self.offsets[table] + self.row_sizes[table] * (row as usize - 1);
+        SCHEMA[table]
+            .iter()
+            .map(|column| {
+                let value = match self.column_size(*column) {
+                    1 => self.bytes.gread::<u8>(&mut offset).ok()? as usize,
+                    2 => self.bytes.gread_with::<u16>(&mut offset, LE).ok()? as usize,
+                    _ => self.bytes.gread_with::<u32>(&mut offset, LE).ok()? as usize,
+                };
+                Some(value)
+            })
+            .collect()
+    }
+}
+
+fn decode_coded_index(tags: &[usize], value: usize) -> (usize, u32) {
+    let tag_bits = usize::BITS - (tags.len() - 1).leading_zeros();
+    let tag = value & ((1 << tag_bits) - 1);
+    (
+        tags.get(tag).copied().unwrap_or(usize::MAX),
+        (value >> tag_bits) as u32,
+    )
+}
+
+// The public key token is the last 8 bytes of the SHA-1 hash of the public key in reverse order
+fn public_key_token(public_key: &[u8]) -> String {
+    let hash = sha1_smol::Sha1::from(public_key).digest().bytes();
+    hash[12..].iter().rev().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn format_version(columns: &[usize]) -> String {
+    format!(
+        "{}.{}.{}.{}",
+        columns[0], columns[1], columns[2], columns[3]
+    )
+}
+
+fn format_culture(culture: Option<String>) -> String {
+    culture
+        .filter(|c| !c.is_empty())
+        .unwrap_or("neutral".to_owned())
+}
+
+fn find_target_framework(tables: &Tables, heaps: &Heaps) -> Option<String> {
+    for row in 1..=tables.rows[CUSTOM_ATTRIBUTE] {
+        let columns = tables.row(CUSTOM_ATTRIBUTE, row)?;
+        let (parent_table, _) = decode_coded_index(HAS_CUSTOM_ATTRIBUTE, columns[0]);
+        if parent_table != ASSEMBLY {
+            continue;
+        }
+
+        let (constructor_table, constructor_row) =
+            decode_coded_index(CUSTOM_ATTRIBUTE_TYPE, columns[1]);
+        if constructor_table != MEMBER_REF {
+            continue;
+        }
+        let Some(member_ref) = tables.row(MEMBER_REF, constructor_row) else {
+            continue;
+        };
+        let (class_table, class_row) = decode_coded_index(MEMBER_REF_PARENT, member_ref[0]);
+        if class_table != TYPE_REF {
+            continue;
+        }
+        let type_name = tables
+            .row(TYPE_REF, class_row)
+            .and_then(|type_ref| heaps.string(type_ref[1]));
+        if type_name.as_deref() != Some(TARGET_FRAMEWORK_ATTRIBUTE) {
+            continue;
+        }
+
+        // The value blob starts with the 0x0001 prolog and a SerString of the first argument
+        let value = heaps.blob(columns[2])?;
+        let (length, size) = read_compressed_u32(value.get(2..)?)?;
+        let start = 2 + size;
+        return value
+            .get(start..start + length as usize)
+            .map(|s| String::from_utf8_lossy(s).into_owned());
+    }
+
+    None
+}
+
+fn parse_metadata(
+    metadata: &[u8],
+    info: &mut DotNetInfo,
+    reference_names: &mut Vec<String>,
+) -> Option<()> {
+    if metadata.pread_with::<u32>(0, LE).ok()? != METADATA_SIGNATURE {
+        return None;
+    }
+    let version_length: u32 = metadata.pread_with(12, LE).ok()?;
+    let version = metadata.get(16..16 + version_length as usize)?;
+    let version_end = version.iter().position(|b| *b == 0).unwrap_or(version.len());
+    info.runtime_version = option_to_c_string(Some(String::from_utf8_lossy(
+        &version[..version_end],
+    )));
+
+    let mut offset = 16 + version_length as usize + 2;
+    let stream_count: u16 = metadata.gread_with(&mut offset, LE).ok()?;
+    let mut heaps = Heaps::default();
+    let mut table_stream = None;
+    for _ in 0..stream_count {
+        let stream_offset: u32 = metadata.gread_with(&mut offset, LE).ok()?;
+        let stream_size: u32 = metadata.gread_with(&mut offset, LE).ok()?;
+        let name_bytes = metadata.get(offset..)?;
+        let name_length = name_bytes.iter().position(|b| *b == 0)?;
+        let name = &name_bytes[..name_length];
+        offset += (name_length + 4) & !3;
+
+        let stream = metadata.get(
+            stream_offset as usize..stream_offset as usize + stream_size as usize,
+        )?;
+        // The uncompressed tables (`#-`) of edit-and-continue builds have the same layout with the Ptr tables in use
+        match name {
+            b"#~" | b"#-" => table_stream = Some(stream),
+            b"#Strings" => heaps.strings = stream,
+            b"#Blob" => heaps.blobs = stream,
+            _ => {}
+        }
+    }
+
+    let tables = Tables::parse(table_stream?)?;
+
+    if let Some(assembly) = tables.row(ASSEMBLY, 1) {
+        info.assembly_name = option_to_c_string(heaps.string(assembly[7]));
+        info.assembly_version = format_version(&assembly[1..5]).to_c_string();
+        info.culture = format_culture(heaps.string(assembly[8])).to_c_string();
+        info.public_key_token = option_to_c_string(
+            heaps
+                .blob(assembly[6])
+                .filter(|key| !key.is_empty())
+                .map(public_key_token),
+        );
+    }
+
+    let references: Vec<String> = (1..=tables.rows[ASSEMBLY_REF])
+        .filter_map(|row| tables.row(ASSEMBLY_REF, row))
+        .map(|reference| {
+            let token = heaps
+                .blob(reference[5])
+                .filter(|key| !key.is_empty())
+                .map(|key| {
+                    if reference[4] as u32 & ASSEMBLY_FLAGS_PUBLIC_KEY != 0 {
+                        public_key_token(key)
+                    } else {
+                        key.iter().map(|b| format!("{:02x}", b)).collect()
+                    }
+                })
+                .unwrap_or("null".to_owned());
+            format!(
+                "{}, Version={}, Culture={}, PublicKeyToken={}",
+                heaps.string(reference[6]).unwrap_or_default(),
+                format_version(&reference[0..4]),
+                format_culture(heaps.string(reference[7])),
+                token
+            )
+        })
+        .collect();
+    info.assembly_references = CharVec::from(references);
+    reference_names.extend(
+        (1..=tables.rows[ASSEMBLY_REF])
+            .filter_map(|row| tables.row(ASSEMBLY_REF, row))
+            .filter_map(|reference| heaps.string(reference[6])),
+    );
+    info.target_framework = option_to_c_string(find_target_framework(&tables, &heaps));
+
+    Some(())
+}
+
+fn read_data_directory(buffer: &[u8], offset: usize) -> Option<DataDirectory> {
+    Some(DataDirectory {
+        virtual_address: buffer.pread_with(offset, LE).ok()?,
+        size: buffer.pread_with(offset + 4, LE).ok()?,
+    })
+}
+
+///
+/// Parses the CLI header and the metadata of .NET assemblies.
+///
+/// The names of the referenced assemblies (Like: System.Runtime) are returned as well to list them with the native libraries.
+///
+pub(crate) fn parse_clr(buffer: &[u8], pe: &PE) -> (DotNetInfo, Vec<String>) {
+    let mut info = DotNetInfo::default();
+    let mut reference_names = vec![];
+    let Some(clr_header) = pe
+        .header
+        .optional_header
+        .and_then(|h| h.data_directories.get_clr_runtime_header().copied())
+    else {
+        return (info, reference_names);
+    };
+    let Some(header_offset) = rva_to_offset(pe, clr_header.virtual_address as usize) else {
+        return (info, reference_names);
+    };
+
+    // Source: ECMA-335 Partition II, 25.3.3 (CLI header)
+    let (Some(metadata), Ok(flags), Some(managed_native_header)) = (
+        read_data_directory(buffer, header_offset + 8),
+        buffer.pread_with::<u32>(header_offset + 16, LE),
+        read_data_directory(buffer, header_offset + 64),
+    ) else {
+        return (info, reference_names);
+    };
+
+    info.is_dotnet = true;
+    info.is_il_only = flags & COMIMAGE_FLAGS_ILONLY != 0;
+    info.requires_32bit = flags & COMIMAGE_FLAGS_32BITREQUIRED != 0
+        && flags & COMIMAGE_FLAGS_32BITPREFERRED == 0;
+    info.is_ready_to_run = managed_native_header.virtual_address != 0
+        && rva_to_offset(pe, managed_native_header.virtual_address as usize)
+            .and_then(|offset| buffer.pread_with::<u32>(offset, LE).ok())
+            == Some(READY_TO_RUN_SIGNATURE);
+
+    if let Some(metadata_offset) = rva_to_offset(pe, metadata.virtual_address as usize) {
+        if let Some(metadata_bytes) =
+            buffer.get(metadata_offset..metadata_offset + metadata.size as usize)
+        {
+            parse_metadata(metadata_bytes, &mut info, &mut reference_names);
+        }
+    }
+
+    (info, reference_names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A table stream with a Module row and an AssemblyRef row whose name is at 0x1234 of the string heap
+    fn table_stream(heap_sizes: u8) -> Vec<u8> {
+        let mut stream = vec![0, 0, 0, 0, 2, 0, heap_sizes, 1];
+        stream.extend(((1_u64 << 0x00) | (1 << ASSEMBLY_REF)).to_le_bytes());
+        stream.extend(0_u64.to_le_bytes());
+        stream.extend(1_u32.to_le_bytes());
+        stream.extend(1_u32.to_le_bytes());
+        if heap_sizes & HEAP_SIZES_EXTRA_DATA != 0 {
+            stream.extend(0xdead_beef_u32.to_le_bytes());
+        }
+        stream.extend([0; 10]);
+        // Version 1.2.3.4, flags, public key, name, culture and hash
+        stream.extend([1, 0, 2, 0, 3, 0, 4, 0]);
+        stream.extend([0, 0, 0, 0, 0, 0, 0x34, 0x12, 0, 0, 0, 0]);
+        stream
+    }
+
+    #[test]
+    fn rows_follow_the_extra_data() {
+        for heap_sizes in [0, HEAP_SIZES_EXTRA_DATA] {
+            let stream = table_stream(heap_sizes);
+            let tables = Tables::parse(&stream).unwrap();
+            let reference = tables.row(ASSEMBLY_REF, 1).unwrap();
+            assert_eq!(reference[..4], [1, 2, 3, 4]);
+            assert_eq!(reference[6], 0x1234);
+        }
+    }
+
+    #[test]
+    fn coded_indexes_grow_with_the_biggest_tagged_table() {
+        let mut tables = Tables {
+            bytes: &[],
+            heap_sizes: 0,
+            rows: [0; TABLE_COUNT],
+            offsets: [0; TABLE_COUNT],
+            row_sizes: [0; TABLE_COUNT],
+        };
+        // ResolutionScope has 2 tag bits and CustomAttributeType has 3
+        tables.rows[ASSEMBLY_REF] = (1 << 14) - 1;
+        assert_eq!(tables.column_size(Coded(RESOLUTION_SCOPE)), 2);
+        tables.rows[ASSEMBLY_REF] = 1 << 14;
+        assert_eq!(tables.column_size(Coded(RESOLUTION_SCOPE)), 4);
+        tables.rows[MEMBER_REF] = (1 << 13) - 1;
+        assert_eq!(tables.column_size(Coded(CUSTOM_ATTRIBUTE_TYPE)), 2);
+        tables.rows[MEMBER_REF] = 1 << 13;
+        assert_eq!(tables.column_size(Coded(CUSTOM_ATTRIBUTE_TYPE)), 4);
+    }
+
+    #[test]
+    fn coded_indexes_decode_to_tables_and_rows() {
+        assert_eq!(
+            decode_coded_index(RESOLUTION_SCOPE, (5 << 2) | 2),
+            (ASSEMBLY_REF, 5)
+        );
+        assert_eq!(
+            decode_coded_index(CUSTOM_ATTRIBUTE_TYPE, (7 << 3) | 3),
+            (MEMBER_REF, 7)
+        );
+        assert_eq!(
+            decode_coded_index(HAS_CUSTOM_ATTRIBUTE, (1 << 5) | 14),
+            (ASSEMBLY, 1)
+        );
+        // Unused tags don't name a table
+        assert_eq!(decode_coded_index(CUSTOM_ATTRIBUTE_TYPE, 0).0, usize::MAX);
+    }
+}
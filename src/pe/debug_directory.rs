@@ -0,0 +1,200 @@
+use crate::{
+    debug::{option_to_c_string, read_c_str, to_hex},
+    pe::rva_to_offset,
+    structs::{CharVec, PeDebugInfo},
+    types::{
+        DebugInfoFormat, DebugInfoLocation, PeDebugType, PE_DEBUG_TYPE, PE_EX_DLL_CHARACTERISTICS,
+    },
+};
+use goblin::pe::{debug::ImageDebugDirectory, PE};
+use scroll::{Pread, LE};
+
+// Source: https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#debug-directory-image-only
+const SIZEOF_IMAGE_DEBUG_DIRECTORY: usize = 28;
+
+const CODEVIEW_RSDS_MAGIC: &[u8; 4] = b"RSDS";
+const CODEVIEW_NB10_MAGIC: &[u8; 4] = b"NB10";
+
+// Source: The names printed by `dumpbin /headers` for the VC_FEATURE entry
+const VC_FEATURE_NAMES: [&str; 5] = ["Pre-VC++ 11.00", "C/C++", "/GS", "/sdl", "guardN"];
+
+/// Everything found in the debug directory. `ULDDObj` only gets the C side of it, the rest is used to classify stripping.
+#[derive(Default)]
+pub(crate) struct DebugDirectory {
+    pub(crate) types: Vec<u32>,
+    pub(crate) pdb_path: Option<String>,
+    pub(crate) pdb_guid: Option<String>,
+    pub(crate) pdb_age: u32,
+    pub(crate) pogo_sections: Vec<String>,
+    pub(crate) vc_features: Vec<String>,
+    pub(crate) repro_hash: Option<String>,
+    pub(crate) ex_dll_characteristics: Vec<String>,
+}
+
+impl DebugDirectory {
+    /// CodeView entries point to PDB files, COFF and MISC entries carry the old style symbols embedded in the image.
+    pub(crate) fn debug_info(&self) -> Vec<(DebugInfoFormat, DebugInfoLocation)> {
+        self.types
+            .iter()
+            .filter_map(|t| match PE_DEBUG_TYPE.get(t) {
+                Some(PeDebugType::CodeView) => {
+                    Some((DebugInfoFormat::PDB, DebugInfoLocation::Split))
+                }
+                Some(PeDebugType::EmbeddedPortablePDB) => {
+                    Some((DebugInfoFormat::PDB, DebugInfoLocation::Embedded))
+                }
+                Some(PeDebugType::COFF | PeDebugType::Misc) => {
+                    Some((DebugInfoFormat::COFF, DebugInfoLocation::Embedded))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub(crate) fn to_pe_debug_info(&self) -> PeDebugInfo {
+        let entries: Vec<String> = self
+            .types
+            .iter()
+            .map(|t| match PE_DEBUG_TYPE.get(t) {
+                Some(debug_type) => debug_type.to_string(),
+                None => format!("Unknown ({:#x})", t),
+            })
+            .collect();
+
+        PeDebugInfo {
+            entries: CharVec::from(entries),
+            pdb_path: option_to_c_string(self.pdb_path.as_ref()),
+            pdb_guid: option_to_c_string(self.pdb_guid.as_ref()),
+            pdb_age: self.pdb_age,
+            pogo_sections: CharVec::from(self.pogo_sections.clone()),
+            vc_features: CharVec::from(self.vc_features.clone()),
+            repro_hash: option_to_c_string(self.repro_hash.as_ref()),
+            ex_dll_characteristics: CharVec::from(self.ex_dll_characteristics.clone()),
+        }
+    }
+}
+
+// Source: https://github.com/dotnet/runtime/blob/main/docs/design/specs/PE-COFF.md#codeview-debug-directory-entry-type-2
+fn parse_codeview(data: &[u8], directory: &mut DebugDirectory) {
+    match data.get(0..4) {
+        Some(magic) if magic == CODEVIEW_RSDS_MAGIC && data.len() >= 24 => {
+            let guid = &data[4..20];
+            let (Ok(d1), Ok(d2), Ok(d3)) = (
+                guid.pread_with::<u32>(0, LE),
+                guid.pread_with::<u16>(4, LE),
+                guid.pread_with::<u16>(6, LE),
+            ) else {
+                return;
+            };
+            directory.pdb_guid = Some(format!(
+                "{{{:08X}-{:04X}-{:04X}-{}-{}}}",
+                d1,
+                d2,
+                d3,
+                to_hex(&guid[8..10]).to_uppercase(),
+                to_hex(&guid[10..16]).to_uppercase()
+            ));
+            directory.pdb_age = data.pread_with::<u32>(20, LE).unwrap_or(0);
+            directory.pdb_path = Some(read_c_str(&data[24..]));
+        }
+        Some(magic) if magic == CODEVIEW_NB10_MAGIC && data.len() >= 16 => {
+            directory.pdb_guid = data
+                .pread_with::<u32>(8, LE)
+                .ok()
+                .map(|signature| format!("{:08X}", signature));
+            directory.pdb_age = data.pread_with::<u32>(12, LE).unwrap_or(0);
+            directory.pdb_path = Some(read_c_str(&data[16..]));
+        }
+        _ => {}
+    }
+}
+
+// POGO data is a signature followed by (rva: u32, size: u32, name: 4-byte aligned C string) records
+fn parse_pogo(data: &[u8], directory: &mut DebugDirectory) {
+    let mut offset = 4;
+    while offset + 8 < data.len() {
+        let name_start = offset + 8;
+        let Some(name_length) = data[name_start..].iter().position(|b| *b == 0) else {
+            break;
+        };
+        let name = read_c_str(&data[name_start..]);
+        if !name.is_empty() {
+            directory.pogo_sections.push(name);
+        }
+        offset = (name_start + name_length + 1 + 3) & !3;
+    }
+}
+
+fn parse_vc_feature(data: &[u8], directory: &mut DebugDirectory) {
+    for (index, name) in VC_FEATURE_NAMES.iter().enumerate() {
+        if let Ok(count) = data.pread_with::<u32>(index * 4, LE) {
+            directory.vc_features.push(format!("{}: {}", name, count));
+        }
+    }
+}
+
+// REPRO data is either empty (only the timestamp is replaced) or a length-prefixed hash
+fn parse_repro(data: &[u8], directory: &mut DebugDirectory) {
+    if let Ok(length) = data.pread_with::<u32>(0, LE) {
+        let hash = (length as usize)
+            .checked_add(4)
+            .and_then(|end| data.get(4..end));
+        if let Some(hash) = hash {
+            directory.repro_hash = Some(to_hex(hash));
+        }
+    }
+}
+
+fn parse_ex_dll_characteristics(data: &[u8], directory: &mut DebugDirectory) {
+    if let Ok(flags) = data.pread_with::<u32>(0, LE) {
+        directory.ex_dll_characteristics = (0..u32::BITS)
+            .map(|bit| 1 << bit)
+            .filter(|flag| flags & flag != 0)
+            .filter_map(|flag| PE_EX_DLL_CHARACTERISTICS.get(&flag))
+            .map(|characteristic| characteristic.to_string())
+            .collect();
+    }
+}
+
+pub(crate) fn parse_debug_directory(buffer: &[u8], pe: &PE) -> DebugDirectory {
+    let mut directory = DebugDirectory::default();
+    let Some(debug_table) = pe
+        .header
+        .optional_header
+        .and_then(|h| h.data_directories.get_debug_table().copied())
+    else {
+        return directory;
+    };
+    let Some(table_offset) = rva_to_offset(pe, debug_table.virtual_address as usize) else {
+        return directory;
+    };
+
+    let count = debug_table.size as usize / SIZEOF_IMAGE_DEBUG_DIRECTORY;
+    for index in 0..count {
+        let Ok(entry) = buffer.pread_with::<ImageDebugDirectory>(
+            table_offset + index * SIZEOF_IMAGE_DEBUG_DIRECTORY,
+            LE,
+        ) else {
+            break;
+        };
+        directory.types.push(entry.data_type);
+
+        let start = entry.pointer_to_raw_data as usize;
+        let Some(data) = buffer.get(start..start.saturating_add(entry.size_of_data as usize))
+        else {
+            continue;
+        };
+        match PE_DEBUG_TYPE.get(&entry.data_type) {
+            Some(PeDebugType::CodeView) => parse_codeview(data, &mut directory),
+            Some(PeDebugType::POGO) => parse_pogo(data, &mut directory),
+            Some(PeDebugType::VCFeature) => parse_vc_feature(data, &mut directory),
+            Some(PeDebugType::Repro) => parse_repro(data, &mut directory),
+            Some(PeDebugType::ExDllCharacteristics) => {
+                parse_ex_dll_characteristics(data, &mut directory)
+            }
+            _ => {}
+        }
+    }
+
+    directory
+}
@@ -0,0 +1,92 @@
+use crate::{
+    debug::hash_names,
+    types::{OLEAUT32_ORDINALS, WS2_32_ORDINALS},
+};
+use goblin::pe::{import::SyntheticImportLookupTableEntry, PE};
+
+// Source: https://github.com/erocarrera/pefile/blob/master/pefile.py (PE.get_imphash)
+const STRIPPED_EXTENSIONS: [&str; 3] = ["ocx", "sys", "dll"];
+
+fn lookup_ordinal(dll: &str, ordinal: u16) -> String {
+    let name = match dll {
+        "ws2_32.dll" | "wsock32.dll" => WS2_32_ORDINALS.get(&ordinal),
+        "oleaut32.dll" => OLEAUT32_ORDINALS.get(&ordinal),
+        _ => None,
+    };
+
+    name.map(|n| n.to_string())
+        .unwrap_or_else(|| format!("ord{}", ordinal))
+}
+
+/// Calculates the imphash of the object. It is a null pointer if the object doesn't import anything.
+pub(crate) fn imphash(pe: &PE) -> Option<String> {
+    let directories = pe.import_data.iter().flat_map(|data| &data.import_data);
+    let mut imports: Vec<String> = vec![];
+    for directory in directories {
+        let dll = directory.name.to_lowercase();
+        let library = match dll.rsplit_once('.') {
+            Some((name, extension)) if STRIPPED_EXTENSIONS.contains(&extension) => name,
+            _ => &dll,
+        };
+        // The entries of the lookup table tell the ordinal imports by their flag, not by their names
+        for entry in directory.import_lookup_table.iter().flatten() {
+            let function = match entry {
+                SyntheticImportLookupTableEntry::OrdinalNumber(ordinal) => {
+                    lookup_ordinal(&dll, *ordinal)
+                }
+                SyntheticImportLookupTableEntry::HintNameTableRVA((_, hint_entry)) => {
+                    hint_entry.name.to_owned()
+                }
+            };
+            imports.push(format!("{}.{}", library, function.to_lowercase()));
+        }
+    }
+
+    if imports.is_empty() {
+        None
+    } else {
+        Some(hash_names(&imports))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::pe;
+
+    // A PE32+ image which imports the ordinal 2 of oleaut32.dll and a function named "ORDINAL 5" from it
+    fn image() -> Vec<u8> {
+        let mut section = vec![0; 0x200];
+        // The import descriptor, the name of the library, the lookup table and the hint/name entry
+        section[0..4].copy_from_slice(&0x1100_u32.to_le_bytes());
+        section[0xc..0x10].copy_from_slice(&0x1080_u32.to_le_bytes());
+        section[0x10..0x14].copy_from_slice(&0x1100_u32.to_le_bytes());
+        section[0x80..0x8d].copy_from_slice(b"oleaut32.dll\0");
+        section[0x100..0x108].copy_from_slice(&(1_u64 << 63 | 2).to_le_bytes());
+        section[0x108..0x110].copy_from_slice(&0x1180_u64.to_le_bytes());
+        section[0x182..0x18c].copy_from_slice(b"ORDINAL 5\0");
+
+        pe(0x8664, true, &[(1, 0x1000, 40)], &section)
+    }
+
+    #[test]
+    fn ordinals_are_told_by_their_flag() {
+        let image = image();
+        let pe = PE::parse(&image).unwrap();
+
+        assert_eq!(
+            imphash(&pe),
+            Some(hash_names(&[
+                "oleaut32.sysallocstring",
+                "oleaut32.ordinal 5"
+            ]))
+        );
+    }
+
+    #[test]
+    fn unknown_ordinals_are_named_like_pefile() {
+        assert_eq!(lookup_ordinal("oleaut32.dll", 2), "SysAllocString");
+        assert_eq!(lookup_ordinal("ws2_32.dll", 23), "socket");
+        assert_eq!(lookup_ordinal("kernel32.dll", 7), "ord7");
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{
+    debug::option_to_c_string,
+    pe::rva_to_offset,
+    structs::{CharVec, PeLoadInfo, U64Vec},
+    types::PE_GUARD_FLAGS,
+};
+use goblin::pe::PE;
+use scroll::{Pread, LE};
+
+/*
+Sources:
+    https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#the-tls-section
+    https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#load-configuration-layout
+    https://learn.microsoft.com/en-us/windows/arm/arm64ec-abi
+*/
+
+const IMAGE_FILE_MACHINE_I386: u16 = 0x014C;
+const IMAGE_FILE_MACHINE_AMD64: u16 = 0x8664;
+const IMAGE_FILE_MACHINE_ARM64: u16 = 0xAA64;
+
+// A malformed TLS directory shouldn't make us read the whole file as callbacks
+const MAX_TLS_CALLBACKS: usize = 256;
+
+/// Offsets of the load configuration fields for 32 and 64 bit objects.
+struct LoadConfigLayout {
+    security_cookie: usize,
+    se_handler_table: usize,
+    se_handler_count: usize,
+    guard_flags: usize,
+    chpe_metadata: usize,
+}
+
+const LOAD_CONFIG_32: LoadConfigLayout = LoadConfigLayout {
+    security_cookie: 60,
+    se_handler_table: 64,
+    se_handler_count: 68,
+    guard_flags: 88,
+    chpe_metadata: 124,
+};
+
+const LOAD_CONFIG_64: LoadConfigLayout = LoadConfigLayout {
+    security_cookie: 88,
+    se_handler_table: 96,
+    se_handler_count: 104,
+    guard_flags: 144,
+    chpe_metadata: 200,
+};
+
+fn read_pointer(buffer: &[u8], offset: usize, is_64: bool) -> Option<u64> {
+    if is_64 {
+        buffer.pread_with::<u64>(offset, LE).ok()
+    } else {
+        buffer.pread_with::<u32>(offset, LE).ok().map(u64::from)
+    }
+}
+
+fn find_section_name(pe: &PE, rva: u64) -> Option<String> {
+    pe.sections
+        .iter()
+        .find(|s| {
+            let start = s.virtual_address as u64;
+            let size = s.virtual_size.max(s.size_of_raw_data) as u64;
+            (start..start + size).contains(&rva)
+        })
+        .map(|s| s.name().unwrap_or_default().to_owned())
+}
+
+fn va_to_rva(pe: &PE, va: u64) -> Option<u64> {
+    va.checked_sub(pe.image_base as u64)
+}
+
+fn parse_tls_callbacks(buffer: &[u8], pe: &PE) -> Vec<u64> {
+    let Some(tls_table) = pe
+        .header
+        .optional_header
+        .and_then(|h| h.data_directories.get_tls_table().copied())
+    else {
+        return vec![];
+    };
+    let Some(tls_offset) = rva_to_offset(pe, tls_table.virtual_address as usize) else {
+        return vec![];
+    };
+
+    // AddressOfCallBacks comes after StartAddressOfRawData, EndAddressOfRawData and AddressOfIndex
+    let pointer_size = if pe.is_64 { 8 } else { 4 };
+    let Some(callbacks_offset) = read_pointer(buffer, tls_offset + 3 * pointer_size, pe.is_64)
+        .and_then(|va| va_to_rva(pe, va))
+        .and_then(|rva| rva_to_offset(pe, rva as usize))
+    else {
+        return vec![];
+    };
+
+    (0..MAX_TLS_CALLBACKS)
+        .map_while(|index| read_pointer(buffer, callbacks_offset + index * pointer_size, pe.is_64))
+        .take_while(|va| *va != 0)
+        .filter_map(|va| va_to_rva(pe, va))
+        .collect()
+}
+
+fn parse_load_config(buffer: &[u8], pe: &PE, info: &mut PeLoadInfo) {
+    let Some(load_config_table) = pe
+        .header
+        .optional_header
+        .and_then(|h| h.data_directories.get_load_config_table().copied())
+    else {
+        return;
+    };
+    let Some(offset) = rva_to_offset(pe, load_config_table.virtual_address as usize) else {
+        return;
+    };
+    let Ok(size) = buffer.pread_with::<u32>(offset, LE) else {
+        return;
+    };
+
+    // The structure grew with every Windows release. The fields after the size written into it are absent.
+    let (layout, pointer_size) = if pe.is_64 {
+        (LOAD_CONFIG_64, 8)
+    } else {
+        (LOAD_CONFIG_32, 4)
+    };
+    let read_field = |field_offset: usize, field_size: usize| {
+        if field_offset + field_size > size as usize {
+            return None;
+        }
+        match field_size {
+            4 => buffer
+                .pread_with::<u32>(offset + field_offset, LE)
+                .ok()
+                .map(u64::from),
+            _ => buffer.pread_with::<u64>(offset + field_offset, LE).ok(),
+        }
+    };
+
+    info.security_cookie = read_field(layout.security_cookie, pointer_size).unwrap_or(0);
+    info.se_handler_table = read_field(layout.se_handler_table, pointer_size).unwrap_or(0);
+    info.se_handler_count = read_field(layout.se_handler_count, pointer_size).unwrap_or(0);
+    info.chpe_metadata = read_field(layout.chpe_metadata, pointer_size).unwrap_or(0);
+
+    if let Some(guard_flags) = read_field(layout.guard_flags, 4) {
+        let flags: Vec<String> = (0..u32::BITS)
+            .map(|bit| 1 << bit)
+            .filter(|flag| guard_flags as u32 & flag != 0)
+            .filter_map(|flag| PE_GUARD_FLAGS.get(&flag))
+            .map(|flag| flag.to_string())
+            .collect();
+        info.guard_flags = CharVec::from(flags);
+    }
+
+    if info.chpe_metadata != 0 {
+        let hybrid_kind = match pe.header.coff_header.machine {
+            IMAGE_FILE_MACHINE_ARM64 => Some("ARM64X"),
+            IMAGE_FILE_MACHINE_AMD64 => Some("ARM64EC"),
+            IMAGE_FILE_MACHINE_I386 => Some("CHPE (x86 on ARM64)"),
+            _ => None,
+        };
+        info.hybrid_kind = option_to_c_string(hybrid_kind);
+    }
+}
+
+pub(crate) fn parse_load_info(buffer: &[u8], pe: &PE) -> PeLoadInfo {
+    let entry_point = pe.entry as u64;
+    let mut info = PeLoadInfo {
+        entry_point,
+        // The DLLs without an entry point (Like: resource-only DLLs) have 0 as the entry point
+        entry_point_section: option_to_c_string(
+            (entry_point != 0)
+                .then(|| find_section_name(pe, entry_point))
+                .flatten(),
+        ),
+        tls_callbacks: U64Vec::from(parse_tls_callbacks(buffer, pe)),
+        exception_directory_size: pe
+            .header
+            .optional_header
+            .and_then(|h| h.data_directories.get_exception_table().map(|dd| dd.size))
+            .unwrap_or(0),
+        ..Default::default()
+    };
+    parse_load_config(buffer, pe, &mut info);
+
+    info
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{pe, read_string, read_strings};
+
+    const LOAD_CONFIG_TABLE: usize = 10;
+
+    // The load configuration is at the start of the section and the fields which are read are set to their offsets
+    fn load_info(machine: u16, is_64: bool, size: u32) -> PeLoadInfo {
+        let layout = if is_64 {
+            LOAD_CONFIG_64
+        } else {
+            LOAD_CONFIG_32
+        };
+        let mut section = vec![0; 0x200];
+        section[..4].copy_from_slice(&size.to_le_bytes());
+        for offset in [
+            layout.security_cookie,
+            layout.se_handler_table,
+            layout.se_handler_count,
+            layout.chpe_metadata,
+        ] {
+            section[offset..offset + 4].copy_from_slice(&(offset as u32).to_le_bytes());
+        }
+        // CF_INSTRUMENTED and CF_FUNCTION_TABLE_PRESENT
+        section[layout.guard_flags..layout.guard_flags + 4]
+            .copy_from_slice(&0x500_u32.to_le_bytes());
+        let image = pe(
+            machine,
+            is_64,
+            &[(LOAD_CONFIG_TABLE, 0x1000, size)],
+            &section,
+        );
+
+        parse_load_info(&image, &PE::parse(&image).unwrap())
+    }
+
+    #[test]
+    fn fields_of_32_bit_load_configs() {
+        let info = load_info(IMAGE_FILE_MACHINE_I386, false, 0xc0);
+
+        assert_eq!(
+            (
+                info.security_cookie,
+                info.se_handler_table,
+                info.se_handler_count,
+                info.chpe_metadata
+            ),
+            (60, 64, 68, 124)
+        );
+        assert_eq!(
+            read_strings(&info.guard_flags),
+            ["CF instrumented", "CF function table present"]
+        );
+        assert_eq!(
+            read_string(info.hybrid_kind).as_deref(),
+            Some("CHPE (x86 on ARM64)")
+        );
+    }
+
+    #[test]
+    fn fields_of_64_bit_load_configs() {
+        let info = load_info(IMAGE_FILE_MACHINE_AMD64, true, 0x140);
+
+        assert_eq!(
+            (
+                info.security_cookie,
+                info.se_handler_table,
+                info.se_handler_count,
+                info.chpe_metadata
+            ),
+            (88, 96, 104, 200)
+        );
+        assert_eq!(read_strings(&info.guard_flags).len(), 2);
+        assert_eq!(read_string(info.hybrid_kind).as_deref(), Some("ARM64EC"));
+    }
+
+    #[test]
+    fn fields_after_the_size_are_absent() {
+        // The size ends right after GuardFlags, so CHPEMetadataPointer is not read
+        let info = load_info(IMAGE_FILE_MACHINE_ARM64, true, 148);
+
+        assert_eq!((info.security_cookie, info.chpe_metadata), (88, 0));
+        assert_eq!(read_strings(&info.guard_flags).len(), 2);
+        assert!(info.hybrid_kind.is_null());
+    }
+}
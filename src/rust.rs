@@ -0,0 +1,199 @@
+use crate::{
+    debug::option_to_c_string,
+    structs::{CharVec, RustInfo},
+    types::RustMangling,
+};
+use miniz_oxide::inflate::decompress_to_vec_zlib_with_limit;
+use serde_json::Value;
+
+/*
+Sources:
+    https://github.com/rust-secure-code/cargo-auditable/blob/master/PARSING.md
+    https://doc.rust-lang.org/rustc/symbol-mangling/v0.html
+    https://github.com/rust-lang/rustc-demangle/blob/main/src/legacy.rs
+    https://github.com/rust-lang/rust/blob/master/compiler/rustc_codegen_ssa/src/back/metadata.rs
+*/
+
+/// The name of the section cargo-auditable writes the dependency list into.
+pub(crate) const AUDITABLE_SECTION: &str = ".dep-v0";
+// The format is a zlib-compressed JSON, so the decompressed size is limited against zip bombs
+const AUDITABLE_SIZE_LIMIT: usize = 8 * 1024 * 1024;
+const RUSTC_VERSION_PREFIX: &str = "rustc version ";
+// Panic locations in the standard library are paths like /rustc/<commit hash>/library/core/src/...
+const RUSTC_PATH: &[u8] = b"/rustc/";
+const RUSTC_COMMIT_LENGTH: usize = 40;
+const PANIC_STRINGS: [&[u8]; 2] = [
+    b"called `Option::unwrap()` on a `None` value",
+    b"called `Result::unwrap()` on an `Err` value",
+];
+// "17h" + 16 hex digits + "E"
+const LEGACY_HASH_SUFFIX_LENGTH: usize = 20;
+// The symbols of the panic runtime of the standard library, which are not mangled
+const RUST_SYMBOLS: [&str; 2] = ["rust_begin_unwind", "rust_panic"];
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn is_legacy_symbol(name: &str) -> bool {
+    // Mach-O adds an underscore in front of the names
+    let name = name.trim_start_matches('_').as_bytes();
+    if !name.starts_with(b"ZN") || name.len() < 2 + LEGACY_HASH_SUFFIX_LENGTH {
+        return false;
+    }
+    let suffix = &name[name.len() - LEGACY_HASH_SUFFIX_LENGTH..];
+
+    suffix.starts_with(b"17h")
+        && suffix.ends_with(b"E")
+        && suffix[3..19].iter().all(|b| b.is_ascii_hexdigit())
+}
+
+fn is_v0_symbol(name: &str) -> bool {
+    let Some(name) = name
+        .strip_prefix("__R")
+        .or_else(|| name.strip_prefix("_R"))
+    else {
+        return false;
+    };
+    let name = name.as_bytes();
+
+    // The path of a symbol starts with one of these tags and every path ends at the crate root which contains digits
+    name.first().is_some_and(|b| b"NCMXYIB".contains(b))
+        && name.iter().any(|b| b.is_ascii_digit())
+        && name.iter().all(|b| b.is_ascii_alphanumeric() || *b == b'_')
+}
+
+// Returns the mangling scheme and whether the symbols of the panic runtime are found
+fn find_mangling<'a>(symbols: impl Iterator<Item = &'a str>) -> (Option<RustMangling>, bool) {
+    let (mut legacy, mut v0, mut has_runtime) = (false, false, false);
+    for name in symbols {
+        legacy |= is_legacy_symbol(name);
+        v0 |= is_v0_symbol(name);
+        has_runtime |= RUST_SYMBOLS.contains(&name.trim_start_matches('_'));
+    }
+
+    let mangling = match (legacy, v0) {
+        (true, true) => Some(RustMangling::Mixed),
+        (true, false) => Some(RustMangling::Legacy),
+        (false, true) => Some(RustMangling::V0),
+        _ => None,
+    };
+    (mangling, has_runtime)
+}
+
+fn find_rustc_commit(data: &[u8]) -> Option<String> {
+    let mut rest = data;
+    while let Some(position) = find_bytes(rest, RUSTC_PATH) {
+        let path = &rest[position + RUSTC_PATH.len()..];
+        if let Some(hash) = path.get(..RUSTC_COMMIT_LENGTH) {
+            if hash.iter().all(|b| b.is_ascii_hexdigit())
+                && matches!(path.get(RUSTC_COMMIT_LENGTH), Some(b'/' | b'\\'))
+            {
+                return Some(String::from_utf8_lossy(hash).into_owned());
+            }
+        }
+        rest = &rest[position + 1..];
+    }
+
+    None
+}
+
+// Returns the root crate and the other crates as "name version (source, kind)" strings
+fn parse_dependencies(compressed: &[u8]) -> Option<(Option<String>, Vec<String>)> {
+    let json = decompress_to_vec_zlib_with_limit(compressed, AUDITABLE_SIZE_LIMIT).ok()?;
+    let value: Value = serde_json::from_slice(&json).ok()?;
+
+    let mut root = None;
+    let mut crates = vec![];
+    for package in value.get("packages")?.as_array()? {
+        // JSON strings may have escaped NUL bytes ("\u0000"), so they are cut there like C strings
+        let field = |key: &str| {
+            package
+                .get(key)
+                .and_then(Value::as_str)
+                .and_then(|value| value.split('\0').next())
+        };
+        let (Some(name), Some(version)) = (field("name"), field("version")) else {
+            continue;
+        };
+        if package.get("root").and_then(Value::as_bool) == Some(true) {
+            root = Some(format!("{} {}", name, version));
+            continue;
+        }
+        // Packages are runtime dependencies unless the kind says otherwise
+        let details: Vec<&str> = [field("source"), field("kind").filter(|k| *k != "runtime")]
+            .into_iter()
+            .flatten()
+            .collect();
+        if details.is_empty() {
+            crates.push(format!("{} {}", name, version));
+        } else {
+            crates.push(format!("{} {} ({})", name, version, details.join(", ")));
+        }
+    }
+
+    Some((root, crates))
+}
+
+///
+/// Looks for the traces rustc leaves in the object.
+///
+/// - `comment`: The entries of `.comment` (ELF only)
+/// - `has_metadata`: The object has the `.rustc` section which Rust dylibs carry their metadata in
+/// - `symbols`: The names of the symbols
+/// - `dependencies`: The contents of the `.dep-v0` section
+/// - `data`: The read-only data sections which are searched for the panic strings and the paths of the standard library
+///
+/// It is the only Rust detector, so the "Rust" entry of the toolchain follows `is_rust`.
+///
+pub(crate) fn parse_rust<'a>(
+    comment: Option<&[u8]>,
+    has_metadata: bool,
+    symbols: impl Iterator<Item = &'a str>,
+    dependencies: Option<&[u8]>,
+    data: &[&[u8]],
+) -> RustInfo {
+    let rustc_version = comment.and_then(|comment| {
+        comment.split(|b| *b == 0).find_map(|entry| {
+            let entry = String::from_utf8_lossy(entry);
+            entry
+                .strip_prefix(RUSTC_VERSION_PREFIX)
+                .map(|version| version.to_owned())
+        })
+    });
+    let (mangling, has_runtime) = find_mangling(symbols);
+    let (root_crate, crates) = dependencies
+        .and_then(parse_dependencies)
+        .unwrap_or_default();
+    let rustc_commit = data.iter().find_map(|data| find_rustc_commit(data));
+    let has_panic_strings = || {
+        data.iter().any(|data| {
+            PANIC_STRINGS
+                .iter()
+                .any(|string| find_bytes(data, string).is_some())
+        })
+    };
+
+    let is_rust = rustc_version.is_some()
+        || has_metadata
+        || mangling.is_some()
+        || has_runtime
+        || root_crate.is_some()
+        || !crates.is_empty()
+        || rustc_commit.is_some()
+        || has_panic_strings();
+    if !is_rust {
+        return RustInfo::default();
+    }
+
+    RustInfo {
+        is_rust,
+        rustc_version: option_to_c_string(rustc_version),
+        rustc_commit: option_to_c_string(rustc_commit),
+        mangling: option_to_c_string(mangling),
+        root_crate: option_to_c_string(root_crate),
+        dependencies: CharVec::from(crates),
+    }
+}
@@ -8,6 +8,14 @@ pub struct CharVec {
     pub vec: *mut *mut c_char,
 }
 
+/// A C-compatible vector for `Vec<u64>`.
+#[repr(C)]
+pub struct U64Vec {
+    pub capacity: usize,
+    pub length: usize,
+    pub vec: *mut u64,
+}
+
 ///
 /// An error struct for making error handling easy.
 ///
@@ -64,13 +72,17 @@ pub struct ULDDObj {
     pub os_type: *mut c_char,
     /// The type of the object.
     pub file_type: *mut c_char,
-    /// The field is true if the object was stripped from debug symbols otherwise it is not stripped or the object is an erroneous one .
+    /// The field is true if the object has neither a symbol table nor debug information otherwise it is not stripped or the object is an erroneous one.
+    ///
+    /// See `stripping` for the details.
     pub is_stripped: bool,
     /// The ISA (CPU Architecture) the object compiled for.
     pub cpu_type: *mut c_char,
     /// The specific CPU model the object compiled for.
     ///
-    /// macOS only field. It is null pointer in other executable formats.
+    /// macOS and XCOFF only field. It is null pointer in other executable formats.
+    ///
+    /// It returns the CPU type in the auxiliary header of XCOFF objects (Like: POWER7).
     pub cpu_subtype: *mut c_char,
     /// The name/version of the linker.
     ///
@@ -81,7 +93,658 @@ pub struct ULDDObj {
     /// A vector of libraries linked against the object.
     ///
     /// It is blank in COFF files because they are mostly PE object files therefore they don't have linked libraries against them.
+    ///
+    /// The names of the assemblies which .NET assemblies reference (Like: System.Runtime) follow their native libraries. Their display names are in `dotnet`.
     pub libraries: CharVec,
+    /// The information decoded from the debug directory of the object.
+    ///
+    /// PE only field. It is blank in other executable formats.
+    pub pe_debug: PeDebugInfo,
+    /// The metadata of .NET assemblies.
+    ///
+    /// PE only field. It is blank in other executable formats and native PE files.
+    pub dotnet: DotNetInfo,
+    /// The information about how the object is loaded and started (entry point, TLS callbacks and load configuration).
+    ///
+    /// PE only field. It is blank in other executable formats.
+    pub pe_load: PeLoadInfo,
+    /// The results of the overlay, checksum and section heuristics.
+    ///
+    /// PE only field. It is blank in other executable formats.
+    pub pe_anomalies: PeAnomalies,
+    /// The MD5 hash of the imports of the object, which is used to cluster similar objects.
+    ///
+    /// - PE: imphash (compatible with pefile)
+    /// - Mach-O: symhash (undefined external symbols)
+    /// - ELF: Sorted names of the undefined dynamic symbols
+    ///
+    /// It is a null pointer if the object doesn't import anything or the executable format is not one of the above.
+    pub import_hash: *mut c_char,
+    /// The minimum version of the OS the object requires, if the object records it.
+    ///
+    /// - ELF: The minimum kernel version in `NT_GNU_ABI_TAG`, the API level in the Android note or the OS version in the BSD ident notes
+    /// - WebAssembly: The version of the WASI interfaces the component imports (Like: 0.2.0)
+    /// - NE: The expected version of Windows (Like: 3.10)
+    ///
+    /// It is a null pointer if the object doesn't record it or the executable format is not one of the above.
+    pub os_version: *mut c_char,
+    /// The build ID and the links to the separate debug files.
+    ///
+    /// ELF only field. It is blank in other executable formats.
+    pub elf_debug: ElfDebugInfo,
+    /// The classification of the symbols and the debug information left in the object.
+    pub stripping: StrippingInfo,
+    /// The information about the crashed process.
+    ///
+    /// ELF core dump only field. It is blank in other objects.
+    pub core: CoreDumpInfo,
+    /// The metadata of the kernel module.
+    ///
+    /// Linux kernel module only field. It is blank in other objects. The modules it depends on are listed in `libraries`.
+    pub kernel_module: KernelModuleInfo,
+    /// The compilers, linkers and other tools which built the object, with their versions and options when recorded.
+    ///
+    /// - ELF: `.comment`, `DW_AT_producer` of the compilation units, `.GCC.command.line`, `.note.gnu.property` and the Go/Rust markers (with the Go version in `go`)
+    /// - WebAssembly: The `producers` custom section as "field: name version" strings (Like: processed-by: rustc 1.80.1)
+    ///
+    /// It is empty if the object doesn't record them or the executable format is not one of the above.
+    pub toolchain: CharVec,
+    /// The build information embedded by the Go linker.
+    ///
+    /// ELF, PE and Mach-O only field. It is blank in other executable formats and binaries not built by Go.
+    pub go: GoBuildInfo,
+    /// The traces rustc and cargo-auditable leave in the object.
+    ///
+    /// ELF, PE and Mach-O only field. It is blank in other executable formats and objects not built by rustc.
+    pub rust: RustInfo,
+    /// The entries of the dynamic section other than the needed libraries (which are listed in `libraries`).
+    ///
+    /// ELF only field. It is blank in other executable formats and statically linked objects.
+    pub elf_dynamic: ElfDynamicInfo,
+    /// The sections of the object.
+    ///
+    /// - ELF: Section headers
+    /// - PE and COFF: Section table
+    /// - Mach-O: Sections of all segments
+    ///
+    /// It is only filled if `list_sections` of [`ULDDOptions`] is true.
+    pub sections: SectionInfoVec,
+    /// The segments of the object.
+    ///
+    /// - ELF: Program headers
+    /// - Mach-O: Segments
+    /// - NE: Segment table
+    /// - LE and LX: Object table
+    ///
+    /// It is only filled if `list_sections` of [`ULDDOptions`] is true.
+    pub segments: SectionInfoVec,
+    /// The imports, exports, limits and custom sections of the WebAssembly module or component.
+    ///
+    /// WebAssembly only field. It is blank in other executable formats. The imported modules (or the interfaces of components) are listed in `libraries`.
+    pub wasm: WasmInfo,
+    /// The auxiliary header and the loader section of the XCOFF object.
+    ///
+    /// XCOFF only field. It is blank in other executable formats. The imported libraries are listed in `libraries`.
+    pub xcoff: XcoffInfo,
+    /// The headers of MS-DOS executables and the executables which are behind the MZ stub of MS-DOS, except PE.
+    ///
+    /// MS-DOS, NE, LE and LX only field. It is blank in other executable formats. The imported modules are listed in `libraries` and the segments (or the objects) are listed in `segments`.
+    pub mz: MzInfo,
+    /// The header of the a.out executable or object.
+    ///
+    /// a.out only field. It is blank in other executable formats. `os_type` is guessed from the byte order of the header, the magic number and the machine ID.
+    pub aout: AoutInfo,
+    /// The version, the names and the access flags of the Java class file.
+    ///
+    /// Java class file only field. It is blank in other executable formats. JARs are not objects themselves, their native libraries are parsed as members instead.
+    pub java: JavaInfo,
+    /// The version and the sizes of the DEX file, or the version and the instruction set of the OAT or VDEX file.
+    ///
+    /// DEX, VDEX and OAT only field. It is blank in other executable formats. OAT files are ELF shared objects which export `oatdata`, so their `os_type` is Android.
+    pub dex: DexInfo,
+    /// The metadata of the package which the object was installed from.
+    ///
+    /// It is blank if the object is not in a `.deb` or `.rpm` package. The objects in packages are named with the paths they are installed to (Like: /usr/bin/ls). The packages are given as objects as well,
+    /// which only have `file_name`, `member_name`, `file_type` (Debian package or RPM package) and this field, so their metadata is given even if they have no objects.
+    pub package: PackageInfo,
+}
+
+///
+/// A struct contains the information decoded from the debug directory (`IMAGE_DIRECTORY_ENTRY_DEBUG`) of PE files.
+///
+/// Fields which are not found in the debug directory will be null pointers, blank vectors or `0`.
+///
+#[repr(C)]
+pub struct PeDebugInfo {
+    /// Types of the entries in the debug directory (Like: CodeView, POGO, Repro, etc.).
+    pub entries: CharVec,
+    /// The path of the PDB file which is written by the linker into the CodeView entry.
+    pub pdb_path: *mut c_char,
+    /// The GUID of the PDB file (in registry format) for RSDS entries or the signature of it (in hex) for NB10 entries.
+    pub pdb_guid: *mut c_char,
+    /// The age of the PDB file. It is incremented each time the PDB file is updated.
+    pub pdb_age: u32,
+    /// The names of the sections listed in the POGO (Profile Guided Optimization) entry.
+    pub pogo_sections: CharVec,
+    /// The counts in the VC_FEATURE entry as "name: count" strings.
+    pub vc_features: CharVec,
+    /// The hash (in hex) written into the REPRO entry by deterministic builds.
+    pub repro_hash: *mut c_char,
+    /// The names of the flags set in the EX_DLLCHARACTERISTICS entry.
+    pub ex_dll_characteristics: CharVec,
+}
+
+///
+/// A struct contains the metadata of .NET (CLR) assemblies.
+///
+/// It is filled if the CLR runtime header data directory of the PE file is present.
+///
+#[repr(C)]
+pub struct DotNetInfo {
+    /// The field is true if the object is a .NET assembly.
+    pub is_dotnet: bool,
+    /// The version of the runtime the assembly was built against (Like: v4.0.30319).
+    pub runtime_version: *mut c_char,
+    /// The name of the assembly.
+    ///
+    /// It is a null pointer in modules (.netmodule) since they don't have an assembly manifest.
+    pub assembly_name: *mut c_char,
+    /// The version of the assembly.
+    pub assembly_version: *mut c_char,
+    /// The culture of the assembly. It is "neutral" if the assembly is not a satellite assembly.
+    pub culture: *mut c_char,
+    /// The public key token (in hex) of the assembly. It is a null pointer if the assembly is not strong-named.
+    pub public_key_token: *mut c_char,
+    /// The value of `TargetFrameworkAttribute` (Like: .NETCoreApp,Version=v8.0).
+    pub target_framework: *mut c_char,
+    /// The field is true if the assembly contains only IL code.
+    pub is_il_only: bool,
+    /// The field is true if the assembly can only run as a 32 bit process.
+    pub requires_32bit: bool,
+    /// The field is true if the assembly contains precompiled (ReadyToRun) native code.
+    pub is_ready_to_run: bool,
+    /// The assemblies referenced by the assembly as display names (Like: "System.Runtime, Version=8.0.0.0, Culture=neutral, PublicKeyToken=b03f5f7f11d50a3a").
+    pub assembly_references: CharVec,
+}
+
+///
+/// A struct contains the entry point, TLS callbacks and load configuration of PE files.
+///
+/// Addresses are RVAs unless they are stated as VAs. Fields which are not found in the object will be null pointers, blank vectors or `0`.
+///
+#[repr(C)]
+pub struct PeLoadInfo {
+    /// The RVA of the entry point.
+    pub entry_point: u64,
+    /// The name of the section which contains the entry point.
+    ///
+    /// It is a null pointer if the object doesn't have an entry point (`entry_point` is `0`, like resource-only DLLs).
+    /// Otherwise, it is a null pointer if the entry point is not inside of any section, which is a common sign of packed objects.
+    pub entry_point_section: *mut c_char,
+    /// The RVAs of the TLS callbacks. They are run before the entry point.
+    pub tls_callbacks: U64Vec,
+    /// The VA of the security cookie used by `/GS`.
+    pub security_cookie: u64,
+    /// The VA of the safe SEH handler table (32 bit objects only).
+    pub se_handler_table: u64,
+    /// The count of the handlers in the safe SEH handler table (32 bit objects only).
+    pub se_handler_count: u64,
+    /// The names of the Control Flow Guard flags set in the load configuration.
+    pub guard_flags: CharVec,
+    /// The VA of the CHPE metadata. It is only set in hybrid (ARM64EC, ARM64X and CHPE) objects.
+    pub chpe_metadata: u64,
+    /// The kind of the hybrid object (Like: ARM64EC, ARM64X). It is a null pointer in non-hybrid objects.
+    pub hybrid_kind: *mut c_char,
+    /// The size of the exception directory (`.pdata`) in bytes.
+    pub exception_directory_size: u32,
+}
+
+///
+/// A struct contains the results of the heuristics which are commonly used to triage PE files.
+///
+#[repr(C)]
+#[derive(Default)]
+pub struct PeAnomalies {
+    /// The field is true if there is data after the raw data of the last section (and the certificate table).
+    pub has_overlay: bool,
+    /// The file offset of the overlay. It is `0` if there is no overlay.
+    pub overlay_offset: u64,
+    /// The size of the overlay in bytes.
+    pub overlay_size: u64,
+    /// The `CheckSum` field of the optional header. Most linkers leave it `0` unless the object is a driver or a system DLL.
+    pub stored_checksum: u32,
+    /// The checksum calculated from the bytes of the object.
+    pub computed_checksum: u32,
+    /// The field is true if the stored checksum matches the calculated one.
+    pub is_checksum_valid: bool,
+    /// Explanations of the suspicious sections (Like: writable and executable sections, sections beyond the end of the file, etc.).
+    pub section_anomalies: CharVec,
+}
+
+///
+/// A struct contains the build ID of ELF files and the links to their separate debug files.
+///
+/// Fields which are not found in the object will be null pointers or `0`.
+///
+#[repr(C)]
+pub struct ElfDebugInfo {
+    /// The build ID (in hex) in the `NT_GNU_BUILD_ID` note.
+    pub build_id: *mut c_char,
+    /// The name of the debug file in the `.gnu_debuglink` section.
+    pub debuglink: *mut c_char,
+    /// The CRC32 of the debug file in the `.gnu_debuglink` section.
+    pub debuglink_crc: u32,
+    /// The name of the supplementary debug file (dwz) in the `.gnu_debugaltlink` section.
+    pub debugaltlink: *mut c_char,
+    /// The build ID (in hex) of the supplementary debug file in the `.gnu_debugaltlink` section.
+    pub debugaltlink_build_id: *mut c_char,
+    /// The path of the debug file which matches the object.
+    ///
+    /// It is only searched if [`ULDDOptions::find_debug_files`] is true. It is a null pointer if the search is disabled or no debug file is found.
+    pub debug_file: *mut c_char,
+}
+
+///
+/// A struct contains what is left in the object after stripping.
+///
+/// The symbol table and debug information is looked up in:
+/// - ELF: `.symtab`, `.debug_*`, `.zdebug_*` and `.stab` sections, `.gnu_debuglink` and `.gnu_debugaltlink`
+/// - PE: The COFF symbol table, the debug directory and `.debug_*` sections
+/// - COFF: The symbol table, `.debug$*` and `.debug_*` sections
+/// - Mach-O: Local symbols, stabs (`N_OSO` and the others) and `__DWARF` sections
+///
+#[repr(C)]
+pub struct StrippingInfo {
+    /// One of "Fully stripped", "Symbols only" or "Debug info".
+    ///
+    /// Only the embedded debug information makes it "Debug info", so the objects which only point to split or referenced debug information are "Fully stripped" or "Symbols only".
+    pub status: *mut c_char,
+    /// The field is true if the object has a symbol table (not the dynamic one) with local symbols.
+    pub has_symbols: bool,
+    /// The formats and locations of the debug information as "format (location)" strings (Like: "DWARF (Embedded)", "PDB (Split)", "DWARF (Referenced)").
+    pub debug_info: CharVec,
+}
+
+///
+/// A struct contains the state of a thread at the time the core dump was written (`NT_PRSTATUS`).
+///
+#[repr(C)]
+pub struct CoreThread {
+    /// The ID of the thread.
+    pub tid: i32,
+    /// The signal which was being delivered to the thread. It is `0` if there was none.
+    pub signal: i32,
+    /// The general purpose registers in the order of `elf_gregset_t` of the architecture.
+    pub registers: U64Vec,
+}
+
+/// A C-compatible vector for [`CoreThread`].
+#[repr(C)]
+pub struct CoreThreadVec {
+    pub capacity: usize,
+    pub length: usize,
+    pub vec: *mut CoreThread,
+}
+
+///
+/// A struct contains the information about the crashed process which is found in the notes of ELF core dumps.
+///
+/// Fields which are not found in the core dump will be null pointers, blank vectors or `0`.
+///
+#[repr(C)]
+pub struct CoreDumpInfo {
+    /// The field is true if the object is a core dump.
+    pub is_core: bool,
+    /// The name of the executable of the process (`pr_fname` in `NT_PRPSINFO`). It is truncated to 15 characters by the kernel.
+    pub process_name: *mut c_char,
+    /// The command line of the process (`pr_psargs` in `NT_PRPSINFO`). It is truncated to 79 characters by the kernel.
+    pub process_arguments: *mut c_char,
+    /// The ID of the process.
+    pub pid: i32,
+    /// The signal which caused the core dump.
+    pub signal: i32,
+    /// The threads of the process.
+    pub threads: CoreThreadVec,
+    /// The auxiliary vector (`NT_AUXV`) as "name: value" strings (Like: "AT_ENTRY: 0x401000").
+    pub auxv: CharVec,
+    /// The files which were mapped into the memory of the process (`NT_FILE`).
+    ///
+    /// The shared libraries among them are also listed in `libraries`.
+    pub mapped_files: CharVec,
+}
+
+///
+/// A struct contains the entries of the dynamic section of ELF objects.
+///
+/// Fields which are not found in the object will be null pointers, zeros or blank vectors.
+///
+#[repr(C)]
+pub struct ElfDynamicInfo {
+    /// The name the dynamic linker matches with the needed libraries of other objects (`DT_SONAME`).
+    pub soname: *mut c_char,
+    /// The search paths in `DT_RPATH`, which are searched before `LD_LIBRARY_PATH`.
+    pub rpath: CharVec,
+    /// The search paths in `DT_RUNPATH`, which are searched after `LD_LIBRARY_PATH`.
+    pub runpath: CharVec,
+    /// The names of the bits set in `DT_FLAGS` (Like: ORIGIN, BIND_NOW, STATIC_TLS). Unknown bits are written in hexadecimal.
+    pub flags: CharVec,
+    /// The names of the bits set in `DT_FLAGS_1` (Like: NOW, NODELETE, NOOPEN, ORIGIN, PIE). Unknown bits are written in hexadecimal.
+    pub flags_1: CharVec,
+    /// The auditing libraries in `DT_AUDIT` and `DT_DEPAUDIT`.
+    pub audit: CharVec,
+    /// The libraries the object is a standard filter of (`DT_FILTER`).
+    pub filter: CharVec,
+    /// The libraries the object is an auxiliary filter of (`DT_AUXILIARY`).
+    pub auxiliary: CharVec,
+    /// The field is true if the object has relocations against read-only segments (`DT_TEXTREL` or `DF_TEXTREL`).
+    pub has_textrel: bool,
+    /// The address of the initialization function (`DT_INIT`).
+    pub init: u64,
+    /// The address of the termination function (`DT_FINI`).
+    pub fini: u64,
+    /// The functions in `DT_PREINIT_ARRAY`.
+    pub preinit_array: U64Vec,
+    /// The functions in `DT_INIT_ARRAY`.
+    pub init_array: U64Vec,
+    /// The functions in `DT_FINI_ARRAY`.
+    pub fini_array: U64Vec,
+}
+
+///
+/// A struct contains the layout of a section or a segment.
+///
+/// Fields which the executable format doesn't have will be null pointers or `0`.
+///
+#[repr(C)]
+pub struct SectionInfo {
+    /// The name of the section or the segment. ELF, NE and LE/LX segments don't have names, so their types are used instead (Like: PT_LOAD, CODE).
+    pub name: *mut c_char,
+    /// The name of the segment the section belongs to. Mach-O only.
+    pub segment_name: *mut c_char,
+    /// The type of the ELF or XCOFF section (Like: SHT_PROGBITS, STYP_TEXT). ELF and XCOFF only.
+    pub section_type: *mut c_char,
+    /// The address of the section in the memory. The image base is added to the addresses of PE sections.
+    pub virtual_address: u64,
+    /// The size of the section in the memory.
+    pub virtual_size: u64,
+    /// The offset of the section in the file.
+    pub file_offset: u64,
+    /// The size of the section in the file. It is `0` for sections which are not stored in the file (Like: .bss).
+    pub file_size: u64,
+    /// The memory permissions like `ls` (Like: r-x). Mach-O sections have the permissions of their segments.
+    pub permissions: *mut c_char,
+    /// The raw flags (`sh_flags`, `p_flags`, `Characteristics` or `flags` of Mach-O).
+    pub flags: u64,
+    /// The alignment of the section in bytes.
+    pub alignment: u64,
+}
+
+/// A C-compatible vector for [`SectionInfo`].
+#[repr(C)]
+pub struct SectionInfoVec {
+    pub capacity: usize,
+    pub length: usize,
+    pub vec: *mut SectionInfo,
+}
+
+///
+/// A struct contains the metadata of Linux kernel modules which is found in the `.modinfo` and `__versions` sections.
+///
+/// Fields which are not found in the module will be null pointers or blank vectors.
+///
+#[repr(C)]
+pub struct KernelModuleInfo {
+    /// The field is true if the object is a kernel module.
+    pub is_kernel_module: bool,
+    /// The name of the module.
+    pub name: *mut c_char,
+    /// The license of the module (Like: GPL, Dual BSD/GPL, Proprietary, etc.).
+    pub license: *mut c_char,
+    /// The version magic string which has to match the kernel the module is loaded into.
+    pub vermagic: *mut c_char,
+    /// The checksum of the source files of the module.
+    pub srcversion: *mut c_char,
+    /// The version of the module.
+    pub version: *mut c_char,
+    /// The description of the module.
+    pub description: *mut c_char,
+    /// The authors of the module.
+    pub authors: CharVec,
+    /// The device aliases which make the module get loaded automatically (Like: pci:v00008086d*).
+    pub aliases: CharVec,
+    /// The CRCs of the kernel symbols the module uses as "name: crc" strings. They are only present if the kernel was built with `CONFIG_MODVERSIONS`.
+    pub symbol_versions: CharVec,
+}
+
+///
+/// A struct contains the build information which the Go linker embeds into the binaries (the output of `go version -m`).
+///
+/// Fields which are not found in the binary will be null pointers or blank vectors.
+///
+#[repr(C)]
+pub struct GoBuildInfo {
+    /// The field is true if the build information is found.
+    pub is_go: bool,
+    /// The version of Go which built the binary (Like: go1.22.5).
+    pub go_version: *mut c_char,
+    /// The package path of the main package (Like: github.com/foo/bar/cmd/bar).
+    pub path: *mut c_char,
+    /// The main module as "path version checksum". It is a null pointer if the binary is not built in module mode.
+    pub main_module: *mut c_char,
+    /// The dependency modules as "path version checksum" strings. Replaced modules are followed by " => path version checksum".
+    pub dependencies: CharVec,
+    /// The build settings as "key=value" strings (Like: CGO_ENABLED=1, GOOS=linux, -ldflags=-s -w).
+    pub build_settings: CharVec,
+}
+
+///
+/// A struct contains the information about objects built by rustc.
+///
+/// Fields which are not found in the object will be null pointers or blank vectors.
+///
+#[repr(C)]
+pub struct RustInfo {
+    /// The field is true if any trace of rustc is found (the fields below or the panic messages of the standard library).
+    pub is_rust: bool,
+    /// The version of rustc in `.comment` (Like: 1.80.1 (3f5fd8dd4 2024-08-06)). ELF only.
+    pub rustc_version: *mut c_char,
+    /// The commit hash of rustc in the paths of the standard library which are kept for panic messages.
+    pub rustc_commit: *mut c_char,
+    /// The symbol mangling scheme (Legacy, v0 or Legacy and v0). It is a null pointer if the symbols are stripped.
+    pub mangling: *mut c_char,
+    /// The crate the object is built from as "name version". It is only present if the object is built with `cargo auditable`.
+    pub root_crate: *mut c_char,
+    /// The crates in the dependency tree as "name version (source, kind)" strings. They are only present if the object is built with `cargo auditable`.
+    pub dependencies: CharVec,
+}
+
+///
+/// A struct contains the information about WebAssembly modules and components.
+///
+/// Fields which are not found in the binary will be null pointers, blank vectors or `0`.
+///
+#[repr(C)]
+pub struct WasmInfo {
+    /// The version in the header of the binary. It is `1` for modules and the draft version of the binary format (Like: 13) for components.
+    pub version: u32,
+    /// The field is true if the binary is a component of the component model.
+    pub is_component: bool,
+    /// The name of the module in the `name` custom section.
+    pub module_name: *mut c_char,
+    /// The version of WASI the binary targets (Preview 1 or Preview 2). It is a null pointer if it doesn't import any WASI function or interface.
+    pub wasi: *mut c_char,
+    /// The imports as "module.name (kind)" strings for modules and "interface (kind)" strings for components (Like: wasi_snapshot_preview1.fd_write (function)).
+    pub imports: CharVec,
+    /// The exports as "name (kind)" strings (Like: _start (function)).
+    pub exports: CharVec,
+    /// The limits of the memories in 64 KiB pages (Like: min 17 pages, max 256 pages, shared).
+    pub memories: CharVec,
+    /// The element types and the limits of the tables (Like: funcref: min 1 elements, max 1 elements).
+    pub tables: CharVec,
+    /// The names of the custom sections in the order they appear (Like: name, producers, target_features).
+    pub custom_sections: CharVec,
+    /// The features in the `target_features` custom section with their prefixes (+: used, -: disallowed, =: required).
+    pub target_features: CharVec,
+}
+
+///
+/// A struct contains the information in the auxiliary header and the loader section of XCOFF objects.
+///
+/// Object files usually don't have the full auxiliary header, so the fields read from it will be `0` or null pointers.
+///
+#[repr(C)]
+pub struct XcoffInfo {
+    /// The address of the function descriptor of the entry point (`o_entry`).
+    pub entry_point: u64,
+    /// The address of the TOC anchor (`o_toc`).
+    pub toc: u64,
+    /// The module type (1L: single use, RO: read-only, RE: reentrant).
+    pub module_type: *mut c_char,
+    /// The flags of the file header (Like: F_EXEC, F_DYNLOAD, F_SHROBJ).
+    pub flags: CharVec,
+    /// The default library search path in the first import file ID of the loader section.
+    pub library_path: *mut c_char,
+    /// The size of the text in bytes.
+    pub text_size: u64,
+    /// The size of the initialized data in bytes.
+    pub data_size: u64,
+    /// The size of the uninitialized data in bytes.
+    pub bss_size: u64,
+    /// The maximum stack size. It is `0` if the system default is used.
+    pub max_stack: u64,
+    /// The maximum data size. It is `0` if the system default is used.
+    pub max_data: u64,
+}
+
+///
+/// A struct contains the information in the headers of MS-DOS, NE, LE and LX executables.
+///
+/// Fields which are not found in the headers will be null pointers, blank vectors or `0`.
+///
+#[repr(C)]
+pub struct MzInfo {
+    /// The name of the module (the first entry of the resident names table).
+    pub module_name: *mut c_char,
+    /// The description of the module (the first entry of the non-resident names table).
+    pub description: *mut c_char,
+    /// The initial CS:IP as "segment:offset" (Like: 0001:0010). LE and LX use the object number instead of the segment.
+    ///
+    /// The segments of MS-DOS executables are relative to the start of the image.
+    pub entry_point: *mut c_char,
+    /// The initial SS:SP as "segment:offset". LE and LX use the object number instead of the segment.
+    pub stack_pointer: *mut c_char,
+    /// The flags of the header (Like: Multiple data, Library module, Compatible with Presentation Manager).
+    pub flags: CharVec,
+    /// The version of the linker. NE only.
+    pub linker_version: *mut c_char,
+    /// The number of the relocations in the MZ header. MS-DOS only.
+    pub relocations: u16,
+    /// The memory the program needs in addition to its image in bytes (`e_minalloc`). MS-DOS only.
+    pub minimum_memory: u32,
+    /// The memory the program requests in addition to its image in bytes (`e_maxalloc`). MS-DOS only.
+    pub maximum_memory: u32,
+    /// The DOS extenders found in the stub (Like: DOS/4GW, PMODE/W, CWSDPMI). MS-DOS executables are searched from the beginning instead of the stub.
+    pub extenders: CharVec,
+    /// The executable packers found in the stub (Like: LZEXE, PKLITE, UPX). MS-DOS executables are searched from the beginning instead of the stub.
+    pub packers: CharVec,
+}
+
+///
+/// A struct contains the information in the header of a.out executables and objects.
+///
+/// The fields which Plan 9 doesn't have will be `0`.
+///
+#[repr(C)]
+pub struct AoutInfo {
+    /// The magic number (OMAGIC, NMAGIC, ZMAGIC or QMAGIC). Plan 9 executables have the hexadecimal magic number instead.
+    pub magic: *mut c_char,
+    /// The size of the text in bytes.
+    pub text_size: u64,
+    /// The size of the initialized data in bytes.
+    pub data_size: u64,
+    /// The size of the uninitialized data in bytes.
+    pub bss_size: u64,
+    /// The address of the entry point.
+    pub entry_point: u64,
+    /// The size of the symbol table in bytes. It is `0` if the symbol table is stripped.
+    pub symbols_size: u64,
+    /// Whether the executable is dynamically linked (`EX_DYNAMIC` on BSD and the dynamic bit on SunOS).
+    pub is_dynamic: bool,
+}
+
+///
+/// A struct contains the information in Java class files.
+///
+/// The names of the classes are written with dots (Like: java.lang.Object).
+///
+#[repr(C)]
+pub struct JavaInfo {
+    /// The major version of the class file (Like: 61).
+    pub major_version: u16,
+    /// The minor version of the class file. It is 65535 if the class uses the preview features.
+    pub minor_version: u16,
+    /// The Java release which introduced the class file version (Like: Java SE 17).
+    pub java_release: *mut c_char,
+    /// The field is true if the class depends on the preview features of its Java release.
+    pub uses_preview_features: bool,
+    /// The name of the class.
+    pub class_name: *mut c_char,
+    /// The name of the super class. It is a null pointer for java.lang.Object and modules.
+    pub super_class: *mut c_char,
+    /// The interfaces the class implements.
+    pub interfaces: CharVec,
+    /// The access flags of the class (Like: ACC_PUBLIC, ACC_FINAL, ACC_SUPER).
+    pub access_flags: CharVec,
+    /// The name of the source file in the `SourceFile` attribute. Obfuscators usually remove it.
+    pub source_file: *mut c_char,
+}
+
+///
+/// A struct contains the information in the files of Android Runtime (DEX, OAT and VDEX).
+///
+/// The counts which the format doesn't have will be `0`.
+///
+#[repr(C)]
+pub struct DexInfo {
+    /// The version of the format (Like: 039 for DEX, 230 for OAT and 027 for VDEX).
+    pub version: *mut c_char,
+    /// The number of the classes defined in the DEX file.
+    pub class_count: u32,
+    /// The number of the strings in the DEX file.
+    pub string_count: u32,
+    /// The number of the types the DEX file refers to.
+    pub type_count: u32,
+    /// The number of the methods the DEX file refers to.
+    pub method_count: u32,
+    /// The number of the fields the DEX file refers to.
+    pub field_count: u32,
+    /// The number of the DEX files which the OAT or VDEX file was made from.
+    pub dex_file_count: u32,
+    /// The instruction set the OAT file was compiled for (Like: arm64).
+    ///
+    /// VDEX files don't record it, so it is taken from the name of their directory (Like: oat/arm64/base.vdex) if it is an instruction set.
+    pub instruction_set: *mut c_char,
+}
+
+///
+/// A struct contains the metadata of Debian (`.deb`) and RPM (`.rpm`) packages.
+///
+/// The dependencies are the ones the package declares, so they can be compared with the libraries its objects need.
+///
+#[repr(C)]
+pub struct PackageInfo {
+    /// The format of the package (deb or rpm).
+    pub format: *mut c_char,
+    /// The name of the package (Like: coreutils).
+    pub name: *mut c_char,
+    /// The version of the package with its epoch if it has one (Like: 1:2.10-3 for deb and 1:9.4-3.fc40 for rpm).
+    pub version: *mut c_char,
+    /// The architecture of the package (Like: amd64 for deb and x86_64 for rpm).
+    pub architecture: *mut c_char,
+    /// The packages and the capabilities the package depends on with their versions (Like: libc6 (>= 2.34) for deb and libc.so.6(GLIBC_2.34)(64bit) for rpm).
+    ///
+    /// The `Pre-Depends` and `Depends` fields are read from deb packages and the requirements except the ones on the features of rpm itself (rpmlib(...)) are read from rpm packages.
+    pub dependencies: CharVec,
 }
 
 /// A struct packs (empty or filled) error and (successfully or not) read object.
@@ -99,6 +762,24 @@ pub struct ULDDObjResultVec {
     pub vec: *mut ULDDObjResult,
 }
 
+///
+/// Options to change how the objects are parsed.
+///
+/// [`read_obj`](crate::read_obj) uses the default options (all fields are `false`) except `debugging`.
+///
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct ULDDOptions {
+    /// Prints the information about the parsing process.
+    pub debugging: bool,
+    /// Searches the separate debug files of ELF objects under `/usr/lib/debug` and next to the file.
+    ///
+    /// It reads files from the disk, so `file_name` given to [`read_obj_with_options`](crate::read_obj_with_options) should be a path.
+    pub find_debug_files: bool,
+    /// Lists the sections and the segments of the objects in `sections` and `segments` of [`ULDDObj`].
+    pub list_sections: bool,
+}
+
 #[doc(hidden)]
 pub(crate) enum Debugging {
     Info(String),
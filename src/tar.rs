@@ -0,0 +1,439 @@
+use crate::{
+    debug::{debug_objects, merge_members, option_to_c_string, read_c_str},
+    impls::{ErrorToInt, StringToCString},
+    is_object,
+    package::installed_path,
+    parse_objects,
+    structs::{CharVec, Debugging, ParsingError, ULDDObj, ULDDObjResult, ULDDOptions},
+    zip::{Budget, MAX_NESTING_DEPTH},
+};
+use goblin::error::Error;
+use std::collections::HashMap;
+
+/*
+Sources:
+    https://www.gnu.org/software/tar/manual/html_node/Standard.html
+    https://pubs.opengroup.org/onlinepubs/9799919799/utilities/pax.html#tag_20_94_13_03
+*/
+
+const BLOCK_SIZE: usize = 512;
+// POSIX archives have "ustar\0" followed by the version "00" and GNU archives have "ustar  \0"
+const USTAR_MAGIC: &[u8] = b"ustar\0";
+const GNU_MAGIC: &[u8] = b"ustar  \0";
+const MAGIC_OFFSET: usize = 257;
+
+const REGTYPE: u8 = b'0';
+const AREGTYPE: u8 = b'\0';
+const LNKTYPE: u8 = b'1';
+const SYMTYPE: u8 = b'2';
+const CONTTYPE: u8 = b'7';
+const GNUTYPE_LONGLINK: u8 = b'K';
+const GNUTYPE_LONGNAME: u8 = b'L';
+const GNUTYPE_SPARSE: u8 = b'S';
+const XHDTYPE: u8 = b'x';
+const XGLTYPE: u8 = b'g';
+
+/// An entry of a tar file. The names in the GNU and pax extended headers replace the names in the header.
+pub(crate) struct TarEntry<'a> {
+    pub(crate) path: String,
+    link: String,
+    kind: u8,
+    pub(crate) data: &'a [u8],
+}
+
+#[derive(Default)]
+struct ExtendedHeader {
+    path: Option<String>,
+    link: Option<String>,
+    size: Option<u64>,
+}
+
+// The checksum is the sum of the bytes of the header while the checksum field is filled with spaces
+fn is_checksum_valid(header: &[u8]) -> bool {
+    let Ok(checksum) = read_number(&header[148..156]) else {
+        return false;
+    };
+    let field = 148..156;
+    let (unsigned, signed) = header
+        .iter()
+        .enumerate()
+        .map(|(index, byte)| if field.contains(&index) { b' ' } else { *byte })
+        .fold((0u64, 0i64), |(unsigned, signed), byte| {
+            (unsigned + byte as u64, signed + byte as i8 as i64)
+        });
+
+    // Some old implementations summed the bytes as signed
+    checksum == unsigned || checksum as i64 == signed
+}
+
+// The numbers are octal, but GNU tar writes the ones which don't fit in base-256 with the high bit set
+fn read_number(field: &[u8]) -> Result<u64, Error> {
+    let malformed = || {
+        Error::Malformed(format!(
+            "The number {:?} in the tar header is malformed",
+            field
+        ))
+    };
+    if field.first().is_some_and(|byte| byte & 0x80 != 0) {
+        return field[1..]
+            .iter()
+            .try_fold((field[0] & 0x7f) as u64, |number, byte| {
+                number.checked_mul(256)?.checked_add(*byte as u64)
+            })
+            .ok_or_else(malformed);
+    }
+    let text = String::from_utf8_lossy(field);
+    let digits = text.trim_matches(|c: char| c == '\0' || c == ' ');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+
+    u64::from_str_radix(digits, 8).map_err(|_| malformed())
+}
+
+///
+/// Tells tar files by the magic number in the first header and its checksum.
+///
+/// The headers of the old (V7) tar files don't have a magic number, so they are not recognized.
+///
+pub(crate) fn is_tar(buffer: &[u8]) -> bool {
+    buffer.get(..BLOCK_SIZE).is_some_and(|header| {
+        (header[MAGIC_OFFSET..].starts_with(USTAR_MAGIC)
+            || header[MAGIC_OFFSET..].starts_with(GNU_MAGIC))
+            && is_checksum_valid(header)
+    })
+}
+
+// The records are "<length> <key>=<value>\n" where the length includes itself
+fn read_pax_records(data: &[u8], extended: &mut ExtendedHeader) -> Result<(), Error> {
+    let malformed =
+        || Error::Malformed("A record of the pax extended header is malformed".to_owned());
+    let mut rest = data;
+    while !rest.is_empty() && rest[0] != 0 {
+        let space = rest.iter().position(|b| *b == b' ').ok_or_else(malformed)?;
+        let length: usize = String::from_utf8_lossy(&rest[..space])
+            .parse()
+            .map_err(|_| malformed())?;
+        let record = rest.get(space + 1..length).ok_or_else(malformed)?;
+        let record = String::from_utf8_lossy(record.strip_suffix(b"\n").unwrap_or(record));
+        if let Some((key, value)) = record.split_once('=') {
+            // The values are UTF-8 strings which may have NUL bytes in them, so they are cut there like the names in the header
+            let value = value.split('\0').next().unwrap_or_default();
+            match key {
+                "path" => extended.path = Some(value.to_owned()),
+                "linkpath" => extended.link = Some(value.to_owned()),
+                "size" => extended.size = Some(value.parse().map_err(|_| malformed())?),
+                _ => (),
+            }
+        }
+        rest = &rest[length..];
+    }
+
+    Ok(())
+}
+
+// The names longer than 100 bytes are split into the prefix and the name in POSIX archives
+fn header_path(header: &[u8]) -> String {
+    let name = read_c_str(&header[0..100]);
+    if !header[MAGIC_OFFSET..].starts_with(USTAR_MAGIC) {
+        return name;
+    }
+
+    match read_c_str(&header[345..500]) {
+        prefix if prefix.is_empty() => name,
+        prefix => format!("{}/{}", prefix, name),
+    }
+}
+
+pub(crate) fn read_entries(buffer: &[u8]) -> Result<Vec<TarEntry<'_>>, Error> {
+    let mut entries = vec![];
+    let mut extended = ExtendedHeader::default();
+    let mut offset = 0;
+    while let Some(header) = buffer.get(offset..offset + BLOCK_SIZE) {
+        // The archive ends with two blocks of zeros
+        if header.iter().all(|b| *b == 0) {
+            break;
+        }
+        if !is_checksum_valid(header) {
+            return Err(Error::Malformed(format!(
+                "The checksum of the header at {:#x} is wrong",
+                offset
+            )));
+        }
+        let kind = header[156];
+        let size = match extended.size.take() {
+            Some(size) => size,
+            None => read_number(&header[124..136])?,
+        };
+        let start = offset + BLOCK_SIZE;
+        let data = usize::try_from(size)
+            .ok()
+            .and_then(|size| start.checked_add(size))
+            .and_then(|end| buffer.get(start..end))
+            .ok_or_else(|| {
+                Error::Malformed(format!(
+                    "The data of the entry at {:#x} is out of the tar file",
+                    offset
+                ))
+            })?;
+        offset = start + data.len().div_ceil(BLOCK_SIZE) * BLOCK_SIZE;
+
+        match kind {
+            GNUTYPE_LONGNAME => extended.path = Some(read_c_str(data)),
+            GNUTYPE_LONGLINK => extended.link = Some(read_c_str(data)),
+            XHDTYPE => read_pax_records(data, &mut extended)?,
+            // The global headers usually have the comments and the times only
+            XGLTYPE => (),
+            _ => entries.push(TarEntry {
+                path: extended.path.take().unwrap_or_else(|| header_path(header)),
+                link: extended
+                    .link
+                    .take()
+                    .unwrap_or_else(|| read_c_str(&header[157..257])),
+                kind,
+                data,
+            }),
+        }
+    }
+
+    Ok(entries)
+}
+
+// The errors of the entries are reported with the names of the entries and the errors of the tar file with its own name
+fn to_error(
+    file_name: &str,
+    entry_name: Option<&str>,
+    error: Error,
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+) -> ULDDObjResult {
+    let message = match entry_name {
+        Some(entry_name) => format!(
+            "Error while extracting the entry named '{}' from the tar file named '{}'",
+            entry_name, file_name
+        ),
+        None => format!("Error while reading the tar file named '{}'", file_name),
+    };
+    Debugging::Error(format!(
+        "{}{}\nDetails:\n{}",
+        message,
+        merge_members(member_names),
+        error
+    ))
+    .print(options.debugging);
+
+    ULDDObjResult {
+        error: ParsingError {
+            code: error.to_int(),
+            explanation: error.to_c_string(),
+        },
+        obj: ULDDObj {
+            file_name: entry_name.unwrap_or(file_name).to_c_string(),
+            member_name: CharVec::from(member_names),
+            file_type: option_to_c_string(entry_name.is_none().then_some("Archive")),
+            ..Default::default()
+        },
+    }
+}
+
+///
+/// Parses the objects in tar files (ustar, GNU and pax) as members named with their paths in the archive.
+///
+/// Only the regular files which start with the magic number of an object or a container are parsed, and the links are handled as below:
+/// - Hard links are parsed with the data of the file they link to, as they are the same file installed in another path
+/// - Symbolic links are not followed, as they may point out of the archive and the files they point to are parsed by themselves
+///
+/// The entries are borrowed from the buffer, so the limits are only on the compressed streams in the archive (See [`parse_compressed`](crate::compression::parse_compressed)).
+///
+pub(crate) fn parse_tar(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    objects: &mut Vec<ULDDObjResult>,
+    budget: &mut Budget,
+    options: &ULDDOptions,
+) {
+    parse_entries(
+        file_name,
+        buffer,
+        member_names,
+        objects,
+        budget,
+        options,
+        false,
+    )
+}
+
+///
+/// Parses the objects in the data of packages like [`parse_tar`], but names them with the paths they are installed to (Like: /usr/bin/ls for ./usr/bin/ls).
+///
+pub(crate) fn parse_installed_tar(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    objects: &mut Vec<ULDDObjResult>,
+    budget: &mut Budget,
+    options: &ULDDOptions,
+) {
+    parse_entries(
+        file_name,
+        buffer,
+        member_names,
+        objects,
+        budget,
+        options,
+        true,
+    )
+}
+
+fn parse_entries(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    objects: &mut Vec<ULDDObjResult>,
+    budget: &mut Budget,
+    options: &ULDDOptions,
+    is_installed: bool,
+) {
+    if member_names.len() >= MAX_NESTING_DEPTH {
+        let error = Error::Malformed(format!(
+            "The tar file is nested deeper than {} members",
+            MAX_NESTING_DEPTH
+        ));
+        return objects.push(to_error(file_name, None, error, member_names, options));
+    }
+    let entries = match read_entries(buffer) {
+        Ok(entries) => entries,
+        Err(error) => return objects.push(to_error(file_name, None, error, member_names, options)),
+    };
+
+    // Hard links can only point to the files before them
+    let mut files: HashMap<&str, &[u8]> = HashMap::new();
+    for entry in &entries {
+        let data = match entry.kind {
+            REGTYPE | AREGTYPE | CONTTYPE => {
+                files.insert(&entry.path, entry.data);
+                entry.data
+            }
+            LNKTYPE => match files.get(entry.link.as_str()) {
+                Some(data) => data,
+                None => {
+                    let error = Error::Malformed(format!(
+                        "The hard link points to '{}' which is not a file before it",
+                        entry.link
+                    ));
+                    let mut names: Vec<&str> = member_names.to_vec();
+                    names.push(file_name);
+                    objects.push(to_error(
+                        file_name,
+                        Some(&entry.path),
+                        error,
+                        &mut names,
+                        options,
+                    ));
+                    continue;
+                }
+            },
+            SYMTYPE => {
+                Debugging::Info(format!(
+                    "The symbolic link named '{}' of the tar file named '{}' is not followed (-> '{}')",
+                    entry.path, file_name, entry.link
+                ))
+                .print(options.debugging);
+                continue;
+            }
+            GNUTYPE_SPARSE => {
+                Debugging::Info(format!(
+                    "The sparse file named '{}' of the tar file named '{}' is skipped",
+                    entry.path, file_name
+                ))
+                .print(options.debugging);
+                continue;
+            }
+            // Directories, devices and FIFOs
+            _ => continue,
+        };
+        if !is_object(data) {
+            continue;
+        }
+
+        // The names of the entries don't live as long as the tar file, so the names are copied for each entry
+        let entry_name = if is_installed {
+            installed_path(&entry.path)
+        } else {
+            entry.path.clone()
+        };
+        let mut names: Vec<&str> = member_names.to_vec();
+        names.push(file_name);
+        debug_objects(file_name, &mut names, "a tar file", options.debugging);
+        parse_objects(&entry_name, data, &mut names, objects, budget, options);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{elf, parse, summarize, tar, tar_header};
+
+    // The length of a record includes its own digits
+    fn pax_record(key: &str, value: &str) -> Vec<u8> {
+        let record = format!(" {}={}\n", key, value);
+        let digits = (record.len() + 1).to_string().len();
+        let length = (record.len() + digits).to_string();
+
+        format!("{}{}", length, record).into_bytes()
+    }
+
+    #[test]
+    fn pax_headers_override_the_size_and_the_path() {
+        let records = [
+            pax_record("path", "usr/lib/libfoo.so"),
+            pax_record("size", "64"),
+        ]
+        .concat();
+        // The size in the header is zero, so only the pax header tells where the entry ends
+        let buffer = [
+            tar_header("PaxHeaders/libfoo.so", XHDTYPE, records.len(), ""),
+            records.clone(),
+            vec![0; BLOCK_SIZE - records.len()],
+            tar_header("libfoo.so", REGTYPE, 0, ""),
+            elf(),
+            vec![0; BLOCK_SIZE - elf().len() + 2 * BLOCK_SIZE],
+        ]
+        .concat();
+
+        let entries = read_entries(&buffer).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].path, "usr/lib/libfoo.so");
+        assert_eq!(entries[0].data, elf());
+    }
+
+    #[test]
+    fn hard_links_only_point_to_the_files_before_them() {
+        let buffer = tar(&[
+            ("bin/early", LNKTYPE, b"bin/tool"),
+            ("bin/tool", REGTYPE, &elf()),
+            ("bin/late", LNKTYPE, b"bin/tool"),
+        ]);
+
+        let objects = summarize(&parse("a.tar", &buffer));
+        let names: Vec<(&str, i64)> = objects
+            .iter()
+            .map(|(name, _, code)| (name.as_str(), *code))
+            .collect();
+        assert_eq!(names, [("bin/early", -1), ("bin/tool", 0), ("bin/late", 0)]);
+    }
+
+    #[test]
+    fn headers_with_wrong_checksums_are_refused() {
+        let mut buffer = tar(&[("lib.so", REGTYPE, &elf())]);
+        assert!(is_tar(&buffer));
+        buffer[0] = b'L';
+
+        assert!(!is_tar(&buffer));
+        assert_eq!(
+            read_entries(&buffer).err().unwrap().to_string(),
+            "Malformed entity: The checksum of the header at 0x0 is wrong"
+        );
+    }
+}
@@ -0,0 +1,287 @@
+//! The fixtures shared by the tests of the containers.
+
+use crate::{
+    parse_objects,
+    structs::{CharVec, ULDDObjResult, ULDDOptions},
+    zip::Budget,
+};
+use flate2::{write::GzEncoder, Compression};
+use std::{
+    ffi::{c_char, CStr},
+    io::Write,
+};
+
+pub(crate) fn read_string(string: *mut c_char) -> Option<String> {
+    (!string.is_null()).then(|| {
+        unsafe { CStr::from_ptr(string) }
+            .to_string_lossy()
+            .into_owned()
+    })
+}
+
+pub(crate) fn read_strings(strings: &CharVec) -> Vec<String> {
+    if strings.vec.is_null() {
+        return vec![];
+    }
+
+    unsafe { std::slice::from_raw_parts(strings.vec, strings.length) }
+        .iter()
+        .filter_map(|string| read_string(*string))
+        .collect()
+}
+
+/// Returns the file name, the member names and the error code of each object.
+pub(crate) fn summarize(objects: &[ULDDObjResult]) -> Vec<(String, Vec<String>, i64)> {
+    objects
+        .iter()
+        .map(|object| {
+            (
+                read_string(object.obj.file_name).unwrap_or_default(),
+                read_strings(&object.obj.member_name),
+                object.error.code,
+            )
+        })
+        .collect()
+}
+
+pub(crate) fn parse_with_budget(
+    file_name: &str,
+    buffer: &[u8],
+    budget: &mut Budget,
+) -> Vec<ULDDObjResult> {
+    let mut objects = vec![];
+    let options = ULDDOptions::default();
+    parse_objects(
+        file_name,
+        buffer,
+        &mut vec![],
+        &mut objects,
+        budget,
+        &options,
+    );
+
+    objects
+}
+
+pub(crate) fn parse(file_name: &str, buffer: &[u8]) -> Vec<ULDDObjResult> {
+    parse_with_budget(file_name, buffer, &mut Budget::new())
+}
+
+/// An ELF header of an x86-64 executable without any segments or sections.
+pub(crate) fn elf() -> Vec<u8> {
+    let mut header = vec![0; 64];
+    header[..7].copy_from_slice(b"\x7fELF\x02\x01\x01");
+    header[16..20].copy_from_slice(&[2, 0, 0x3e, 0]);
+    header[20] = 1;
+    header[52] = 64;
+
+    header
+}
+
+pub(crate) fn gzip(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(vec![], Compression::default());
+    encoder.write_all(data).unwrap();
+
+    encoder.finish().unwrap()
+}
+
+/// A tar file with the entries (The path, the type and the data) in POSIX (ustar) headers. The data of the links are their targets.
+pub(crate) fn tar(entries: &[(&str, u8, &[u8])]) -> Vec<u8> {
+    let mut archive = vec![];
+    for (path, kind, data) in entries {
+        if [b'1', b'2'].contains(kind) {
+            archive.extend(tar_header(path, *kind, 0, &String::from_utf8_lossy(data)));
+            continue;
+        }
+        archive.extend(tar_header(path, *kind, data.len(), ""));
+        archive.extend(*data);
+        archive.resize(archive.len().next_multiple_of(512), 0);
+    }
+    archive.resize(archive.len() + 1024, 0);
+
+    archive
+}
+
+pub(crate) fn tar_header(path: &str, kind: u8, size: usize, link: &str) -> Vec<u8> {
+    let mut header = vec![0; 512];
+    header[..path.len()].copy_from_slice(path.as_bytes());
+    header[100..108].copy_from_slice(b"0000644\0");
+    header[124..136].copy_from_slice(format!("{:011o}\0", size).as_bytes());
+    header[156] = kind;
+    header[157..157 + link.len()].copy_from_slice(link.as_bytes());
+    header[257..265].copy_from_slice(b"ustar\x0000");
+    header[148..156].fill(b' ');
+    let checksum: u32 = header.iter().map(|byte| *byte as u32).sum();
+    header[148..156].copy_from_slice(format!("{:06o}\0 ", checksum).as_bytes());
+
+    header
+}
+
+/// A ZIP file with the stored entries (The name and the data).
+pub(crate) fn zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut archive = vec![];
+    let mut central_directory = vec![];
+    for (name, data) in entries {
+        let header = archive.len() as u32;
+        let crc = crc32fast::hash(data);
+        archive.extend(b"PK\x03\x04\x14\0\0\0\0\0\0\0\0\0");
+        archive.extend(crc.to_le_bytes());
+        archive.extend((data.len() as u32).to_le_bytes());
+        archive.extend((data.len() as u32).to_le_bytes());
+        archive.extend((name.len() as u16).to_le_bytes());
+        archive.extend(0_u16.to_le_bytes());
+        archive.extend(name.as_bytes());
+        archive.extend(*data);
+
+        central_directory.extend(b"PK\x01\x02\x14\0\x14\0\0\0\0\0\0\0\0\0");
+        central_directory.extend(crc.to_le_bytes());
+        central_directory.extend((data.len() as u32).to_le_bytes());
+        central_directory.extend((data.len() as u32).to_le_bytes());
+        central_directory.extend((name.len() as u16).to_le_bytes());
+        central_directory.extend([0; 12]);
+        central_directory.extend(header.to_le_bytes());
+        central_directory.extend(name.as_bytes());
+    }
+    let start = archive.len() as u32;
+    archive.extend(&central_directory);
+    archive.extend(b"PK\x05\x06\0\0\0\0");
+    archive.extend((entries.len() as u16).to_le_bytes());
+    archive.extend((entries.len() as u16).to_le_bytes());
+    archive.extend((central_directory.len() as u32).to_le_bytes());
+    archive.extend(start.to_le_bytes());
+    archive.extend(0_u16.to_le_bytes());
+
+    archive
+}
+
+/// An `ar` archive with the members (The name and the data).
+pub(crate) fn ar(members: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut archive = b"!<arch>\n".to_vec();
+    for (name, data) in members {
+        let header = format!(
+            "{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n",
+            name,
+            0,
+            0,
+            0,
+            100644,
+            data.len()
+        );
+        archive.extend(header.as_bytes());
+        archive.extend(*data);
+        // The members are aligned to 2 bytes
+        if !archive.len().is_multiple_of(2) {
+            archive.push(b'\n');
+        }
+    }
+
+    archive
+}
+
+/// A cpio archive in the new ASCII format with the regular files (The path, the inode number, the number of the links and the data).
+pub(crate) fn cpio(entries: &[(&str, u32, u32, &[u8])]) -> Vec<u8> {
+    let mut archive = vec![];
+    let trailer = ("TRAILER!!!", 0, 1, &[][..]);
+    for (path, inode, link_count, data) in entries.iter().chain([&trailer]) {
+        let mode = if *path == trailer.0 { 0 } else { 0o100644 };
+        let fields = [
+            *inode,
+            mode,
+            0,
+            0,
+            *link_count,
+            0,
+            data.len() as u32,
+            0,
+            0,
+            0,
+            0,
+        ];
+        archive.extend(b"070701");
+        for field in fields.iter().chain([&(path.len() as u32 + 1), &0]) {
+            archive.extend(format!("{:08x}", field).as_bytes());
+        }
+        archive.extend(path.as_bytes());
+        archive.push(0);
+        archive.resize(archive.len().next_multiple_of(4), 0);
+        archive.extend(*data);
+        archive.resize(archive.len().next_multiple_of(4), 0);
+    }
+
+    archive
+}
+
+fn put(buffer: &mut [u8], offset: usize, bytes: &[u8]) {
+    buffer[offset..offset + bytes.len()].copy_from_slice(bytes);
+}
+
+///
+/// A PE image (PE32+ if `is_64`) with a section which has `section` at the RVA 0x1000 and the offset 0x200.
+///
+/// The data directories are given with their indexes, RVAs and sizes.
+///
+pub(crate) fn pe(
+    machine: u16,
+    is_64: bool,
+    directories: &[(usize, u32, u32)],
+    section: &[u8],
+) -> Vec<u8> {
+    let raw_size = section.len().next_multiple_of(0x200).max(0x200);
+    let mut image = vec![0; 0x200 + raw_size];
+    put(&mut image, 0, b"MZ");
+    put(&mut image, 0x3c, &0x40_u32.to_le_bytes());
+    put(&mut image, 0x40, b"PE\0\0");
+    put(&mut image, 0x44, &machine.to_le_bytes());
+    put(&mut image, 0x46, &1_u16.to_le_bytes());
+    let optional_header_size: u16 = if is_64 { 0xf0 } else { 0xe0 };
+    put(&mut image, 0x54, &optional_header_size.to_le_bytes());
+    put(&mut image, 0x56, &0x22_u16.to_le_bytes());
+
+    // The image base and the data directories are 16 bytes further in PE32+ images
+    let directories_start = if is_64 {
+        put(&mut image, 0x58, &0x20b_u16.to_le_bytes());
+        put(&mut image, 0x58 + 24, &0x1_4000_0000_u64.to_le_bytes());
+        0x58 + 112
+    } else {
+        put(&mut image, 0x58, &0x10b_u16.to_le_bytes());
+        put(&mut image, 0x58 + 28, &0x40_0000_u32.to_le_bytes());
+        0x58 + 96
+    };
+    put(&mut image, 0x58 + 32, &0x1000_u32.to_le_bytes());
+    put(&mut image, 0x58 + 36, &0x200_u32.to_le_bytes());
+    let image_size = 0x1000 + raw_size.next_multiple_of(0x1000) as u32;
+    put(&mut image, 0x58 + 56, &image_size.to_le_bytes());
+    put(&mut image, 0x58 + 60, &0x200_u32.to_le_bytes());
+    put(&mut image, directories_start - 4, &16_u32.to_le_bytes());
+    for (index, rva, size) in directories {
+        put(
+            &mut image,
+            directories_start + index * 8,
+            &rva.to_le_bytes(),
+        );
+        put(
+            &mut image,
+            directories_start + index * 8 + 4,
+            &size.to_le_bytes(),
+        );
+    }
+
+    let section_header = 0x58 + optional_header_size as usize;
+    put(&mut image, section_header, b".data\0\0\0");
+    for (field, value) in [
+        (8, raw_size as u32),
+        (12, 0x1000),
+        (16, raw_size as u32),
+        (20, 0x200),
+    ] {
+        put(&mut image, section_header + field, &value.to_le_bytes());
+    }
+    put(
+        &mut image,
+        section_header + 36,
+        &0xc000_0040_u32.to_le_bytes(),
+    );
+    put(&mut image, 0x200, section);
+
+    image
+}
@@ -195,6 +195,7 @@ pub enum ElfOS {
     Illumos,
     SerenityOS,
     Android,
+    Haiku,
     Undefined,
 }
 
@@ -212,6 +213,8 @@ pub enum ElfFileType {
     CoreFile,
     OsSpecific,
     ProcessorSpecific,
+    KernelModule,
+    OatFile,
 }
 
 // Source: https://en.wikipedia.org/wiki/Executable_and_Linkable_Format
@@ -550,6 +553,1046 @@ pub enum PeOS {
     Undefined,
 }
 
+// Source: https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#debug-type
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub enum PeDebugType {
+    Unknown,
+    COFF,
+    CodeView,
+    FPO,
+    Misc,
+    Exception,
+    Fixup,
+    OmapToSrc,
+    OmapFromSrc,
+    Borland,
+    Reserved10,
+    CLSID,
+    VCFeature,
+    POGO,
+    ILTCG,
+    MPX,
+    Repro,
+    EmbeddedPortablePDB,
+    SPGO,
+    PDBChecksum,
+    ExDllCharacteristics,
+}
+
+// Source: https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#debug-type
+pub const PE_DEBUG_TYPE: phf::Map<u32, PeDebugType> = phf_map! {
+    0_u32 => PeDebugType::Unknown,
+    1_u32 => PeDebugType::COFF,
+    2_u32 => PeDebugType::CodeView,
+    3_u32 => PeDebugType::FPO,
+    4_u32 => PeDebugType::Misc,
+    5_u32 => PeDebugType::Exception,
+    6_u32 => PeDebugType::Fixup,
+    7_u32 => PeDebugType::OmapToSrc,
+    8_u32 => PeDebugType::OmapFromSrc,
+    9_u32 => PeDebugType::Borland,
+    10_u32 => PeDebugType::Reserved10,
+    11_u32 => PeDebugType::CLSID,
+    12_u32 => PeDebugType::VCFeature,
+    13_u32 => PeDebugType::POGO,
+    14_u32 => PeDebugType::ILTCG,
+    15_u32 => PeDebugType::MPX,
+    16_u32 => PeDebugType::Repro,
+    17_u32 => PeDebugType::EmbeddedPortablePDB,
+    18_u32 => PeDebugType::SPGO,
+    19_u32 => PeDebugType::PDBChecksum,
+    20_u32 => PeDebugType::ExDllCharacteristics,
+};
+
+// Source: https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#extended-dll-characteristics
+#[derive(Debug)]
+pub enum PeExDllCharacteristic {
+    CETCompatible,
+    CETStrictMode,
+    CETSetContextIPValidationRelaxedMode,
+    CETDynamicAPIsAllowInProc,
+    CETReserved1,
+    CETReserved2,
+    ForwardCFICompatible,
+    HotpatchCompatible,
+}
+
+// Source: https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#extended-dll-characteristics
+pub const PE_EX_DLL_CHARACTERISTICS: phf::Map<u32, PeExDllCharacteristic> = phf_map! {
+    0x01_u32 => PeExDllCharacteristic::CETCompatible,
+    0x02_u32 => PeExDllCharacteristic::CETStrictMode,
+    0x04_u32 => PeExDllCharacteristic::CETSetContextIPValidationRelaxedMode,
+    0x08_u32 => PeExDllCharacteristic::CETDynamicAPIsAllowInProc,
+    0x10_u32 => PeExDllCharacteristic::CETReserved1,
+    0x20_u32 => PeExDllCharacteristic::CETReserved2,
+    0x40_u32 => PeExDllCharacteristic::ForwardCFICompatible,
+    0x80_u32 => PeExDllCharacteristic::HotpatchCompatible,
+};
+
+// Source: https://learn.microsoft.com/en-us/windows/win32/secbp/pe-metadata
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub enum PeGuardFlag {
+    CFInstrumented,
+    CFWInstrumented,
+    CFFunctionTablePresent,
+    SecurityCookieUnused,
+    ProtectDelayLoadIAT,
+    DelayLoadIATInItsOwnSection,
+    CFExportSuppressionInfoPresent,
+    CFEnableExportSuppression,
+    CFLongJumpTablePresent,
+    RFInstrumented,
+    RFEnable,
+    RFStrict,
+    RetpolinePresent,
+    EHContinuationTablePresent,
+    XFGEnabled,
+    CastGuardPresent,
+    MemcpyPresent,
+}
+
+// Source: https://learn.microsoft.com/en-us/windows/win32/secbp/pe-metadata
+pub const PE_GUARD_FLAGS: phf::Map<u32, PeGuardFlag> = phf_map! {
+    0x0000_0100_u32 => PeGuardFlag::CFInstrumented,
+    0x0000_0200_u32 => PeGuardFlag::CFWInstrumented,
+    0x0000_0400_u32 => PeGuardFlag::CFFunctionTablePresent,
+    0x0000_0800_u32 => PeGuardFlag::SecurityCookieUnused,
+    0x0000_1000_u32 => PeGuardFlag::ProtectDelayLoadIAT,
+    0x0000_2000_u32 => PeGuardFlag::DelayLoadIATInItsOwnSection,
+    0x0000_4000_u32 => PeGuardFlag::CFExportSuppressionInfoPresent,
+    0x0000_8000_u32 => PeGuardFlag::CFEnableExportSuppression,
+    0x0001_0000_u32 => PeGuardFlag::CFLongJumpTablePresent,
+    0x0002_0000_u32 => PeGuardFlag::RFInstrumented,
+    0x0004_0000_u32 => PeGuardFlag::RFEnable,
+    0x0008_0000_u32 => PeGuardFlag::RFStrict,
+    0x0010_0000_u32 => PeGuardFlag::RetpolinePresent,
+    0x0040_0000_u32 => PeGuardFlag::EHContinuationTablePresent,
+    0x0080_0000_u32 => PeGuardFlag::XFGEnabled,
+    0x0100_0000_u32 => PeGuardFlag::CastGuardPresent,
+    0x0200_0000_u32 => PeGuardFlag::MemcpyPresent,
+};
+
+/*
+Sources:
+    https://github.com/erocarrera/pefile/blob/master/ordlookup/ws2_32.py
+    https://github.com/erocarrera/pefile/blob/master/ordlookup/oleaut32.py
+The tables are the same as the ones of pefile, so the other ordinals are hashed as "ordN" like pefile does.
+*/
+pub const WS2_32_ORDINALS: phf::Map<u16, &'static str> = phf_map! {
+    1_u16 => "accept",
+    2_u16 => "bind",
+    3_u16 => "closesocket",
+    4_u16 => "connect",
+    5_u16 => "getpeername",
+    6_u16 => "getsockname",
+    7_u16 => "getsockopt",
+    8_u16 => "htonl",
+    9_u16 => "htons",
+    10_u16 => "ioctlsocket",
+    11_u16 => "inet_addr",
+    12_u16 => "inet_ntoa",
+    13_u16 => "listen",
+    14_u16 => "ntohl",
+    15_u16 => "ntohs",
+    16_u16 => "recv",
+    17_u16 => "recvfrom",
+    18_u16 => "select",
+    19_u16 => "send",
+    20_u16 => "sendto",
+    21_u16 => "setsockopt",
+    22_u16 => "shutdown",
+    23_u16 => "socket",
+    24_u16 => "GetAddrInfoW",
+    25_u16 => "GetNameInfoW",
+    26_u16 => "WSApSetPostRoutine",
+    27_u16 => "FreeAddrInfoW",
+    28_u16 => "WPUCompleteOverlappedRequest",
+    29_u16 => "WSAAccept",
+    30_u16 => "WSAAddressToStringA",
+    31_u16 => "WSAAddressToStringW",
+    32_u16 => "WSACloseEvent",
+    33_u16 => "WSAConnect",
+    34_u16 => "WSACreateEvent",
+    35_u16 => "WSADuplicateSocketA",
+    36_u16 => "WSADuplicateSocketW",
+    37_u16 => "WSAEnumNameSpaceProvidersA",
+    38_u16 => "WSAEnumNameSpaceProvidersW",
+    39_u16 => "WSAEnumNetworkEvents",
+    40_u16 => "WSAEnumProtocolsA",
+    41_u16 => "WSAEnumProtocolsW",
+    42_u16 => "WSAEventSelect",
+    43_u16 => "WSAGetOverlappedResult",
+    44_u16 => "WSAGetQOSByName",
+    45_u16 => "WSAGetServiceClassInfoA",
+    46_u16 => "WSAGetServiceClassInfoW",
+    47_u16 => "WSAGetServiceClassNameByClassIdA",
+    48_u16 => "WSAGetServiceClassNameByClassIdW",
+    49_u16 => "WSAHtonl",
+    50_u16 => "WSAHtons",
+    51_u16 => "gethostbyaddr",
+    52_u16 => "gethostbyname",
+    53_u16 => "getprotobyname",
+    54_u16 => "getprotobynumber",
+    55_u16 => "getservbyname",
+    56_u16 => "getservbyport",
+    57_u16 => "gethostname",
+    58_u16 => "WSAInstallServiceClassA",
+    59_u16 => "WSAInstallServiceClassW",
+    60_u16 => "WSAIoctl",
+    61_u16 => "WSAJoinLeaf",
+    62_u16 => "WSALookupServiceBeginA",
+    63_u16 => "WSALookupServiceBeginW",
+    64_u16 => "WSALookupServiceEnd",
+    65_u16 => "WSALookupServiceNextA",
+    66_u16 => "WSALookupServiceNextW",
+    67_u16 => "WSANSPIoctl",
+    68_u16 => "WSANtohl",
+    69_u16 => "WSANtohs",
+    70_u16 => "WSAProviderConfigChange",
+    71_u16 => "WSARecv",
+    72_u16 => "WSARecvDisconnect",
+    73_u16 => "WSARecvFrom",
+    74_u16 => "WSARemoveServiceClass",
+    75_u16 => "WSAResetEvent",
+    76_u16 => "WSASend",
+    77_u16 => "WSASendDisconnect",
+    78_u16 => "WSASendTo",
+    79_u16 => "WSASetEvent",
+    80_u16 => "WSASetServiceA",
+    81_u16 => "WSASetServiceW",
+    82_u16 => "WSASocketA",
+    83_u16 => "WSASocketW",
+    84_u16 => "WSAStringToAddressA",
+    85_u16 => "WSAStringToAddressW",
+    86_u16 => "WSAWaitForMultipleEvents",
+    87_u16 => "WSCDeinstallProvider",
+    88_u16 => "WSCEnableNSProvider",
+    89_u16 => "WSCEnumProtocols",
+    90_u16 => "WSCGetProviderPath",
+    91_u16 => "WSCInstallNameSpace",
+    92_u16 => "WSCInstallProvider",
+    93_u16 => "WSCUnInstallNameSpace",
+    94_u16 => "WSCUpdateProvider",
+    95_u16 => "WSCWriteNameSpaceOrder",
+    96_u16 => "WSCWriteProviderOrder",
+    97_u16 => "freeaddrinfo",
+    98_u16 => "getaddrinfo",
+    99_u16 => "getnameinfo",
+    101_u16 => "WSAAsyncSelect",
+    102_u16 => "WSAAsyncGetHostByAddr",
+    103_u16 => "WSAAsyncGetHostByName",
+    104_u16 => "WSAAsyncGetProtoByNumber",
+    105_u16 => "WSAAsyncGetProtoByName",
+    106_u16 => "WSAAsyncGetServByPort",
+    107_u16 => "WSAAsyncGetServByName",
+    108_u16 => "WSACancelAsyncRequest",
+    109_u16 => "WSASetBlockingHook",
+    110_u16 => "WSAUnhookBlockingHook",
+    111_u16 => "WSAGetLastError",
+    112_u16 => "WSASetLastError",
+    113_u16 => "WSACancelBlockingCall",
+    114_u16 => "WSAIsBlocking",
+    115_u16 => "WSAStartup",
+    116_u16 => "WSACleanup",
+    151_u16 => "__WSAFDIsSet",
+    500_u16 => "WEP",
+};
+
+pub const OLEAUT32_ORDINALS: phf::Map<u16, &'static str> = phf_map! {
+    2_u16 => "SysAllocString",
+    3_u16 => "SysReAllocString",
+    4_u16 => "SysAllocStringLen",
+    5_u16 => "SysReAllocStringLen",
+    6_u16 => "SysFreeString",
+    7_u16 => "SysStringLen",
+    8_u16 => "VariantInit",
+    9_u16 => "VariantClear",
+    10_u16 => "VariantCopy",
+    11_u16 => "VariantCopyInd",
+    12_u16 => "VariantChangeType",
+    13_u16 => "VariantTimeToDosDateTime",
+    14_u16 => "DosDateTimeToVariantTime",
+    15_u16 => "SafeArrayCreate",
+    16_u16 => "SafeArrayDestroy",
+    17_u16 => "SafeArrayGetDim",
+    18_u16 => "SafeArrayGetElemsize",
+    19_u16 => "SafeArrayGetUBound",
+    20_u16 => "SafeArrayGetLBound",
+    21_u16 => "SafeArrayLock",
+    22_u16 => "SafeArrayUnlock",
+    23_u16 => "SafeArrayAccessData",
+    24_u16 => "SafeArrayUnaccessData",
+    25_u16 => "SafeArrayGetElement",
+    26_u16 => "SafeArrayPutElement",
+    27_u16 => "SafeArrayCopy",
+    28_u16 => "DispGetParam",
+    29_u16 => "DispGetIDsOfNames",
+    30_u16 => "DispInvoke",
+    31_u16 => "CreateDispTypeInfo",
+    32_u16 => "CreateStdDispatch",
+    33_u16 => "RegisterActiveObject",
+    34_u16 => "RevokeActiveObject",
+    35_u16 => "GetActiveObject",
+    36_u16 => "SafeArrayAllocDescriptor",
+    37_u16 => "SafeArrayAllocData",
+    38_u16 => "SafeArrayDestroyDescriptor",
+    39_u16 => "SafeArrayDestroyData",
+    40_u16 => "SafeArrayRedim",
+    41_u16 => "SafeArrayAllocDescriptorEx",
+    42_u16 => "SafeArrayCreateEx",
+    43_u16 => "SafeArrayCreateVectorEx",
+    44_u16 => "SafeArraySetRecordInfo",
+    45_u16 => "SafeArrayGetRecordInfo",
+    46_u16 => "VarParseNumFromStr",
+    47_u16 => "VarNumFromParseNum",
+    48_u16 => "VarI2FromUI1",
+    49_u16 => "VarI2FromI4",
+    50_u16 => "VarI2FromR4",
+    51_u16 => "VarI2FromR8",
+    52_u16 => "VarI2FromCy",
+    53_u16 => "VarI2FromDate",
+    54_u16 => "VarI2FromStr",
+    55_u16 => "VarI2FromDisp",
+    56_u16 => "VarI2FromBool",
+    57_u16 => "SafeArraySetIID",
+    58_u16 => "VarI4FromUI1",
+    59_u16 => "VarI4FromI2",
+    60_u16 => "VarI4FromR4",
+    61_u16 => "VarI4FromR8",
+    62_u16 => "VarI4FromCy",
+    63_u16 => "VarI4FromDate",
+    64_u16 => "VarI4FromStr",
+    65_u16 => "VarI4FromDisp",
+    66_u16 => "VarI4FromBool",
+    67_u16 => "SafeArrayGetIID",
+    68_u16 => "VarR4FromUI1",
+    69_u16 => "VarR4FromI2",
+    70_u16 => "VarR4FromI4",
+    71_u16 => "VarR4FromR8",
+    72_u16 => "VarR4FromCy",
+    73_u16 => "VarR4FromDate",
+    74_u16 => "VarR4FromStr",
+    75_u16 => "VarR4FromDisp",
+    76_u16 => "VarR4FromBool",
+    77_u16 => "SafeArrayGetVartype",
+    78_u16 => "VarR8FromUI1",
+    79_u16 => "VarR8FromI2",
+    80_u16 => "VarR8FromI4",
+    81_u16 => "VarR8FromR4",
+    82_u16 => "VarR8FromCy",
+    83_u16 => "VarR8FromDate",
+    84_u16 => "VarR8FromStr",
+    85_u16 => "VarR8FromDisp",
+    86_u16 => "VarR8FromBool",
+    87_u16 => "VarFormat",
+    88_u16 => "VarDateFromUI1",
+    89_u16 => "VarDateFromI2",
+    90_u16 => "VarDateFromI4",
+    91_u16 => "VarDateFromR4",
+    92_u16 => "VarDateFromR8",
+    93_u16 => "VarDateFromCy",
+    94_u16 => "VarDateFromStr",
+    95_u16 => "VarDateFromDisp",
+    96_u16 => "VarDateFromBool",
+    97_u16 => "VarFormatDateTime",
+    98_u16 => "VarCyFromUI1",
+    99_u16 => "VarCyFromI2",
+    100_u16 => "VarCyFromI4",
+    101_u16 => "VarCyFromR4",
+    102_u16 => "VarCyFromR8",
+    103_u16 => "VarCyFromDate",
+    104_u16 => "VarCyFromStr",
+    105_u16 => "VarCyFromDisp",
+    106_u16 => "VarCyFromBool",
+    107_u16 => "VarFormatNumber",
+    108_u16 => "VarBstrFromUI1",
+    109_u16 => "VarBstrFromI2",
+    110_u16 => "VarBstrFromI4",
+    111_u16 => "VarBstrFromR4",
+    112_u16 => "VarBstrFromR8",
+    113_u16 => "VarBstrFromCy",
+    114_u16 => "VarBstrFromDate",
+    115_u16 => "VarBstrFromDisp",
+    116_u16 => "VarBstrFromBool",
+    117_u16 => "VarFormatPercent",
+    118_u16 => "VarBoolFromUI1",
+    119_u16 => "VarBoolFromI2",
+    120_u16 => "VarBoolFromI4",
+    121_u16 => "VarBoolFromR4",
+    122_u16 => "VarBoolFromR8",
+    123_u16 => "VarBoolFromDate",
+    124_u16 => "VarBoolFromCy",
+    125_u16 => "VarBoolFromStr",
+    126_u16 => "VarBoolFromDisp",
+    127_u16 => "VarFormatCurrency",
+    128_u16 => "VarWeekdayName",
+    129_u16 => "VarMonthName",
+    130_u16 => "VarUI1FromI2",
+    131_u16 => "VarUI1FromI4",
+    132_u16 => "VarUI1FromR4",
+    133_u16 => "VarUI1FromR8",
+    134_u16 => "VarUI1FromCy",
+    135_u16 => "VarUI1FromDate",
+    136_u16 => "VarUI1FromStr",
+    137_u16 => "VarUI1FromDisp",
+    138_u16 => "VarUI1FromBool",
+    139_u16 => "VarFormatFromTokens",
+    140_u16 => "VarTokenizeFormatString",
+    141_u16 => "VarAdd",
+    142_u16 => "VarAnd",
+    143_u16 => "VarDiv",
+    144_u16 => "DllCanUnloadNow",
+    145_u16 => "DllGetClassObject",
+    146_u16 => "DispCallFunc",
+    147_u16 => "VariantChangeTypeEx",
+    148_u16 => "SafeArrayPtrOfIndex",
+    149_u16 => "SysStringByteLen",
+    150_u16 => "SysAllocStringByteLen",
+    151_u16 => "DllRegisterServer",
+    152_u16 => "VarEqv",
+    153_u16 => "VarIdiv",
+    154_u16 => "VarImp",
+    155_u16 => "VarMod",
+    156_u16 => "VarMul",
+    157_u16 => "VarOr",
+    158_u16 => "VarPow",
+    159_u16 => "VarSub",
+    160_u16 => "CreateTypeLib",
+    161_u16 => "LoadTypeLib",
+    162_u16 => "LoadRegTypeLib",
+    163_u16 => "RegisterTypeLib",
+    164_u16 => "QueryPathOfRegTypeLib",
+    165_u16 => "LHashValOfNameSys",
+    166_u16 => "LHashValOfNameSysA",
+    167_u16 => "VarXor",
+    168_u16 => "VarAbs",
+    169_u16 => "VarFix",
+    170_u16 => "OaBuildVersion",
+    171_u16 => "ClearCustData",
+    172_u16 => "VarInt",
+    173_u16 => "VarNeg",
+    174_u16 => "VarNot",
+    175_u16 => "VarRound",
+    176_u16 => "VarCmp",
+    177_u16 => "VarDecAdd",
+    178_u16 => "VarDecDiv",
+    179_u16 => "VarDecMul",
+    180_u16 => "CreateTypeLib2",
+    181_u16 => "VarDecSub",
+    182_u16 => "VarDecAbs",
+    183_u16 => "LoadTypeLibEx",
+    184_u16 => "SystemTimeToVariantTime",
+    185_u16 => "VariantTimeToSystemTime",
+    186_u16 => "UnRegisterTypeLib",
+    187_u16 => "VarDecFix",
+    188_u16 => "VarDecInt",
+    189_u16 => "VarDecNeg",
+    190_u16 => "VarDecFromUI1",
+    191_u16 => "VarDecFromI2",
+    192_u16 => "VarDecFromI4",
+    193_u16 => "VarDecFromR4",
+    194_u16 => "VarDecFromR8",
+    195_u16 => "VarDecFromDate",
+    196_u16 => "VarDecFromCy",
+    197_u16 => "VarDecFromStr",
+    198_u16 => "VarDecFromDisp",
+    199_u16 => "VarDecFromBool",
+    200_u16 => "GetErrorInfo",
+    201_u16 => "SetErrorInfo",
+    202_u16 => "CreateErrorInfo",
+    203_u16 => "VarDecRound",
+    204_u16 => "VarDecCmp",
+    205_u16 => "VarI2FromI1",
+    206_u16 => "VarI2FromUI2",
+    207_u16 => "VarI2FromUI4",
+    208_u16 => "VarI2FromDec",
+    209_u16 => "VarI4FromI1",
+    210_u16 => "VarI4FromUI2",
+    211_u16 => "VarI4FromUI4",
+    212_u16 => "VarI4FromDec",
+    213_u16 => "VarR4FromI1",
+    214_u16 => "VarR4FromUI2",
+    215_u16 => "VarR4FromUI4",
+    216_u16 => "VarR4FromDec",
+    217_u16 => "VarR8FromI1",
+    218_u16 => "VarR8FromUI2",
+    219_u16 => "VarR8FromUI4",
+    220_u16 => "VarR8FromDec",
+    221_u16 => "VarDateFromI1",
+    222_u16 => "VarDateFromUI2",
+    223_u16 => "VarDateFromUI4",
+    224_u16 => "VarDateFromDec",
+    225_u16 => "VarCyFromI1",
+    226_u16 => "VarCyFromUI2",
+    227_u16 => "VarCyFromUI4",
+    228_u16 => "VarCyFromDec",
+    229_u16 => "VarBstrFromI1",
+    230_u16 => "VarBstrFromUI2",
+    231_u16 => "VarBstrFromUI4",
+    232_u16 => "VarBstrFromDec",
+    233_u16 => "VarBoolFromI1",
+    234_u16 => "VarBoolFromUI2",
+    235_u16 => "VarBoolFromUI4",
+    236_u16 => "VarBoolFromDec",
+    237_u16 => "VarUI1FromI1",
+    238_u16 => "VarUI1FromUI2",
+    239_u16 => "VarUI1FromUI4",
+    240_u16 => "VarUI1FromDec",
+    241_u16 => "VarDecFromI1",
+    242_u16 => "VarDecFromUI2",
+    243_u16 => "VarDecFromUI4",
+    244_u16 => "VarI1FromUI1",
+    245_u16 => "VarI1FromI2",
+    246_u16 => "VarI1FromI4",
+    247_u16 => "VarI1FromR4",
+    248_u16 => "VarI1FromR8",
+    249_u16 => "VarI1FromDate",
+    250_u16 => "VarI1FromCy",
+    251_u16 => "VarI1FromStr",
+    252_u16 => "VarI1FromDisp",
+    253_u16 => "VarI1FromBool",
+    254_u16 => "VarI1FromUI2",
+    255_u16 => "VarI1FromUI4",
+    256_u16 => "VarI1FromDec",
+    257_u16 => "VarUI2FromUI1",
+    258_u16 => "VarUI2FromI2",
+    259_u16 => "VarUI2FromI4",
+    260_u16 => "VarUI2FromR4",
+    261_u16 => "VarUI2FromR8",
+    262_u16 => "VarUI2FromDate",
+    263_u16 => "VarUI2FromCy",
+    264_u16 => "VarUI2FromStr",
+    265_u16 => "VarUI2FromDisp",
+    266_u16 => "VarUI2FromBool",
+    267_u16 => "VarUI2FromI1",
+    268_u16 => "VarUI2FromUI4",
+    269_u16 => "VarUI2FromDec",
+    270_u16 => "VarUI4FromUI1",
+    271_u16 => "VarUI4FromI2",
+    272_u16 => "VarUI4FromI4",
+    273_u16 => "VarUI4FromR4",
+    274_u16 => "VarUI4FromR8",
+    275_u16 => "VarUI4FromDate",
+    276_u16 => "VarUI4FromCy",
+    277_u16 => "VarUI4FromStr",
+    278_u16 => "VarUI4FromDisp",
+    279_u16 => "VarUI4FromBool",
+    280_u16 => "VarUI4FromI1",
+    281_u16 => "VarUI4FromUI2",
+    282_u16 => "VarUI4FromDec",
+    283_u16 => "BSTR_UserSize",
+    284_u16 => "BSTR_UserMarshal",
+    285_u16 => "BSTR_UserUnmarshal",
+    286_u16 => "BSTR_UserFree",
+    287_u16 => "VARIANT_UserSize",
+    288_u16 => "VARIANT_UserMarshal",
+    289_u16 => "VARIANT_UserUnmarshal",
+    290_u16 => "VARIANT_UserFree",
+    291_u16 => "LPSAFEARRAY_UserSize",
+    292_u16 => "LPSAFEARRAY_UserMarshal",
+    293_u16 => "LPSAFEARRAY_UserUnmarshal",
+    294_u16 => "LPSAFEARRAY_UserFree",
+    295_u16 => "LPSAFEARRAY_Size",
+    296_u16 => "LPSAFEARRAY_Marshal",
+    297_u16 => "LPSAFEARRAY_Unmarshal",
+    298_u16 => "VarDecCmpR8",
+    299_u16 => "VarCyAdd",
+    300_u16 => "DllUnregisterServer",
+    301_u16 => "OACreateTypeLib2",
+    303_u16 => "VarCyMul",
+    304_u16 => "VarCyMulI4",
+    305_u16 => "VarCySub",
+    306_u16 => "VarCyAbs",
+    307_u16 => "VarCyFix",
+    308_u16 => "VarCyInt",
+    309_u16 => "VarCyNeg",
+    310_u16 => "VarCyRound",
+    311_u16 => "VarCyCmp",
+    312_u16 => "VarCyCmpR8",
+    313_u16 => "VarBstrCat",
+    314_u16 => "VarBstrCmp",
+    315_u16 => "VarR8Pow",
+    316_u16 => "VarR4CmpR8",
+    317_u16 => "VarR8Round",
+    318_u16 => "VarCat",
+    319_u16 => "VarDateFromUdateEx",
+    322_u16 => "GetRecordInfoFromGuids",
+    323_u16 => "GetRecordInfoFromTypeInfo",
+    325_u16 => "SetVarConversionLocaleSetting",
+    326_u16 => "GetVarConversionLocaleSetting",
+    327_u16 => "SetOaNoCache",
+    329_u16 => "VarCyMulI8",
+    330_u16 => "VarDateFromUdate",
+    331_u16 => "VarUdateFromDate",
+    332_u16 => "GetAltMonthNames",
+    333_u16 => "VarI8FromUI1",
+    334_u16 => "VarI8FromI2",
+    335_u16 => "VarI8FromR4",
+    336_u16 => "VarI8FromR8",
+    337_u16 => "VarI8FromCy",
+    338_u16 => "VarI8FromDate",
+    339_u16 => "VarI8FromStr",
+    340_u16 => "VarI8FromDisp",
+    341_u16 => "VarI8FromBool",
+    342_u16 => "VarI8FromI1",
+    343_u16 => "VarI8FromUI2",
+    344_u16 => "VarI8FromUI4",
+    345_u16 => "VarI8FromDec",
+    346_u16 => "VarI2FromI8",
+    347_u16 => "VarI2FromUI8",
+    348_u16 => "VarI4FromI8",
+    349_u16 => "VarI4FromUI8",
+    360_u16 => "VarR4FromI8",
+    361_u16 => "VarR4FromUI8",
+    362_u16 => "VarR8FromI8",
+    363_u16 => "VarR8FromUI8",
+    364_u16 => "VarDateFromI8",
+    365_u16 => "VarDateFromUI8",
+    366_u16 => "VarCyFromI8",
+    367_u16 => "VarCyFromUI8",
+    368_u16 => "VarBstrFromI8",
+    369_u16 => "VarBstrFromUI8",
+    370_u16 => "VarBoolFromI8",
+    371_u16 => "VarBoolFromUI8",
+    372_u16 => "VarUI1FromI8",
+    373_u16 => "VarUI1FromUI8",
+    374_u16 => "VarDecFromI8",
+    375_u16 => "VarDecFromUI8",
+    376_u16 => "VarI1FromI8",
+    377_u16 => "VarI1FromUI8",
+    378_u16 => "VarUI2FromI8",
+    379_u16 => "VarUI2FromUI8",
+    401_u16 => "OleLoadPictureEx",
+    402_u16 => "OleLoadPictureFileEx",
+    411_u16 => "SafeArrayCreateVector",
+    412_u16 => "SafeArrayCopyData",
+    413_u16 => "VectorFromBstr",
+    414_u16 => "BstrFromVector",
+    415_u16 => "OleIconToCursor",
+    416_u16 => "OleCreatePropertyFrameIndirect",
+    417_u16 => "OleCreatePropertyFrame",
+    418_u16 => "OleLoadPicture",
+    419_u16 => "OleCreatePictureIndirect",
+    420_u16 => "OleCreateFontIndirect",
+    421_u16 => "OleTranslateColor",
+    422_u16 => "OleLoadPictureFile",
+    423_u16 => "OleSavePictureFile",
+    424_u16 => "OleLoadPicturePath",
+    425_u16 => "VarUI4FromI8",
+    426_u16 => "VarUI4FromUI8",
+    427_u16 => "VarI8FromUI8",
+    428_u16 => "VarUI8FromI8",
+    429_u16 => "VarUI8FromUI1",
+    430_u16 => "VarUI8FromI2",
+    431_u16 => "VarUI8FromR4",
+    432_u16 => "VarUI8FromR8",
+    433_u16 => "VarUI8FromCy",
+    434_u16 => "VarUI8FromDate",
+    435_u16 => "VarUI8FromStr",
+    436_u16 => "VarUI8FromDisp",
+    437_u16 => "VarUI8FromBool",
+    438_u16 => "VarUI8FromI1",
+    439_u16 => "VarUI8FromUI2",
+    440_u16 => "VarUI8FromUI4",
+    441_u16 => "VarUI8FromDec",
+    442_u16 => "RegisterTypeLibForUser",
+    443_u16 => "UnRegisterTypeLibForUser",
+};
+
+// Source: https://github.com/torvalds/linux/blob/master/include/uapi/linux/auxvec.h
+pub const ELF_AUXV_TYPE: phf::Map<u64, &'static str> = phf_map! {
+    2_u64 => "AT_EXECFD",
+    3_u64 => "AT_PHDR",
+    4_u64 => "AT_PHENT",
+    5_u64 => "AT_PHNUM",
+    6_u64 => "AT_PAGESZ",
+    7_u64 => "AT_BASE",
+    8_u64 => "AT_FLAGS",
+    9_u64 => "AT_ENTRY",
+    10_u64 => "AT_NOTELF",
+    11_u64 => "AT_UID",
+    12_u64 => "AT_EUID",
+    13_u64 => "AT_GID",
+    14_u64 => "AT_EGID",
+    15_u64 => "AT_PLATFORM",
+    16_u64 => "AT_HWCAP",
+    17_u64 => "AT_CLKTCK",
+    23_u64 => "AT_SECURE",
+    24_u64 => "AT_BASE_PLATFORM",
+    25_u64 => "AT_RANDOM",
+    26_u64 => "AT_HWCAP2",
+    27_u64 => "AT_RSEQ_FEATURE_SIZE",
+    28_u64 => "AT_RSEQ_ALIGN",
+    29_u64 => "AT_HWCAP3",
+    30_u64 => "AT_HWCAP4",
+    31_u64 => "AT_EXECFN",
+    32_u64 => "AT_SYSINFO",
+    33_u64 => "AT_SYSINFO_EHDR",
+    51_u64 => "AT_MINSIGSTKSZ",
+};
+
+// Source: https://refspecs.linuxfoundation.org/elf/gabi4+/ch5.dynamic.html
+pub const ELF_DYNAMIC_FLAGS: phf::Map<u64, &'static str> = phf_map! {
+    0x0000_0001_u64 => "ORIGIN",
+    0x0000_0002_u64 => "SYMBOLIC",
+    0x0000_0004_u64 => "TEXTREL",
+    0x0000_0008_u64 => "BIND_NOW",
+    0x0000_0010_u64 => "STATIC_TLS",
+};
+
+// Source: https://docs.oracle.com/cd/E23824_01/html/819-0690/chapter6-42444.html
+pub const ELF_DYNAMIC_FLAGS_1: phf::Map<u64, &'static str> = phf_map! {
+    0x0000_0001_u64 => "NOW",
+    0x0000_0002_u64 => "GLOBAL",
+    0x0000_0004_u64 => "GROUP",
+    0x0000_0008_u64 => "NODELETE",
+    0x0000_0010_u64 => "LOADFLTR",
+    0x0000_0020_u64 => "INITFIRST",
+    0x0000_0040_u64 => "NOOPEN",
+    0x0000_0080_u64 => "ORIGIN",
+    0x0000_0100_u64 => "DIRECT",
+    0x0000_0200_u64 => "TRANS",
+    0x0000_0400_u64 => "INTERPOSE",
+    0x0000_0800_u64 => "NODEFLIB",
+    0x0000_1000_u64 => "NODUMP",
+    0x0000_2000_u64 => "CONFALT",
+    0x0000_4000_u64 => "ENDFILTEE",
+    0x0000_8000_u64 => "DISPRELDNE",
+    0x0001_0000_u64 => "DISPRELPND",
+    0x0002_0000_u64 => "NODIRECT",
+    0x0004_0000_u64 => "IGNMULDEF",
+    0x0008_0000_u64 => "NOKSYMS",
+    0x0010_0000_u64 => "NOHDR",
+    0x0020_0000_u64 => "EDITED",
+    0x0040_0000_u64 => "NORELOC",
+    0x0080_0000_u64 => "SYMINTPOSE",
+    0x0100_0000_u64 => "GLOBAUDIT",
+    0x0200_0000_u64 => "SINGLETON",
+    0x0400_0000_u64 => "STUB",
+    0x0800_0000_u64 => "PIE",
+    0x1000_0000_u64 => "KMOD",
+    0x2000_0000_u64 => "WEAKFILTER",
+    0x4000_0000_u64 => "NOCOMMON",
+};
+
+/// How much of the symbols and the debug information is left in an object.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StripStatus {
+    FullyStripped,
+    SymbolsOnly,
+    DebugInfo,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DebugInfoFormat {
+    DWARF,
+    PDB,
+    CodeView,
+    COFF,
+    STABS,
+    JavaAttributes,
+    DexDebugInfo,
+}
+
+/// - Embedded: The debug information is inside the object
+/// - Split: The debug information is moved into a separate file which the object points to (Like: PDB and `.gnu_debuglink`)
+/// - Referenced: The debug information is left in other files which the object only names (Like: `N_OSO` stabs and `.gnu_debugaltlink`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DebugInfoLocation {
+    Embedded,
+    Split,
+    Referenced,
+}
+
+/// The symbol mangling schemes of Rust.
+///
+/// - Legacy: Itanium-like names with a hash suffix (Like: `_ZN4core3fmt5write17h0123456789abcdefE`)
+/// - V0: The scheme enabled by `-C symbol-mangling-version=v0` (Like: `_RNvCs1234_7mycrate4main`)
+/// - Mixed: Both of them (Like: a v0 crate linked with the standard library mangled by the legacy scheme)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RustMangling {
+    Legacy,
+    V0,
+    Mixed,
+}
+
+/// The kinds of WebAssembly binaries.
+///
+/// - Module: A core module
+/// - Component: A component of the component model
+/// - RelocatableObject: An object file which has the `linking` custom section for `wasm-ld`
+/// - SharedLibrary: A side module which has the `dylink.0` custom section (Like: Emscripten side modules)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WasmFileType {
+    Module,
+    Component,
+    RelocatableObject,
+    SharedLibrary,
+}
+
+/// The kinds of XCOFF objects, which are decided by the flags of the file header.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum XcoffFileType {
+    Executable,
+    SharedObject,
+    ObjectFile,
+}
+
+// Source: https://github.com/llvm/llvm-project/blob/main/llvm/include/llvm/BinaryFormat/XCOFF.h
+pub const XCOFF_CPU_TYPE: phf::Map<u8, &'static str> = phf_map! {
+    1_u8 => "PowerPC",
+    2_u8 => "PowerPC (64-Bit)",
+    3_u8 => "Common (POWER and PowerPC)",
+    4_u8 => "POWER",
+    5_u8 => "Any",
+    6_u8 => "PowerPC 601",
+    7_u8 => "PowerPC 603",
+    8_u8 => "PowerPC 604",
+    16_u8 => "PowerPC 620",
+    17_u8 => "PowerPC A35",
+    18_u8 => "POWER5",
+    19_u8 => "PowerPC 970",
+    20_u8 => "POWER6",
+    22_u8 => "POWER5+",
+    23_u8 => "POWER6E",
+    24_u8 => "POWER7",
+    25_u8 => "POWER8",
+    26_u8 => "POWER9",
+    27_u8 => "POWER10",
+    224_u8 => "POWERX",
+};
+
+pub const XCOFF_FLAGS: phf::Map<u64, &'static str> = phf_map! {
+    0x0001_u64 => "F_RELFLG",
+    0x0002_u64 => "F_EXEC",
+    0x0004_u64 => "F_LNNO",
+    0x0008_u64 => "F_LSYMS",
+    0x0010_u64 => "F_FDPR_PROF",
+    0x0020_u64 => "F_FDPR_OPTI",
+    0x0040_u64 => "F_DSA",
+    0x0100_u64 => "F_VARPG",
+    0x1000_u64 => "F_DYNLOAD",
+    0x2000_u64 => "F_SHROBJ",
+    0x4000_u64 => "F_LOADONLY",
+};
+
+/// The kinds of NE, LE and LX executables.
+///
+/// - Executable: A program
+/// - DynamicLinkLibrary: A library module (Like: .DLL files of Windows 3.x and OS/2)
+/// - PhysicalDeviceDriver: A physical device driver of OS/2
+/// - VirtualDeviceDriver: A virtual device driver (Like: VxDs of Windows 3.x/9x)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MzFileType {
+    Executable,
+    DynamicLinkLibrary,
+    PhysicalDeviceDriver,
+    VirtualDeviceDriver,
+}
+
+/*
+Sources:
+    https://wiki.osdev.org/NE
+    http://www.edm2.com/index.php/LX_-_Linear_eXecutable_Module_Format_Description
+*/
+
+// NE and LE/LX use the same numbers
+pub const MZ_TARGET_OS: phf::Map<u16, &'static str> = phf_map! {
+    1_u16 => "OS/2",
+    2_u16 => "Windows",
+    3_u16 => "MS-DOS 4.x (European)",
+    4_u16 => "Windows/386",
+    5_u16 => "Borland Operating System Services",
+};
+
+pub const LE_CPU_TYPE: phf::Map<u16, &'static str> = phf_map! {
+    0x01_u16 => "Intel 80286",
+    0x02_u16 => "Intel 386",
+    0x03_u16 => "Intel 486",
+    0x04_u16 => "Intel Pentium",
+    0x20_u16 => "Intel i860 (N10)",
+    0x21_u16 => "Intel i860 (N11)",
+    0x40_u16 => "MIPS Mark I (R2000, R3000)",
+    0x41_u16 => "MIPS Mark II (R6000)",
+    0x42_u16 => "MIPS Mark III (R4000)",
+};
+
+// The program flags are in the low byte and the application flags are in the high byte
+pub const NE_FLAGS: phf::Map<u64, &'static str> = phf_map! {
+    0x0001_u64 => "Single data",
+    0x0002_u64 => "Multiple data",
+    0x0004_u64 => "Global initialization",
+    0x0008_u64 => "Protected mode only",
+    0x0010_u64 => "8086 instructions",
+    0x0020_u64 => "80286 instructions",
+    0x0040_u64 => "80386 instructions",
+    0x0080_u64 => "x87 instructions",
+    0x0800_u64 => "OS/2 family application",
+    0x2000_u64 => "Errors in image",
+    0x4000_u64 => "Non-conforming program",
+    0x8000_u64 => "Library module",
+};
+
+pub const LE_FLAGS: phf::Map<u64, &'static str> = phf_map! {
+    0x0000_0004_u64 => "Per-process library initialization",
+    0x0000_0010_u64 => "Internal fixups removed",
+    0x0000_0020_u64 => "External fixups removed",
+    0x0000_2000_u64 => "Not loadable",
+    0x4000_0000_u64 => "Per-process library termination",
+};
+
+// The application types of NE (bits 8-10) and LE/LX (bits 8-9) flags
+pub const MZ_APPLICATION_TYPE: phf::Map<u16, &'static str> = phf_map! {
+    1_u16 => "Not compatible with Presentation Manager",
+    2_u16 => "Compatible with Presentation Manager",
+    3_u16 => "Uses Presentation Manager",
+};
+
+/// The kinds of a.out executables and objects.
+///
+/// - RelocatableObject: An OMAGIC object file which has relocations
+/// - ImpureExecutable: An OMAGIC executable whose text is writable
+/// - SharedTextExecutable: An NMAGIC executable whose text is read-only and shared
+/// - DemandPagedExecutable: A ZMAGIC or QMAGIC executable which is paged in on demand
+/// - SharedLibrary: A position-independent and dynamically linked BSD library
+/// - Executable: A Plan 9 executable
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AoutFileType {
+    RelocatableObject,
+    ImpureExecutable,
+    SharedTextExecutable,
+    DemandPagedExecutable,
+    SharedLibrary,
+    Executable,
+}
+
+/*
+Sources:
+    https://github.com/NetBSD/src/blob/trunk/sys/sys/exec_aout.h
+    https://github.com/torvalds/linux/blob/v5.0/include/uapi/linux/a.out.h
+*/
+
+// The machine IDs of BSD. Linux uses the same numbers for the machines it supports
+pub const AOUT_MACHINE: phf::Map<u16, &'static str> = phf_map! {
+    1_u16 => "Motorola 68010",
+    2_u16 => "Motorola 68020",
+    100_u16 => "Intel 386",
+    134_u16 => "Intel 386",
+    135_u16 => "Motorola 68000",
+    136_u16 => "Motorola 68000",
+    137_u16 => "National Semiconductor 32532",
+    138_u16 => "SPARC",
+    139_u16 => "MIPS (Little-Endian)",
+    140_u16 => "VAX",
+    141_u16 => "Alpha",
+    142_u16 => "MIPS",
+    143_u16 => "Arm (32-Bit)",
+    144_u16 => "Motorola 68000",
+    145_u16 => "SuperH (SH-3)",
+    148_u16 => "PowerPC (64-Bit)",
+    149_u16 => "PowerPC",
+    150_u16 => "VAX",
+    151_u16 => "MIPS I",
+    152_u16 => "MIPS II",
+    153_u16 => "Motorola 88000",
+    154_u16 => "HP PA-RISC",
+    155_u16 => "SuperH (SH-5, 64-Bit)",
+    156_u16 => "SPARC version 9",
+    157_u16 => "x86-64",
+    158_u16 => "SuperH (SH-5)",
+    159_u16 => "Intel Itanium",
+    160_u16 => "Arm (64-Bit)",
+    161_u16 => "OpenRISC 1000",
+    162_u16 => "RISC-V",
+};
+
+// Source: https://9p.io/magic/man2html/6/a.out
+// The magic numbers of Plan 9 are derived from the architectures. The 68020 (0x107) is left out as it is the same as OMAGIC
+pub const AOUT_PLAN9_MAGIC: phf::Map<u32, &'static str> = phf_map! {
+    0x01eb_u32 => "Intel 386",
+    0x0247_u32 => "Intel 960",
+    0x02ab_u32 => "SPARC",
+    0x0407_u32 => "MIPS R3000",
+    0x048b_u32 => "AT&T DSP3210",
+    0x0517_u32 => "MIPS R4000",
+    0x05ab_u32 => "AMD 29000",
+    0x0647_u32 => "Arm (32-Bit)",
+    0x06eb_u32 => "PowerPC",
+    0x0797_u32 => "MIPS R4000 (Little-Endian)",
+    0x084b_u32 => "Alpha",
+    0x0907_u32 => "MIPS R3000 (Little-Endian)",
+    0x09cb_u32 => "SPARC version 9",
+    0x8a97_u32 => "x86-64",
+    0x8b6b_u32 => "PowerPC (64-Bit)",
+    0x8c47_u32 => "Arm (64-Bit)",
+};
+
+/// The kinds of Java class files.
+///
+/// - Class: A class or a record
+/// - Interface: An interface
+/// - Annotation: An annotation interface
+/// - Enum: An enum class
+/// - Module: A module descriptor (`module-info.class`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JavaFileType {
+    Class,
+    Interface,
+    Annotation,
+    Enum,
+    Module,
+}
+
+// Source: https://docs.oracle.com/javase/specs/jvms/se21/html/jvms-4.html#jvms-4.1-200-E.1
+pub const JAVA_ACCESS_FLAGS: phf::Map<u64, &'static str> = phf_map! {
+    0x0001_u64 => "ACC_PUBLIC",
+    0x0010_u64 => "ACC_FINAL",
+    0x0020_u64 => "ACC_SUPER",
+    0x0200_u64 => "ACC_INTERFACE",
+    0x0400_u64 => "ACC_ABSTRACT",
+    0x1000_u64 => "ACC_SYNTHETIC",
+    0x2000_u64 => "ACC_ANNOTATION",
+    0x4000_u64 => "ACC_ENUM",
+    0x8000_u64 => "ACC_MODULE",
+};
+
+/// The kinds of the files of Android Runtime.
+///
+/// - Dex: A Dalvik executable (`classes.dex`)
+/// - CompactDex: A DEX file which dex2oat shrank to be stored in a VDEX file
+/// - Vdex: A container of the DEX files and their verification results
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DexFileType {
+    Dex,
+    CompactDex,
+    Vdex,
+}
+
+// Source: https://android.googlesource.com/platform/art/+/refs/heads/main/libartbase/arch/instruction_set.h
+pub const ART_INSTRUCTION_SET: phf::Map<u32, &'static str> = phf_map! {
+    1_u32 => "arm",
+    2_u32 => "arm64",
+    // Thumb-2 code is reported as "arm" by ART too
+    3_u32 => "arm",
+    4_u32 => "riscv64",
+    5_u32 => "x86",
+    6_u32 => "x86_64",
+};
+
+// The instruction sets before RISC-V was inserted in the middle (Android 13 and older)
+pub const ART_INSTRUCTION_SET_LEGACY: phf::Map<u32, &'static str> = phf_map! {
+    1_u32 => "arm",
+    2_u32 => "arm64",
+    3_u32 => "arm",
+    4_u32 => "x86",
+    5_u32 => "x86_64",
+    6_u32 => "mips",
+    7_u32 => "mips64",
+};
+
 // Source of the names: https://en.wikipedia.org/wiki/Executable_and_Linkable_Format
 impl Display for ElfOS {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -575,6 +1618,7 @@ impl Display for ElfOS {
             ElfOS::Illumos => write!(f, "Illumos"),
             ElfOS::SerenityOS => write!(f, "SerenityOS"),
             ElfOS::Android => write!(f, "Android"),
+            ElfOS::Haiku => write!(f, "Haiku"),
             ElfOS::Undefined => write!(f, "Undefined"),
         }
     }
@@ -688,6 +1732,8 @@ impl Display for ElfFileType {
             // I couldn't get healthy info. about "OS/CPU specific"
             ElfFileType::OsSpecific => write!(f, "OS-specific"),
             ElfFileType::ProcessorSpecific => write!(f, "CPU-specific"),
+            ElfFileType::KernelModule => write!(f, "Kernel module"),
+            ElfFileType::OatFile => write!(f, "OAT file"),
         }
     }
 }
@@ -767,6 +1813,84 @@ impl Display for PeOS {
     }
 }
 
+// Source: https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#debug-type
+impl Display for PeDebugType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            PeDebugType::Unknown => write!(f, "Unknown"),
+            PeDebugType::COFF => write!(f, "COFF"),
+            PeDebugType::CodeView => write!(f, "CodeView"),
+            PeDebugType::FPO => write!(f, "FPO"),
+            PeDebugType::Misc => write!(f, "Misc"),
+            PeDebugType::Exception => write!(f, "Exception"),
+            PeDebugType::Fixup => write!(f, "Fixup"),
+            PeDebugType::OmapToSrc => write!(f, "OMAP to source"),
+            PeDebugType::OmapFromSrc => write!(f, "OMAP from source"),
+            PeDebugType::Borland => write!(f, "Borland"),
+            PeDebugType::Reserved10 => write!(f, "Reserved"),
+            PeDebugType::CLSID => write!(f, "CLSID"),
+            PeDebugType::VCFeature => write!(f, "VC feature"),
+            PeDebugType::POGO => write!(f, "POGO"),
+            PeDebugType::ILTCG => write!(f, "ILTCG"),
+            PeDebugType::MPX => write!(f, "MPX"),
+            PeDebugType::Repro => write!(f, "Repro"),
+            PeDebugType::EmbeddedPortablePDB => write!(f, "Embedded portable PDB"),
+            PeDebugType::SPGO => write!(f, "SPGO"),
+            PeDebugType::PDBChecksum => write!(f, "PDB checksum"),
+            PeDebugType::ExDllCharacteristics => write!(f, "Extended DLL characteristics"),
+        }
+    }
+}
+
+// Source: https://learn.microsoft.com/en-us/windows/win32/debug/pe-format#extended-dll-characteristics
+impl Display for PeExDllCharacteristic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            PeExDllCharacteristic::CETCompatible => write!(f, "CET compatible"),
+            PeExDllCharacteristic::CETStrictMode => write!(f, "CET strict mode"),
+            PeExDllCharacteristic::CETSetContextIPValidationRelaxedMode => {
+                write!(f, "CET SetContext IP validation relaxed mode")
+            }
+            PeExDllCharacteristic::CETDynamicAPIsAllowInProc => {
+                write!(f, "CET dynamic APIs allowed in process")
+            }
+            PeExDllCharacteristic::CETReserved1 => write!(f, "CET reserved 1"),
+            PeExDllCharacteristic::CETReserved2 => write!(f, "CET reserved 2"),
+            PeExDllCharacteristic::ForwardCFICompatible => write!(f, "Forward CFI compatible"),
+            PeExDllCharacteristic::HotpatchCompatible => write!(f, "Hotpatch compatible"),
+        }
+    }
+}
+
+// Source: https://learn.microsoft.com/en-us/windows/win32/secbp/pe-metadata
+impl Display for PeGuardFlag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            PeGuardFlag::CFInstrumented => write!(f, "CF instrumented"),
+            PeGuardFlag::CFWInstrumented => write!(f, "CFW instrumented"),
+            PeGuardFlag::CFFunctionTablePresent => write!(f, "CF function table present"),
+            PeGuardFlag::SecurityCookieUnused => write!(f, "Security cookie unused"),
+            PeGuardFlag::ProtectDelayLoadIAT => write!(f, "Protect delay load IAT"),
+            PeGuardFlag::DelayLoadIATInItsOwnSection => {
+                write!(f, "Delay load IAT in its own section")
+            }
+            PeGuardFlag::CFExportSuppressionInfoPresent => {
+                write!(f, "CF export suppression info present")
+            }
+            PeGuardFlag::CFEnableExportSuppression => write!(f, "CF export suppression enabled"),
+            PeGuardFlag::CFLongJumpTablePresent => write!(f, "CF long jump table present"),
+            PeGuardFlag::RFInstrumented => write!(f, "RF instrumented"),
+            PeGuardFlag::RFEnable => write!(f, "RF enabled"),
+            PeGuardFlag::RFStrict => write!(f, "RF strict"),
+            PeGuardFlag::RetpolinePresent => write!(f, "Retpoline present"),
+            PeGuardFlag::EHContinuationTablePresent => write!(f, "EH continuation table present"),
+            PeGuardFlag::XFGEnabled => write!(f, "XFG enabled"),
+            PeGuardFlag::CastGuardPresent => write!(f, "CastGuard present"),
+            PeGuardFlag::MemcpyPresent => write!(f, "Memcpy present"),
+        }
+    }
+}
+
 // Source: https://en.wikipedia.org/wiki/Mach-O
 impl Display for MachOArmSubType {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -895,3 +2019,114 @@ impl Display for MachOOs {
         }
     }
 }
+
+impl Display for StripStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            StripStatus::FullyStripped => write!(f, "Fully stripped"),
+            StripStatus::SymbolsOnly => write!(f, "Symbols only"),
+            StripStatus::DebugInfo => write!(f, "Debug info"),
+        }
+    }
+}
+
+impl Display for DebugInfoFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            DebugInfoFormat::DWARF => write!(f, "DWARF"),
+            DebugInfoFormat::PDB => write!(f, "PDB"),
+            DebugInfoFormat::CodeView => write!(f, "CodeView"),
+            DebugInfoFormat::COFF => write!(f, "COFF"),
+            DebugInfoFormat::STABS => write!(f, "STABS"),
+            DebugInfoFormat::JavaAttributes => write!(f, "Java debug attributes"),
+            DebugInfoFormat::DexDebugInfo => write!(f, "DEX debug info"),
+        }
+    }
+}
+
+impl Display for DebugInfoLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            DebugInfoLocation::Embedded => write!(f, "Embedded"),
+            DebugInfoLocation::Split => write!(f, "Split"),
+            DebugInfoLocation::Referenced => write!(f, "Referenced"),
+        }
+    }
+}
+
+impl Display for RustMangling {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            RustMangling::Legacy => write!(f, "Legacy"),
+            RustMangling::V0 => write!(f, "v0"),
+            RustMangling::Mixed => write!(f, "Legacy and v0"),
+        }
+    }
+}
+
+impl Display for WasmFileType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            WasmFileType::Module => write!(f, "Module"),
+            WasmFileType::Component => write!(f, "Component"),
+            WasmFileType::RelocatableObject => write!(f, "Relocatable object"),
+            WasmFileType::SharedLibrary => write!(f, "Shared library"),
+        }
+    }
+}
+
+impl Display for XcoffFileType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            XcoffFileType::Executable => write!(f, "Executable"),
+            XcoffFileType::SharedObject => write!(f, "Shared object"),
+            XcoffFileType::ObjectFile => write!(f, "Object file"),
+        }
+    }
+}
+
+impl Display for MzFileType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            MzFileType::Executable => write!(f, "Executable"),
+            MzFileType::DynamicLinkLibrary => write!(f, "Dynamic-link library"),
+            MzFileType::PhysicalDeviceDriver => write!(f, "Physical device driver"),
+            MzFileType::VirtualDeviceDriver => write!(f, "Virtual device driver"),
+        }
+    }
+}
+
+impl Display for AoutFileType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            AoutFileType::RelocatableObject => write!(f, "Relocatable object"),
+            AoutFileType::ImpureExecutable => write!(f, "Impure executable"),
+            AoutFileType::SharedTextExecutable => write!(f, "Shared text executable"),
+            AoutFileType::DemandPagedExecutable => write!(f, "Demand paged executable"),
+            AoutFileType::SharedLibrary => write!(f, "Shared library"),
+            AoutFileType::Executable => write!(f, "Executable"),
+        }
+    }
+}
+
+impl Display for JavaFileType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            JavaFileType::Class => write!(f, "Class"),
+            JavaFileType::Interface => write!(f, "Interface"),
+            JavaFileType::Annotation => write!(f, "Annotation"),
+            JavaFileType::Enum => write!(f, "Enum"),
+            JavaFileType::Module => write!(f, "Module"),
+        }
+    }
+}
+
+impl Display for DexFileType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            DexFileType::Dex => write!(f, "Dalvik executable"),
+            DexFileType::CompactDex => write!(f, "Compact Dalvik executable"),
+            DexFileType::Vdex => write!(f, "Verified DEX container"),
+        }
+    }
+}
@@ -0,0 +1,552 @@
+use crate::{
+    debug::{classify_stripping, debug_objects, merge_members, option_to_c_string, read_c_str},
+    impls::{ErrorToInt, StringToCString},
+    structs::{CharVec, Debugging, ParsingError, ULDDObj, ULDDObjResult, ULDDOptions, WasmInfo},
+    types::{DebugInfoFormat, DebugInfoLocation, WasmFileType},
+};
+use goblin::error::Error;
+use scroll::{Pread, Uleb128};
+use std::ptr::null_mut;
+
+/*
+Sources:
+    https://webassembly.github.io/spec/core/binary/modules.html
+    https://github.com/WebAssembly/component-model/blob/main/design/mvp/Binary.md
+    https://github.com/WebAssembly/tool-conventions/blob/main/ProducersSection.md
+    https://github.com/WebAssembly/tool-conventions/blob/main/Linking.md (linking and target_features)
+    https://github.com/WebAssembly/tool-conventions/blob/main/DynamicLinking.md (dylink.0)
+*/
+
+pub(crate) const WASM_MAGIC: &[u8] = b"\0asm";
+const MODULE_VERSION: u16 = 1;
+const COMPONENT_LAYER: u16 = 1;
+
+const CUSTOM_SECTION: u8 = 0;
+const IMPORT_SECTION: u8 = 2;
+const TABLE_SECTION: u8 = 4;
+const MEMORY_SECTION: u8 = 5;
+const EXPORT_SECTION: u8 = 7;
+const COMPONENT_IMPORT_SECTION: u8 = 10;
+const COMPONENT_EXPORT_SECTION: u8 = 11;
+
+const LIMITS_HAS_MAX: u8 = 0x01;
+const LIMITS_SHARED: u8 = 0x02;
+const LIMITS_MEMORY64: u8 = 0x04;
+
+const WASI_PREVIEW1_MODULES: [&str; 2] = ["wasi_snapshot_preview1", "wasi_unstable"];
+const WASI_PREVIEW2_PREFIX: &str = "wasi:";
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Reader { data, offset: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.offset >= self.data.len()
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        Ok(self.data.gread::<u8>(&mut self.offset)?)
+    }
+
+    fn u64(&mut self) -> Result<u64, Error> {
+        Ok(Uleb128::read(self.data, &mut self.offset)?)
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        u32::try_from(self.u64()?)
+            .map_err(|_| Error::Malformed("A LEB128 number is too big for u32".to_owned()))
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        let bytes = self
+            .offset
+            .checked_add(length)
+            .and_then(|end| self.data.get(self.offset..end))
+            .ok_or_else(|| {
+                Error::Malformed(format!(
+                    "{} bytes at offset {:#x} are out of the section",
+                    length, self.offset
+                ))
+            })?;
+        self.offset += length;
+
+        Ok(bytes)
+    }
+
+    fn name(&mut self) -> Result<String, Error> {
+        let length = self.u32()? as usize;
+        Ok(read_c_str(self.bytes(length)?))
+    }
+}
+
+#[derive(Default)]
+struct WasmModule {
+    version: u32,
+    is_component: bool,
+    module_name: Option<String>,
+    // Module (or interface of a component), field and kind
+    imports: Vec<(String, Option<String>, &'static str)>,
+    exports: Vec<String>,
+    memories: Vec<String>,
+    tables: Vec<String>,
+    custom_sections: Vec<String>,
+    target_features: Vec<String>,
+    producers: Vec<String>,
+    is_memory64: bool,
+    is_relocatable: bool,
+    is_shared_library: bool,
+    debug_info: Vec<(DebugInfoFormat, DebugInfoLocation)>,
+}
+
+fn external_kind(kind: u8) -> &'static str {
+    match kind {
+        0x00 => "function",
+        0x01 => "table",
+        0x02 => "memory",
+        0x03 => "global",
+        0x04 => "tag",
+        _ => "unknown",
+    }
+}
+
+fn component_sort(kind: u8) -> &'static str {
+    match kind {
+        0x00 => "core module",
+        0x01 => "function",
+        0x02 => "value",
+        0x03 => "type",
+        0x04 => "component",
+        0x05 => "instance",
+        _ => "unknown",
+    }
+}
+
+fn reference_type(kind: u8) -> &'static str {
+    match kind {
+        0x70 => "funcref",
+        0x6f => "externref",
+        0x69 => "exnref",
+        _ => "unknown",
+    }
+}
+
+// Memory sizes are counted in 64 KiB pages and table sizes in elements
+fn read_limits(reader: &mut Reader<'_>, unit: &str) -> Result<(String, bool), Error> {
+    let flags = reader.byte()?;
+    let minimum = reader.u64()?;
+    let mut limits = format!("min {} {}", minimum, unit);
+    if flags & LIMITS_HAS_MAX != 0 {
+        limits.push_str(&format!(", max {} {}", reader.u64()?, unit));
+    }
+    if flags & LIMITS_SHARED != 0 {
+        limits.push_str(", shared");
+    }
+    let is_memory64 = flags & LIMITS_MEMORY64 != 0;
+    if is_memory64 {
+        limits.push_str(", 64-bit");
+    }
+
+    Ok((limits, is_memory64))
+}
+
+fn read_memory(
+    reader: &mut Reader<'_>,
+    module: &mut WasmModule,
+    imported: bool,
+) -> Result<(), Error> {
+    let (limits, is_memory64) = read_limits(reader, "pages")?;
+    module.is_memory64 |= is_memory64;
+    module.memories.push(if imported {
+        format!("{} (imported)", limits)
+    } else {
+        limits
+    });
+
+    Ok(())
+}
+
+fn read_table(
+    reader: &mut Reader<'_>,
+    module: &mut WasmModule,
+    imported: bool,
+) -> Result<(), Error> {
+    let element_type = reference_type(reader.byte()?);
+    let (limits, _) = read_limits(reader, "elements")?;
+    let table = format!("{}: {}", element_type, limits);
+    module.tables.push(if imported {
+        format!("{} (imported)", table)
+    } else {
+        table
+    });
+
+    Ok(())
+}
+
+fn read_imports(reader: &mut Reader<'_>, module: &mut WasmModule) -> Result<(), Error> {
+    for _ in 0..reader.u32()? {
+        let module_name = reader.name()?;
+        let field = reader.name()?;
+        let kind = reader.byte()?;
+        match kind {
+            // Type index of the function or the exception tag
+            0x00 => {
+                reader.u32()?;
+            }
+            0x01 => read_table(reader, module, true)?,
+            0x02 => read_memory(reader, module, true)?,
+            // Value type and mutability of the global
+            0x03 => {
+                reader.byte()?;
+                reader.byte()?;
+            }
+            0x04 => {
+                reader.byte()?;
+                reader.u32()?;
+            }
+            _ => {
+                return Err(Error::Malformed(format!(
+                    "Unknown import kind {:#x} of '{}.{}'",
+                    kind, module_name, field
+                )))
+            }
+        }
+        module
+            .imports
+            .push((module_name, Some(field), external_kind(kind)));
+    }
+
+    Ok(())
+}
+
+fn read_exports(reader: &mut Reader<'_>, module: &mut WasmModule) -> Result<(), Error> {
+    for _ in 0..reader.u32()? {
+        let name = reader.name()?;
+        let kind = reader.byte()?;
+        reader.u32()?;
+        module
+            .exports
+            .push(format!("{} ({})", name, external_kind(kind)));
+    }
+
+    Ok(())
+}
+
+// A component-level `externdesc` which follows the names of the imports and the exports
+fn skip_extern_descriptor(reader: &mut Reader<'_>) -> Result<u8, Error> {
+    let kind = reader.byte()?;
+    match kind {
+        // Core modules have an extra 0x11 byte before the type index
+        0x00 => {
+            reader.byte()?;
+            reader.u32()?;
+        }
+        // Bounds of values are either an equality to a value index (0x00) or a value type (0x01),
+        // which is a primitive type in a single byte or a type index, so both are read as LEB128 numbers
+        0x02 => {
+            reader.byte()?;
+            reader.u32()?;
+        }
+        // Bounds of types are either an equality to a type index or a fresh resource type
+        0x03 => {
+            if reader.byte()? == 0x00 {
+                reader.u32()?;
+            }
+        }
+        // Functions, components and instances are followed by their type indexes
+        _ => {
+            reader.u32()?;
+        }
+    }
+
+    Ok(kind)
+}
+
+// The names of the imports and the exports are prefixed by 0x00, or by 0x01 if they are followed by a version suffix
+fn read_component_name(reader: &mut Reader<'_>) -> Result<String, Error> {
+    let prefix = reader.byte()?;
+    let name = reader.name()?;
+    if prefix == 0x01 {
+        reader.name()?;
+    }
+
+    Ok(name)
+}
+
+fn read_component_imports(reader: &mut Reader<'_>, module: &mut WasmModule) -> Result<(), Error> {
+    for _ in 0..reader.u32()? {
+        let name = read_component_name(reader)?;
+        let kind = skip_extern_descriptor(reader)?;
+        module.imports.push((name, None, component_sort(kind)));
+    }
+
+    Ok(())
+}
+
+fn read_component_exports(reader: &mut Reader<'_>, module: &mut WasmModule) -> Result<(), Error> {
+    for _ in 0..reader.u32()? {
+        let name = read_component_name(reader)?;
+        let sort = reader.byte()?;
+        // Core sorts are followed by the kind of the core item
+        if sort == 0x00 {
+            reader.byte()?;
+        }
+        reader.u32()?;
+        if reader.byte()? == 0x01 {
+            skip_extern_descriptor(reader)?;
+        }
+        module
+            .exports
+            .push(format!("{} ({})", name, component_sort(sort)));
+    }
+
+    Ok(())
+}
+
+fn read_custom_section(data: &[u8], module: &mut WasmModule) -> Result<(), Error> {
+    let mut reader = Reader::new(data);
+    let name = reader.name()?;
+    match name.as_str() {
+        // The module name is the subsection 0 of the name section
+        "name" | "component-name" => {
+            while !reader.is_empty() {
+                let id = reader.byte()?;
+                let size = reader.u32()? as usize;
+                let subsection = reader.bytes(size)?;
+                if id == 0 && name == "name" {
+                    module.module_name = Some(Reader::new(subsection).name()?);
+                }
+            }
+        }
+        "producers" => {
+            for _ in 0..reader.u32()? {
+                let field = reader.name()?;
+                for _ in 0..reader.u32()? {
+                    let tool = reader.name()?;
+                    let version = reader.name()?;
+                    let entry = format!("{}: {} {}", field, tool, version);
+                    module.producers.push(entry.trim_end().to_owned());
+                }
+            }
+        }
+        "target_features" => {
+            for _ in 0..reader.u32()? {
+                let prefix = reader.byte()? as char;
+                let feature = reader.name()?;
+                module
+                    .target_features
+                    .push(format!("{}{}", prefix, feature));
+            }
+        }
+        "linking" => module.is_relocatable = true,
+        "dylink" | "dylink.0" => module.is_shared_library = true,
+        // The debug information is split by `wasm-split` or `llvm-objcopy` into the file named by the section
+        "external_debug_info" => module
+            .debug_info
+            .push((DebugInfoFormat::DWARF, DebugInfoLocation::Split)),
+        _ if name.starts_with(".debug_") => module
+            .debug_info
+            .push((DebugInfoFormat::DWARF, DebugInfoLocation::Embedded)),
+        _ => {}
+    }
+    module.custom_sections.push(name);
+
+    Ok(())
+}
+
+fn read_wasm(buffer: &[u8]) -> Result<WasmModule, Error> {
+    let version = buffer.pread_with::<u16>(4, scroll::LE)?;
+    let layer = buffer.pread_with::<u16>(6, scroll::LE)?;
+    let mut module = WasmModule {
+        version: version as u32,
+        is_component: layer == COMPONENT_LAYER,
+        ..Default::default()
+    };
+    if !module.is_component && version != MODULE_VERSION {
+        return Err(Error::Malformed(format!(
+            "Unsupported WebAssembly version {}",
+            version
+        )));
+    }
+
+    let mut reader = Reader::new(buffer);
+    reader.offset = 8;
+    while !reader.is_empty() {
+        let id = reader.byte()?;
+        let size = reader.u32()? as usize;
+        let data = reader.bytes(size)?;
+        let mut section = Reader::new(data);
+        match (module.is_component, id) {
+            (_, CUSTOM_SECTION) => read_custom_section(data, &mut module)?,
+            (false, IMPORT_SECTION) => read_imports(&mut section, &mut module)?,
+            (false, TABLE_SECTION) => {
+                for _ in 0..section.u32()? {
+                    read_table(&mut section, &mut module, false)?;
+                }
+            }
+            (false, MEMORY_SECTION) => {
+                for _ in 0..section.u32()? {
+                    read_memory(&mut section, &mut module, false)?;
+                }
+            }
+            (false, EXPORT_SECTION) => read_exports(&mut section, &mut module)?,
+            (true, COMPONENT_IMPORT_SECTION) => read_component_imports(&mut section, &mut module)?,
+            (true, COMPONENT_EXPORT_SECTION) => read_component_exports(&mut section, &mut module)?,
+            _ => {}
+        }
+    }
+
+    Ok(module)
+}
+
+fn find_wasi(module: &WasmModule) -> Option<(String, Option<String>)> {
+    let preview2 = module
+        .imports
+        .iter()
+        .find(|(name, _, _)| name.starts_with(WASI_PREVIEW2_PREFIX));
+    if let Some((name, _, _)) = preview2 {
+        // Interfaces are versioned like wasi:cli/environment@0.2.0
+        let version = name.split_once('@').map(|(_, version)| version.to_owned());
+        return Some(("Preview 2".to_owned(), version));
+    }
+
+    module
+        .imports
+        .iter()
+        .any(|(name, _, _)| WASI_PREVIEW1_MODULES.contains(&name.as_str()))
+        .then(|| ("Preview 1".to_owned(), None))
+}
+
+pub(crate) fn parse_wasm(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+) -> ULDDObjResult {
+    let module = match read_wasm(buffer) {
+        Ok(module) => module,
+        Err(error) => {
+            Debugging::Error(format!(
+                "Error while parsing the WebAssembly binary named '{}'{}\nDetails:\n{}",
+                file_name,
+                merge_members(member_names),
+                error
+            ))
+            .print(options.debugging);
+
+            return ULDDObjResult {
+                error: ParsingError {
+                    code: error.to_int(),
+                    explanation: error.to_c_string(),
+                },
+                obj: ULDDObj {
+                    file_name: file_name.to_c_string(),
+                    member_name: CharVec::from(member_names),
+                    executable_format: "WebAssembly".to_c_string(),
+                    ..Default::default()
+                },
+            };
+        }
+    };
+    debug_objects(
+        file_name,
+        member_names,
+        "a WebAssembly binary",
+        options.debugging,
+    );
+
+    let file_type = match &module {
+        m if m.is_component => WasmFileType::Component,
+        m if m.is_relocatable => WasmFileType::RelocatableObject,
+        m if m.is_shared_library => WasmFileType::SharedLibrary,
+        _ => WasmFileType::Module,
+    };
+    // Modules import from other modules and components import interfaces
+    let mut libraries: Vec<&str> = vec![];
+    for (name, _, _) in &module.imports {
+        if !libraries.contains(&name.as_str()) {
+            libraries.push(name);
+        }
+    }
+    let imports: Vec<String> = module
+        .imports
+        .iter()
+        .map(|(name, field, kind)| match field {
+            Some(field) => format!("{}.{} ({})", name, field, kind),
+            None => format!("{} ({})", name, kind),
+        })
+        .collect();
+    let has_names = module
+        .custom_sections
+        .iter()
+        .any(|name| name == "name" || name == "component-name");
+    let (is_stripped, stripping) = classify_stripping(has_names, &module.debug_info);
+    let (wasi, wasi_version) = find_wasi(&module).unzip();
+    let cpu_type = if module.is_memory64 {
+        "wasm64"
+    } else {
+        "wasm32"
+    };
+
+    ULDDObjResult {
+        error: ParsingError::default(),
+        obj: ULDDObj {
+            file_name: file_name.to_c_string(),
+            member_name: CharVec::from(member_names),
+            executable_format: "WebAssembly".to_c_string(),
+            is_64: module.is_memory64,
+            os_type: option_to_c_string(wasi.as_ref().map(|_| "WASI")),
+            file_type: file_type.to_c_string(),
+            is_stripped,
+            cpu_type: cpu_type.to_c_string(),
+            cpu_subtype: null_mut(),
+            interpreter: null_mut(),
+            libraries: CharVec::from(libraries),
+            os_version: option_to_c_string(wasi_version.flatten()),
+            stripping,
+            toolchain: CharVec::from(module.producers),
+            wasm: WasmInfo {
+                version: module.version,
+                is_component: module.is_component,
+                module_name: option_to_c_string(module.module_name),
+                wasi: option_to_c_string(wasi),
+                imports: CharVec::from(imports),
+                exports: CharVec::from(module.exports),
+                memories: CharVec::from(module.memories),
+                tables: CharVec::from(module.tables),
+                custom_sections: CharVec::from(module.custom_sections),
+                target_features: CharVec::from(module.target_features),
+            },
+            ..Default::default()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_imports_keep_the_next_imports_aligned() {
+        let imports = [
+            0x03, // 3 imports
+            0x00, 0x01, b'a', 0x02, 0x00, 0x05, // value a equal to the value 5
+            0x00, 0x01, b'b', 0x02, 0x01, 0x73, // value b of the type string
+            0x00, 0x01, b'c', 0x01, 0x07, // function c of the type 7
+        ];
+        let mut module = WasmModule::default();
+        read_component_imports(&mut Reader::new(&imports), &mut module).unwrap();
+
+        let imports: Vec<(&str, &str)> = module
+            .imports
+            .iter()
+            .map(|(name, _, sort)| (name.as_str(), *sort))
+            .collect();
+        assert_eq!(imports, [("a", "value"), ("b", "value"), ("c", "function")]);
+    }
+}
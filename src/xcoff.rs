@@ -0,0 +1,581 @@
+use crate::{
+    debug::{
+        classify_stripping, debug_objects, flag_names, merge_members, option_to_c_string,
+        permissions_to_c_string, read_c_str,
+    },
+    impls::{ErrorToInt, StringToCString},
+    structs::{
+        CharVec, Debugging, ParsingError, SectionInfo, SectionInfoVec, ULDDObj, ULDDObjResult,
+        ULDDOptions, XcoffInfo,
+    },
+    types::{DebugInfoFormat, DebugInfoLocation, XcoffFileType, XCOFF_CPU_TYPE, XCOFF_FLAGS},
+};
+use goblin::error::Error;
+use scroll::{Pread, BE};
+use std::ptr::null_mut;
+
+/*
+Sources:
+    https://www.ibm.com/docs/en/aix/7.3?topic=formats-xcoff-object-file-format
+    https://github.com/llvm/llvm-project/blob/main/llvm/include/llvm/BinaryFormat/XCOFF.h
+*/
+
+pub(crate) const XCOFF32_MAGIC: u16 = 0x01df;
+pub(crate) const XCOFF64_MAGIC: u16 = 0x01f7;
+// XCOFF64 objects of AIX 4.3 and before
+pub(crate) const XCOFF64_OLD_MAGIC: u16 = 0x01ef;
+
+const FILE_HEADER_SIZE_32: usize = 20;
+const FILE_HEADER_SIZE_64: usize = 24;
+const SECTION_HEADER_SIZE_32: usize = 40;
+const SECTION_HEADER_SIZE_64: usize = 72;
+// Object files may have the short form of the auxiliary header which ends before o_snentry
+const AUX_HEADER_SIZE_32: usize = 72;
+const AUX_HEADER_SIZE_64: usize = 120;
+
+const F_EXEC: u16 = 0x0002;
+const F_SHROBJ: u16 = 0x2000;
+
+const STYP_DWARF: u32 = 0x0010;
+const STYP_TEXT: u32 = 0x0020;
+const STYP_DATA: u32 = 0x0040;
+const STYP_BSS: u32 = 0x0080;
+const STYP_TDATA: u32 = 0x0400;
+const STYP_TBSS: u32 = 0x0800;
+const STYP_LOADER: u32 = 0x1000;
+const STYP_DEBUG: u32 = 0x2000;
+
+struct XcoffSection {
+    name: String,
+    virtual_address: u64,
+    size: u64,
+    file_offset: u64,
+    flags: u32,
+}
+
+struct AuxHeader {
+    module_type: String,
+    cpu_type: u8,
+    text_size: u64,
+    data_size: u64,
+    bss_size: u64,
+    entry_point: u64,
+    toc: u64,
+    max_stack: u64,
+    max_data: u64,
+}
+
+fn section_type(flags: u32) -> Option<&'static str> {
+    // The subtype of DWARF sections is in the upper half
+    match flags & 0xffff {
+        0x0000 => Some("STYP_REG"),
+        0x0008 => Some("STYP_PAD"),
+        STYP_DWARF => Some("STYP_DWARF"),
+        STYP_TEXT => Some("STYP_TEXT"),
+        STYP_DATA => Some("STYP_DATA"),
+        STYP_BSS => Some("STYP_BSS"),
+        0x0100 => Some("STYP_EXCEPT"),
+        0x0200 => Some("STYP_INFO"),
+        STYP_TDATA => Some("STYP_TDATA"),
+        STYP_TBSS => Some("STYP_TBSS"),
+        STYP_LOADER => Some("STYP_LOADER"),
+        STYP_DEBUG => Some("STYP_DEBUG"),
+        0x4000 => Some("STYP_TYPCHK"),
+        0x8000 => Some("STYP_OVRFLO"),
+        _ => None,
+    }
+}
+
+fn read_sections(
+    buffer: &[u8],
+    offset: usize,
+    count: u16,
+    is_64: bool,
+) -> Result<Vec<XcoffSection>, Error> {
+    let size = if is_64 {
+        SECTION_HEADER_SIZE_64
+    } else {
+        SECTION_HEADER_SIZE_32
+    };
+    (0..count as usize)
+        .map(|index| {
+            let header = offset + index * size;
+            let name = buffer.get(header..header + 8).ok_or_else(|| {
+                Error::Malformed(format!("Section header {} is out of the file", index))
+            })?;
+            let name = read_c_str(name);
+            let section = if is_64 {
+                XcoffSection {
+                    name,
+                    virtual_address: buffer.pread_with::<u64>(header + 16, BE)?,
+                    size: buffer.pread_with::<u64>(header + 24, BE)?,
+                    file_offset: buffer.pread_with::<u64>(header + 32, BE)?,
+                    flags: buffer.pread_with::<u32>(header + 64, BE)?,
+                }
+            } else {
+                XcoffSection {
+                    name,
+                    virtual_address: buffer.pread_with::<u32>(header + 12, BE)? as u64,
+                    size: buffer.pread_with::<u32>(header + 16, BE)? as u64,
+                    file_offset: buffer.pread_with::<u32>(header + 20, BE)? as u64,
+                    flags: buffer.pread_with::<u32>(header + 36, BE)?,
+                }
+            };
+            Ok(section)
+        })
+        .collect()
+}
+
+fn read_aux_header(buffer: &[u8], offset: usize, size: usize, is_64: bool) -> Option<AuxHeader> {
+    let header = buffer.get(offset..offset.checked_add(size)?)?;
+    let module_type = read_c_str(header.get(48..50)?).trim_end().to_owned();
+    let aux = if is_64 && size >= AUX_HEADER_SIZE_64 {
+        AuxHeader {
+            module_type,
+            cpu_type: header.pread::<u8>(51).ok()?,
+            toc: header.pread_with::<u64>(24, BE).ok()?,
+            text_size: header.pread_with::<u64>(56, BE).ok()?,
+            data_size: header.pread_with::<u64>(64, BE).ok()?,
+            bss_size: header.pread_with::<u64>(72, BE).ok()?,
+            entry_point: header.pread_with::<u64>(80, BE).ok()?,
+            max_stack: header.pread_with::<u64>(88, BE).ok()?,
+            max_data: header.pread_with::<u64>(96, BE).ok()?,
+        }
+    } else if !is_64 && size >= AUX_HEADER_SIZE_32 {
+        let read = |offset: usize| header.pread_with::<u32>(offset, BE).ok().map(|v| v as u64);
+        AuxHeader {
+            module_type,
+            cpu_type: header.pread::<u8>(51).ok()?,
+            text_size: read(4)?,
+            data_size: read(8)?,
+            bss_size: read(12)?,
+            entry_point: read(16)?,
+            toc: read(28)?,
+            max_stack: read(52)?,
+            max_data: read(56)?,
+        }
+    } else {
+        return None;
+    };
+
+    Some(aux)
+}
+
+///
+/// Reads the import file IDs of the loader section.
+///
+/// Every ID is three null-terminated strings: the path, the base name and the archive member.
+/// The first ID is the default library search path (`LIBPATH`) and has neither a base name nor a member.
+///
+fn read_import_ids(loader: &[u8], is_64: bool) -> Result<(Option<String>, Vec<String>), Error> {
+    let count = loader.pread_with::<u32>(16, BE)? as usize;
+    let length = loader.pread_with::<u32>(12, BE)? as usize;
+    let offset = if is_64 {
+        loader.pread_with::<u64>(24, BE)? as usize
+    } else {
+        loader.pread_with::<u32>(20, BE)? as usize
+    };
+    let table = offset
+        .checked_add(length)
+        .and_then(|end| loader.get(offset..end))
+        .ok_or_else(|| {
+            Error::Malformed("The import file IDs are out of the loader section".to_owned())
+        })?;
+
+    let mut strings = table
+        .split(|b| *b == 0)
+        .map(|string| String::from_utf8_lossy(string).into_owned());
+    let mut library_path = None;
+    let mut libraries = vec![];
+    for index in 0..count {
+        let (Some(path), Some(base), Some(member)) =
+            (strings.next(), strings.next(), strings.next())
+        else {
+            break;
+        };
+        if index == 0 {
+            library_path = Some(path).filter(|path| !path.is_empty());
+            continue;
+        }
+        // Libraries in archives are written like libc.a(shr.o)
+        let mut library = if path.is_empty() {
+            base
+        } else {
+            format!("{}/{}", path.trim_end_matches('/'), base)
+        };
+        if !member.is_empty() {
+            library = format!("{}({})", library, member);
+        }
+        libraries.push(library);
+    }
+
+    Ok((library_path, libraries))
+}
+
+fn list_sections_xcoff(sections: &[XcoffSection]) -> SectionInfoVec {
+    let sections: Vec<SectionInfo> = sections
+        .iter()
+        .map(|s| {
+            let flags = s.flags & 0xffff;
+            // The sections other than these are not loaded into the memory
+            let permissions = match flags {
+                STYP_TEXT => permissions_to_c_string(true, false, true),
+                STYP_DATA | STYP_BSS | STYP_TDATA | STYP_TBSS => {
+                    permissions_to_c_string(true, true, false)
+                }
+                _ => null_mut(),
+            };
+            let file_size = match flags {
+                STYP_BSS | STYP_TBSS => 0,
+                _ => s.size,
+            };
+            SectionInfo {
+                name: s.name.as_str().to_c_string(),
+                segment_name: null_mut(),
+                section_type: option_to_c_string(section_type(s.flags)),
+                virtual_address: s.virtual_address,
+                virtual_size: s.size,
+                file_offset: s.file_offset,
+                file_size,
+                permissions,
+                flags: s.flags as u64,
+                alignment: 0,
+            }
+        })
+        .collect();
+
+    SectionInfoVec::from(sections)
+}
+
+fn read_xcoff(buffer: &[u8], options: &ULDDOptions) -> Result<ULDDObj, Error> {
+    let magic = buffer.pread_with::<u16>(0, BE)?;
+    let is_64 = magic != XCOFF32_MAGIC;
+    let section_count = buffer.pread_with::<u16>(2, BE)?;
+    let (symbol_table, symbol_count, aux_size, flags) = if is_64 {
+        (
+            buffer.pread_with::<u64>(8, BE)?,
+            buffer.pread_with::<u32>(20, BE)?,
+            buffer.pread_with::<u16>(16, BE)?,
+            buffer.pread_with::<u16>(18, BE)?,
+        )
+    } else {
+        (
+            buffer.pread_with::<u32>(8, BE)? as u64,
+            buffer.pread_with::<u32>(12, BE)?,
+            buffer.pread_with::<u16>(16, BE)?,
+            buffer.pread_with::<u16>(18, BE)?,
+        )
+    };
+    let header_size = if is_64 {
+        FILE_HEADER_SIZE_64
+    } else {
+        FILE_HEADER_SIZE_32
+    };
+    let aux = read_aux_header(buffer, header_size, aux_size as usize, is_64);
+    let sections = read_sections(
+        buffer,
+        header_size + aux_size as usize,
+        section_count,
+        is_64,
+    )?;
+
+    let (library_path, libraries) = match sections.iter().find(|s| s.flags & 0xffff == STYP_LOADER)
+    {
+        Some(loader) => {
+            let start = loader.file_offset as usize;
+            let data = start
+                .checked_add(loader.size as usize)
+                .and_then(|end| buffer.get(start..end))
+                .ok_or_else(|| {
+                    Error::Malformed("The loader section is out of the file".to_owned())
+                })?;
+            read_import_ids(data, is_64)?
+        }
+        None => (None, vec![]),
+    };
+    // xlc puts the stabs into the .debug section while the DWARF sections have their own type
+    let debug_info: Vec<(DebugInfoFormat, DebugInfoLocation)> = sections
+        .iter()
+        .filter_map(|s| match s.flags & 0xffff {
+            STYP_DWARF => Some((DebugInfoFormat::DWARF, DebugInfoLocation::Embedded)),
+            STYP_DEBUG => Some((DebugInfoFormat::STABS, DebugInfoLocation::Embedded)),
+            _ => None,
+        })
+        .collect();
+    let has_symbols = symbol_table != 0 && symbol_count != 0;
+    let (is_stripped, stripping) = classify_stripping(has_symbols, &debug_info);
+
+    let file_type = if flags & F_SHROBJ != 0 {
+        XcoffFileType::SharedObject
+    } else if flags & F_EXEC != 0 && aux.is_some() {
+        XcoffFileType::Executable
+    } else {
+        XcoffFileType::ObjectFile
+    };
+    let cpu_type = if is_64 { "PowerPC (64-Bit)" } else { "PowerPC" };
+    let cpu_subtype = aux
+        .as_ref()
+        .and_then(|aux| XCOFF_CPU_TYPE.get(&aux.cpu_type));
+    let sections_info = if options.list_sections {
+        list_sections_xcoff(&sections)
+    } else {
+        Default::default()
+    };
+
+    Ok(ULDDObj {
+        executable_format: "XCOFF".to_c_string(),
+        is_64,
+        os_type: "AIX".to_c_string(),
+        file_type: file_type.to_c_string(),
+        is_stripped,
+        cpu_type: cpu_type.to_c_string(),
+        cpu_subtype: option_to_c_string(cpu_subtype),
+        interpreter: null_mut(),
+        libraries: CharVec::from(libraries),
+        stripping,
+        sections: sections_info,
+        xcoff: XcoffInfo {
+            entry_point: aux.as_ref().map_or(0, |aux| aux.entry_point),
+            toc: aux.as_ref().map_or(0, |aux| aux.toc),
+            module_type: option_to_c_string(
+                aux.as_ref()
+                    .map(|aux| aux.module_type.as_str())
+                    .filter(|module_type| !module_type.is_empty()),
+            ),
+            flags: flag_names(flags as u64, &XCOFF_FLAGS),
+            library_path: option_to_c_string(library_path),
+            text_size: aux.as_ref().map_or(0, |aux| aux.text_size),
+            data_size: aux.as_ref().map_or(0, |aux| aux.data_size),
+            bss_size: aux.as_ref().map_or(0, |aux| aux.bss_size),
+            max_stack: aux.as_ref().map_or(0, |aux| aux.max_stack),
+            max_data: aux.as_ref().map_or(0, |aux| aux.max_data),
+        },
+        ..Default::default()
+    })
+}
+
+pub(crate) fn parse_xcoff(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+) -> ULDDObjResult {
+    match read_xcoff(buffer, options) {
+        Ok(obj) => {
+            debug_objects(
+                file_name,
+                member_names,
+                "an XCOFF binary",
+                options.debugging,
+            );
+            ULDDObjResult {
+                error: ParsingError::default(),
+                obj: ULDDObj {
+                    file_name: file_name.to_c_string(),
+                    member_name: CharVec::from(member_names),
+                    ..obj
+                },
+            }
+        }
+        Err(error) => {
+            Debugging::Error(format!(
+                "Error while parsing the XCOFF binary named '{}'{}\nDetails:\n{}",
+                file_name,
+                merge_members(member_names),
+                error
+            ))
+            .print(options.debugging);
+
+            ULDDObjResult {
+                error: ParsingError {
+                    code: error.to_int(),
+                    explanation: error.to_c_string(),
+                },
+                obj: ULDDObj {
+                    file_name: file_name.to_c_string(),
+                    member_name: CharVec::from(member_names),
+                    executable_format: "XCOFF".to_c_string(),
+                    ..Default::default()
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{read_string, read_strings};
+
+    const IMPORT_IDS: &[u8] = b"/usr/lib:/lib\0\0\0\0libc.a\0shr.o\0/opt/lib\0libfoo.so\0\0";
+
+    fn put(buffer: &mut [u8], offset: usize, bytes: &[u8]) {
+        buffer[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    // Writes the numbers with the sizes of the fields (4 bytes in 32 bit objects)
+    fn put_number(buffer: &mut [u8], offset: usize, number: u64, is_64: bool) {
+        if is_64 {
+            put(buffer, offset, &number.to_be_bytes());
+        } else {
+            put(buffer, offset, &(number as u32).to_be_bytes());
+        }
+    }
+
+    // A shared object with the auxiliary header, the loader section and the text section
+    fn xcoff(is_64: bool) -> Vec<u8> {
+        let (header_size, aux_size, section_size) = if is_64 {
+            (
+                FILE_HEADER_SIZE_64,
+                AUX_HEADER_SIZE_64,
+                SECTION_HEADER_SIZE_64,
+            )
+        } else {
+            (
+                FILE_HEADER_SIZE_32,
+                AUX_HEADER_SIZE_32,
+                SECTION_HEADER_SIZE_32,
+            )
+        };
+        let loader = header_size + aux_size + 2 * section_size;
+        let loader_header_size = if is_64 { 56 } else { 32 };
+        let mut buffer = vec![0; loader + loader_header_size + IMPORT_IDS.len()];
+
+        let magic = if is_64 { XCOFF64_MAGIC } else { XCOFF32_MAGIC };
+        put(&mut buffer, 0, &magic.to_be_bytes());
+        put(&mut buffer, 2, &2_u16.to_be_bytes());
+        put_number(&mut buffer, 8, 0x1234, is_64);
+        put(
+            &mut buffer,
+            if is_64 { 20 } else { 12 },
+            &3_u32.to_be_bytes(),
+        );
+        put(&mut buffer, 16, &(aux_size as u16).to_be_bytes());
+        put(&mut buffer, 18, &(F_EXEC | F_SHROBJ).to_be_bytes());
+
+        let aux = header_size;
+        let fields = if is_64 {
+            [
+                (56, 0x100),
+                (64, 0x200),
+                (72, 0x300),
+                (80, 0x400),
+                (24, 0x500),
+                (88, 0x600),
+                (96, 0x700),
+            ]
+        } else {
+            [
+                (4, 0x100),
+                (8, 0x200),
+                (12, 0x300),
+                (16, 0x400),
+                (28, 0x500),
+                (52, 0x600),
+                (56, 0x700),
+            ]
+        };
+        for (offset, value) in fields {
+            put_number(&mut buffer, aux + offset, value, is_64);
+        }
+        put(&mut buffer, aux + 48, b"RO");
+        buffer[aux + 51] = 3;
+
+        // The virtual address, the size, the file offset and the flags of the sections
+        let sections = aux + aux_size;
+        let (address, size, file_offset, flags) = if is_64 {
+            (16, 24, 32, 64)
+        } else {
+            (12, 16, 20, 36)
+        };
+        put(&mut buffer, sections, b".text\0\0\0");
+        put_number(&mut buffer, sections + address, 0x1000_0000, is_64);
+        put_number(&mut buffer, sections + size, 0x100, is_64);
+        put(&mut buffer, sections + flags, &STYP_TEXT.to_be_bytes());
+        let section = sections + section_size;
+        put(&mut buffer, section, b".loader\0");
+        let loader_size = (loader_header_size + IMPORT_IDS.len()) as u64;
+        put_number(&mut buffer, section + size, loader_size, is_64);
+        put_number(&mut buffer, section + file_offset, loader as u64, is_64);
+        put(&mut buffer, section + flags, &STYP_LOADER.to_be_bytes());
+
+        // The length and the count of the import file IDs and their offset in the loader section
+        put(
+            &mut buffer,
+            loader + 12,
+            &(IMPORT_IDS.len() as u32).to_be_bytes(),
+        );
+        put(&mut buffer, loader + 16, &3_u32.to_be_bytes());
+        put_number(
+            &mut buffer,
+            loader + if is_64 { 24 } else { 20 },
+            loader_header_size as u64,
+            is_64,
+        );
+        put(&mut buffer, loader + loader_header_size, IMPORT_IDS);
+
+        buffer
+    }
+
+    fn check_headers(is_64: bool) {
+        let options = ULDDOptions {
+            list_sections: true,
+            ..Default::default()
+        };
+        let obj = read_xcoff(&xcoff(is_64), &options).unwrap();
+
+        assert_eq!(obj.is_64, is_64);
+        assert_eq!(read_string(obj.file_type).as_deref(), Some("Shared object"));
+        assert_eq!(
+            read_string(obj.cpu_subtype).as_deref(),
+            Some("Common (POWER and PowerPC)")
+        );
+        assert_eq!(
+            read_strings(&obj.libraries),
+            ["libc.a(shr.o)", "/opt/lib/libfoo.so"]
+        );
+        assert!(!obj.is_stripped);
+
+        let xcoff = &obj.xcoff;
+        assert_eq!(
+            [
+                xcoff.text_size,
+                xcoff.data_size,
+                xcoff.bss_size,
+                xcoff.entry_point,
+                xcoff.toc,
+                xcoff.max_stack,
+                xcoff.max_data
+            ],
+            [0x100, 0x200, 0x300, 0x400, 0x500, 0x600, 0x700]
+        );
+        assert_eq!(read_string(xcoff.module_type).as_deref(), Some("RO"));
+        assert_eq!(
+            read_string(xcoff.library_path).as_deref(),
+            Some("/usr/lib:/lib")
+        );
+        assert_eq!(read_strings(&xcoff.flags), ["F_EXEC", "F_SHROBJ"]);
+
+        let sections = unsafe { std::slice::from_raw_parts(obj.sections.vec, obj.sections.length) };
+        assert_eq!(
+            sections
+                .iter()
+                .map(|s| (s.virtual_address, s.virtual_size))
+                .collect::<Vec<_>>(),
+            [
+                (0x1000_0000, 0x100),
+                (0, (if is_64 { 56 } else { 32 } + IMPORT_IDS.len()) as u64)
+            ]
+        );
+    }
+
+    #[test]
+    fn headers_of_32_bit_objects() {
+        check_headers(false);
+    }
+
+    #[test]
+    fn headers_of_64_bit_objects() {
+        check_headers(true);
+    }
+}
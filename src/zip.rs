@@ -0,0 +1,537 @@
+use crate::{
+    debug::{debug_objects, merge_members, option_to_c_string, read_c_str},
+    impls::{ErrorToInt, StringToCString},
+    is_object, parse_objects,
+    structs::{CharVec, Debugging, ParsingError, ULDDObj, ULDDObjResult, ULDDOptions},
+    PEEK_SIZE,
+};
+use goblin::error::Error;
+use miniz_oxide::inflate::{decompress_to_vec_with_limit, TINFLStatus};
+use scroll::{Pread, LE};
+use std::borrow::Cow;
+
+/*
+Source: https://pkware.cachefly.net/webdocs/casestudies/APPNOTE.TXT
+*/
+
+pub(crate) const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+// The end of central directory record is the only record of empty ZIP files
+pub(crate) const EMPTY_ZIP_MAGIC: &[u8] = b"PK\x05\x06";
+/// The maximum size of an entry after decompression.
+pub(crate) const MAX_ENTRY_SIZE: u64 = 256 * 1024 * 1024;
+/// The maximum size of the data extracted and decompressed from all the containers in a file in total.
+pub(crate) const MAX_TOTAL_SIZE: u64 = 1024 * 1024 * 1024;
+/// The maximum number of the objects (containers included) parsed from a file.
+pub(crate) const MAX_OBJECTS: usize = 65536;
+/// The maximum number of the members a ZIP file can be nested in.
+pub(crate) const MAX_NESTING_DEPTH: usize = 16;
+
+///
+/// The limits shared by all the containers in a file, so the containers nested in each other can't multiply them.
+///
+/// [`parse_objects`] spends an object on every call and the containers spend the sizes of the data they extract or decompress.
+///
+pub(crate) struct Budget {
+    size: u64,
+    objects: usize,
+    is_exhausted: bool,
+}
+
+impl Budget {
+    pub(crate) fn new() -> Budget {
+        Budget {
+            size: MAX_TOTAL_SIZE,
+            objects: MAX_OBJECTS,
+            is_exhausted: false,
+        }
+    }
+
+    /// Returns the bytes which can be extracted, at most `limit`.
+    pub(crate) fn size_limit(&self, limit: u64) -> u64 {
+        limit.min(self.size)
+    }
+
+    pub(crate) fn spend_size(&mut self, size: u64) {
+        self.size = self.size.saturating_sub(size);
+    }
+
+    /// Spends an object. It returns an error only the first time the objects run out and `None` after that.
+    pub(crate) fn spend_object(&mut self) -> Option<Result<(), Error>> {
+        if self.objects > 0 {
+            self.objects -= 1;
+            return Some(Ok(()));
+        }
+        if self.is_exhausted {
+            return None;
+        }
+        self.is_exhausted = true;
+        Some(Err(Error::Malformed(format!(
+            "The file has more than {} objects in its containers",
+            MAX_OBJECTS
+        ))))
+    }
+}
+
+const END_OF_CENTRAL_DIRECTORY_MAGIC: &[u8] = b"PK\x05\x06";
+const END_OF_CENTRAL_DIRECTORY_SIZE: usize = 22;
+const ZIP64_LOCATOR_MAGIC: &[u8] = b"PK\x06\x07";
+const ZIP64_LOCATOR_SIZE: usize = 20;
+const ZIP64_END_OF_CENTRAL_DIRECTORY_MAGIC: &[u8] = b"PK\x06\x06";
+const CENTRAL_DIRECTORY_MAGIC: &[u8] = b"PK\x01\x02";
+const CENTRAL_DIRECTORY_HEADER_SIZE: usize = 46;
+const LOCAL_HEADER_SIZE: usize = 30;
+// The comment at the end of the file can be 65535 bytes at most
+const MAX_COMMENT_SIZE: usize = 0xffff;
+const ZIP64_EXTRA_ID: u16 = 0x0001;
+
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATED: u16 = 8;
+const FLAG_ENCRYPTED: u16 = 0x0001;
+
+/// An entry in the central directory of a ZIP file.
+pub(crate) struct ZipEntry {
+    pub(crate) name: String,
+    method: u16,
+    flags: u16,
+    compressed_size: u64,
+    uncompressed_size: u64,
+    local_header: u64,
+}
+
+impl ZipEntry {
+    pub(crate) fn is_directory(&self) -> bool {
+        self.name.ends_with('/')
+    }
+}
+
+fn out_of_file(what: &str, offset: usize) -> Error {
+    Error::Malformed(format!(
+        "The {} at {:#x} is out of the ZIP file",
+        what, offset
+    ))
+}
+
+// Returns the number of the entries and the offset of the central directory
+fn find_central_directory(buffer: &[u8]) -> Result<(u64, u64), Error> {
+    let search_start = buffer
+        .len()
+        .saturating_sub(END_OF_CENTRAL_DIRECTORY_SIZE + MAX_COMMENT_SIZE);
+    let end = buffer
+        .len()
+        .checked_sub(END_OF_CENTRAL_DIRECTORY_SIZE)
+        .and_then(|last| {
+            (search_start..=last)
+                .rev()
+                .find(|offset| buffer[*offset..].starts_with(END_OF_CENTRAL_DIRECTORY_MAGIC))
+        })
+        .ok_or_else(|| {
+            Error::Malformed("The end of central directory record is not found".to_owned())
+        })?;
+    let entries = buffer.pread_with::<u16>(end + 10, LE)? as u64;
+    let offset = buffer.pread_with::<u32>(end + 16, LE)? as u64;
+
+    // ZIP64 files have the real values in another record which is located before this one
+    if entries != 0xffff && offset != 0xffff_ffff {
+        return Ok((entries, offset));
+    }
+    let locator = end
+        .checked_sub(ZIP64_LOCATOR_SIZE)
+        .filter(|locator| buffer[*locator..].starts_with(ZIP64_LOCATOR_MAGIC))
+        .ok_or_else(|| {
+            Error::Malformed("The ZIP64 end of central directory locator is not found".to_owned())
+        })?;
+    let record = buffer.pread_with::<u64>(locator + 8, LE)? as usize;
+    if !buffer
+        .get(record..)
+        .is_some_and(|record| record.starts_with(ZIP64_END_OF_CENTRAL_DIRECTORY_MAGIC))
+    {
+        return Err(out_of_file("ZIP64 end of central directory record", record));
+    }
+
+    Ok((
+        buffer.pread_with::<u64>(record + 32, LE)?,
+        buffer.pread_with::<u64>(record + 48, LE)?,
+    ))
+}
+
+// The sizes and the offset are in the ZIP64 extra field if they don't fit in 32 bits
+fn read_zip64_extra(extra: &[u8], values: &mut [&mut u64]) -> Result<(), Error> {
+    let mut offset = 0;
+    while offset + 4 <= extra.len() {
+        let id = extra.pread_with::<u16>(offset, LE)?;
+        let size = extra.pread_with::<u16>(offset + 2, LE)? as usize;
+        if id == ZIP64_EXTRA_ID {
+            let mut field = offset + 4;
+            for value in values.iter_mut().filter(|value| ***value == 0xffff_ffff) {
+                if field + 8 > offset + 4 + size {
+                    break;
+                }
+                **value = extra.pread_with::<u64>(field, LE)?;
+                field += 8;
+            }
+            break;
+        }
+        offset += 4 + size;
+    }
+
+    Ok(())
+}
+
+///
+/// Reads the entries in the central directory of a ZIP file.
+///
+/// The local headers are not trusted for the sizes, as they are zero if the sizes are written after the data.
+///
+pub(crate) fn read_entries(buffer: &[u8]) -> Result<Vec<ZipEntry>, Error> {
+    let (count, mut offset) = find_central_directory(buffer)?;
+    let mut entries = vec![];
+    for _ in 0..count {
+        let header = offset as usize;
+        if !buffer
+            .get(header..)
+            .is_some_and(|header| header.starts_with(CENTRAL_DIRECTORY_MAGIC))
+        {
+            return Err(out_of_file("central directory header", header));
+        }
+        let name_length = buffer.pread_with::<u16>(header + 28, LE)? as usize;
+        let extra_length = buffer.pread_with::<u16>(header + 30, LE)? as usize;
+        let comment_length = buffer.pread_with::<u16>(header + 32, LE)? as usize;
+        let name_start = header + CENTRAL_DIRECTORY_HEADER_SIZE;
+        let name = buffer
+            .get(name_start..name_start + name_length)
+            .ok_or_else(|| out_of_file("name of the entry", header))?;
+        let extra = buffer
+            .get(name_start + name_length..name_start + name_length + extra_length)
+            .ok_or_else(|| out_of_file("extra field of the entry", header))?;
+
+        let mut compressed_size = buffer.pread_with::<u32>(header + 20, LE)? as u64;
+        let mut uncompressed_size = buffer.pread_with::<u32>(header + 24, LE)? as u64;
+        let mut local_header = buffer.pread_with::<u32>(header + 42, LE)? as u64;
+        read_zip64_extra(
+            extra,
+            &mut [
+                &mut uncompressed_size,
+                &mut compressed_size,
+                &mut local_header,
+            ],
+        )?;
+
+        entries.push(ZipEntry {
+            // The names are cut at NUL bytes, since they become the file names of the objects
+            name: read_c_str(name),
+            method: buffer.pread_with::<u16>(header + 10, LE)?,
+            flags: buffer.pread_with::<u16>(header + 8, LE)?,
+            compressed_size,
+            uncompressed_size,
+            local_header,
+        });
+        offset = (name_start + name_length + extra_length + comment_length) as u64;
+    }
+    check_overlaps(buffer, &entries)?;
+
+    Ok(entries)
+}
+
+// The entries which share their bytes would be extracted again and again from a small file, so they are refused
+fn check_overlaps(buffer: &[u8], entries: &[ZipEntry]) -> Result<(), Error> {
+    let mut spans: Vec<(u64, u64, &str)> = entries
+        .iter()
+        .map(|entry| {
+            let header = entry.local_header as usize;
+            let lengths = buffer
+                .pread_with::<u16>(header + 26, LE)
+                .ok()
+                .zip(buffer.pread_with::<u16>(header + 28, LE).ok());
+            let header_size = lengths.map_or(LOCAL_HEADER_SIZE as u64, |(name, extra)| {
+                (LOCAL_HEADER_SIZE + name as usize + extra as usize) as u64
+            });
+            let end = entry
+                .local_header
+                .saturating_add(header_size)
+                .saturating_add(entry.compressed_size);
+            (entry.local_header, end, entry.name.as_str())
+        })
+        .collect();
+    spans.sort_unstable();
+    for pair in spans.windows(2) {
+        let ((_, end, name), (start, _, next_name)) = (pair[0], pair[1]);
+        if start < end {
+            return Err(Error::Malformed(format!(
+                "The entry named '{}' overlaps the entry named '{}'",
+                next_name, name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+// Returns the compressed bytes of the entry
+fn entry_data<'a>(buffer: &'a [u8], entry: &ZipEntry) -> Result<&'a [u8], Error> {
+    if entry.flags & FLAG_ENCRYPTED != 0 {
+        return Err(Error::Malformed(format!(
+            "The entry named '{}' is encrypted",
+            entry.name
+        )));
+    }
+    if ![METHOD_STORED, METHOD_DEFLATED].contains(&entry.method) {
+        return Err(Error::Malformed(format!(
+            "The entry named '{}' uses an unsupported compression method ({})",
+            entry.name, entry.method
+        )));
+    }
+    let header = entry.local_header as usize;
+    if !buffer
+        .get(header..)
+        .is_some_and(|header| header.starts_with(ZIP_MAGIC))
+    {
+        return Err(out_of_file("local header", header));
+    }
+    let name_length = buffer.pread_with::<u16>(header + 26, LE)? as usize;
+    let extra_length = buffer.pread_with::<u16>(header + 28, LE)? as usize;
+    let start = header + LOCAL_HEADER_SIZE + name_length + extra_length;
+
+    start
+        .checked_add(entry.compressed_size as usize)
+        .and_then(|end| buffer.get(start..end))
+        .ok_or_else(|| out_of_file("data of the entry", header))
+}
+
+///
+/// Returns the bytes of the entry. Stored entries are borrowed from the buffer and deflated ones are decompressed.
+///
+/// Entries bigger than `limit` after decompression are not extracted.
+///
+fn extract<'a>(buffer: &'a [u8], entry: &ZipEntry, limit: u64) -> Result<Cow<'a, [u8]>, Error> {
+    if entry.uncompressed_size > limit {
+        return Err(Error::Malformed(format!(
+            "The entry named '{}' is bigger than the limit ({} > {} bytes)",
+            entry.name, entry.uncompressed_size, limit
+        )));
+    }
+    let data = entry_data(buffer, entry)?;
+
+    match entry.method {
+        METHOD_STORED => Ok(Cow::Borrowed(data)),
+        // The size in the central directory is not trusted for the limit as it can be forged
+        _ => decompress_to_vec_with_limit(data, limit as usize)
+            .map(Cow::Owned)
+            .map_err(|error| {
+                Error::Malformed(format!(
+                    "The entry named '{}' couldn't be decompressed: {}",
+                    entry.name, error
+                ))
+            }),
+    }
+}
+
+// Returns the first bytes of the entry without decompressing the rest
+fn peek<'a>(buffer: &'a [u8], entry: &ZipEntry) -> Result<Cow<'a, [u8]>, Error> {
+    let data = entry_data(buffer, entry)?;
+
+    match entry.method {
+        METHOD_STORED => Ok(Cow::Borrowed(&data[..data.len().min(PEEK_SIZE)])),
+        _ => match decompress_to_vec_with_limit(data, PEEK_SIZE) {
+            Ok(head) => Ok(Cow::Owned(head)),
+            Err(error) if error.status == TINFLStatus::HasMoreOutput => {
+                Ok(Cow::Owned(error.output))
+            }
+            Err(error) => Err(Error::Malformed(format!(
+                "The entry named '{}' couldn't be decompressed: {}",
+                entry.name, error
+            ))),
+        },
+    }
+}
+
+// The errors of the entries are reported with the names of the entries and the errors of the ZIP file with its own name
+fn to_error(
+    file_name: &str,
+    entry_name: Option<&str>,
+    error: Error,
+    member_names: &mut Vec<&str>,
+    options: &ULDDOptions,
+) -> ULDDObjResult {
+    let message = match entry_name {
+        Some(entry_name) => format!(
+            "Error while extracting the entry named '{}' from the ZIP file named '{}'",
+            entry_name, file_name
+        ),
+        None => format!("Error while reading the ZIP file named '{}'", file_name),
+    };
+    Debugging::Error(format!(
+        "{}{}\nDetails:\n{}",
+        message,
+        merge_members(member_names),
+        error
+    ))
+    .print(options.debugging);
+
+    ULDDObjResult {
+        error: ParsingError {
+            code: error.to_int(),
+            explanation: error.to_c_string(),
+        },
+        obj: ULDDObj {
+            file_name: entry_name.unwrap_or(file_name).to_c_string(),
+            member_name: CharVec::from(member_names),
+            file_type: option_to_c_string(entry_name.is_none().then_some("Archive")),
+            ..Default::default()
+        },
+    }
+}
+
+///
+/// Parses the objects in ZIP containers (Like: JAR, APK, AAR, Python wheels, NuGet packages and IPA files) as members.
+///
+/// Every entry is peeked and only the ones that start with the magic number of an object or a container are extracted,
+/// so the class files and the resources are skipped. The limits below protect against ZIP bombs:
+/// - An entry can't be bigger than [`MAX_ENTRY_SIZE`] after decompression
+/// - The entries extracted from all the containers in the file can't be bigger than [`MAX_TOTAL_SIZE`] in total (see [`Budget`])
+/// - The entries can't share their bytes
+/// - ZIP files are not parsed deeper than [`MAX_NESTING_DEPTH`] members
+///
+pub(crate) fn parse_zip(
+    file_name: &str,
+    buffer: &[u8],
+    member_names: &mut Vec<&str>,
+    objects: &mut Vec<ULDDObjResult>,
+    budget: &mut Budget,
+    options: &ULDDOptions,
+) {
+    if member_names.len() >= MAX_NESTING_DEPTH {
+        let error = Error::Malformed(format!(
+            "The ZIP file is nested deeper than {} members",
+            MAX_NESTING_DEPTH
+        ));
+        return objects.push(to_error(file_name, None, error, member_names, options));
+    }
+    let entries = match read_entries(buffer) {
+        Ok(entries) => entries,
+        Err(error) => return objects.push(to_error(file_name, None, error, member_names, options)),
+    };
+
+    for entry in entries.iter().filter(|entry| !entry.is_directory()) {
+        // The decompressed entries don't live as long as the ZIP file, so the names are copied for each entry
+        let mut names: Vec<&str> = member_names.to_vec();
+        names.push(file_name);
+        match peek(buffer, entry) {
+            Ok(head) if is_object(&head) => (),
+            Ok(_) => continue,
+            Err(error) => {
+                Debugging::Info(format!(
+                    "The entry named '{}' of the ZIP file named '{}' is skipped: {}",
+                    entry.name, file_name, error
+                ))
+                .print(options.debugging);
+                continue;
+            }
+        }
+        match extract(buffer, entry, budget.size_limit(MAX_ENTRY_SIZE)) {
+            Ok(data) => {
+                budget.spend_size(data.len() as u64);
+                debug_objects(file_name, &mut names, "a ZIP file", options.debugging);
+                parse_objects(&entry.name, &data, &mut names, objects, budget, options);
+            }
+            Err(error) => objects.push(to_error(
+                file_name,
+                Some(&entry.name),
+                error,
+                &mut names,
+                options,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{elf, parse, parse_with_budget, summarize, zip};
+    use miniz_oxide::deflate::compress_to_vec;
+
+    // The ZIP file has an entry which is deflated from an ELF header and 1 MiB of zeros, but the central directory says it is 64 bytes
+    fn bomb() -> Vec<u8> {
+        let mut contents = elf();
+        contents.resize(1024 * 1024, 0);
+        let deflated = compress_to_vec(&contents, 10);
+        let mut buffer = zip(&[("bomb.so", &deflated)]);
+        let central_directory = buffer.len() - 22 - CENTRAL_DIRECTORY_HEADER_SIZE - 7;
+        buffer[8] = METHOD_DEFLATED as u8;
+        buffer[central_directory + 10] = METHOD_DEFLATED as u8;
+        buffer[central_directory + 24..central_directory + 28]
+            .copy_from_slice(&64_u32.to_le_bytes());
+
+        buffer
+    }
+
+    #[test]
+    fn entries_which_share_their_bytes_are_refused() {
+        let mut buffer = zip(&[("a.so", &elf()), ("b.so", &elf())]);
+        // The second entry of the central directory points to the local header of the first one
+        let central_directory = buffer.len() - 22 - 2 * (CENTRAL_DIRECTORY_HEADER_SIZE + 4);
+        let second = central_directory + CENTRAL_DIRECTORY_HEADER_SIZE + 4;
+        buffer[second + 42..second + 46].copy_from_slice(&0_u32.to_le_bytes());
+
+        let error = read_entries(&buffer).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Malformed entity: The entry named 'b.so' overlaps the entry named 'a.so'"
+        );
+    }
+
+    #[test]
+    fn forged_sizes_dont_lift_the_entry_limit() {
+        let buffer = bomb();
+        let entries = read_entries(&buffer).unwrap();
+
+        assert_eq!(entries[0].uncompressed_size, 64);
+        assert!(extract(&buffer, &entries[0], 4096).is_err());
+        assert_eq!(
+            extract(&buffer, &entries[0], MAX_ENTRY_SIZE).unwrap().len(),
+            1024 * 1024
+        );
+    }
+
+    #[test]
+    fn entries_past_the_budget_are_not_extracted() {
+        let mut budget = Budget::new();
+        budget.size = 4096;
+        let objects = parse_with_budget("bomb.zip", &bomb(), &mut budget);
+
+        assert_eq!(
+            summarize(&objects),
+            [("bomb.so".to_owned(), vec!["bomb.zip".to_owned()], -1)]
+        );
+    }
+
+    #[test]
+    fn names_are_cut_at_nul_bytes() {
+        let buffer = zip(&[("lib.so\0.txt", &elf())]);
+
+        assert_eq!(read_entries(&buffer).unwrap()[0].name, "lib.so");
+        assert_eq!(
+            summarize(&parse("a.zip", &buffer)),
+            [("lib.so".to_owned(), vec!["a.zip".to_owned()], 0)]
+        );
+    }
+
+    #[test]
+    fn nesting_stops_at_the_maximum_depth() {
+        let nest = |depth: usize| (0..depth).fold(elf(), |inner, _| zip(&[("inner.zip", &inner)]));
+
+        let objects = parse("outer.zip", &nest(MAX_NESTING_DEPTH));
+        let (name, members, code) = summarize(&objects).pop().unwrap();
+        assert_eq!(
+            (name.as_str(), members.len(), code),
+            ("inner.zip", MAX_NESTING_DEPTH, 0)
+        );
+
+        let objects = parse("outer.zip", &nest(MAX_NESTING_DEPTH + 1));
+        let (name, members, code) = summarize(&objects).pop().unwrap();
+        assert_eq!(
+            (name.as_str(), members.len(), code),
+            ("inner.zip", MAX_NESTING_DEPTH, -1)
+        );
+    }
+}